@@ -77,6 +77,40 @@ impl LatentArchive {
         hash
     }
 
+    /// Archives a truth linked to the current latest entry.
+    ///
+    /// Sets `previous_hash` to the latest truth's registration hash and
+    /// folds it into the new registration hash, so reordering or
+    /// removing entries breaks `verify_chain`.
+    ///
+    /// # Returns
+    /// The chained registration hash.
+    pub fn archive_chained(&mut self, mut truth: FoucaultianTruth) -> String {
+        let previous = self.latest().map(|t| t.registration_hash.clone());
+        truth.link_to(previous);
+        self.archive(truth)
+    }
+
+    /// Verifies the chronological chain of the whole archive.
+    ///
+    /// Every truth must verify on its own, the first must have no
+    /// predecessor, and each later one must point at the registration
+    /// hash of the entry before it.
+    pub fn verify_chain(&self) -> bool {
+        let mut previous: Option<&str> = None;
+        for hash in &self.chronological_index {
+            let truth = match self.truths.get(hash) {
+                Some(t) => t,
+                None => return false,
+            };
+            if !truth.verify() || truth.previous_hash.as_deref() != previous {
+                return false;
+            }
+            previous = Some(truth.registration_hash.as_str());
+        }
+        true
+    }
+
     /// Retrieves a truth by its registration hash.
     pub fn get(&self, hash: &str) -> Option<&FoucaultianTruth> {
         self.truths.get(hash)
@@ -194,6 +228,60 @@ mod tests {
         assert!(archive.get(&hash).is_some());
     }
 
+    fn chained_archive(n: usize) -> LatentArchive {
+        let mut archive = LatentArchive::new();
+        for i in 0..n {
+            let action = ObservedAction::new(
+                format!("source_{}", i),
+                i as i64,
+                BTreeMap::new(),
+                serde_json::json!({"index": i}),
+            )
+            .expect("Failed to create action");
+            let hash = action.content_hash.clone();
+            let truth = FoucaultianTruth::new(action, hash, i as i64, "registrar".to_string());
+            archive.archive_chained(truth);
+        }
+        archive
+    }
+
+    #[test]
+    fn test_chain_verifies() {
+        let archive = chained_archive(4);
+        assert!(archive.verify_chain());
+        assert!(archive.verify_all());
+
+        let entries: Vec<_> = archive.chronological().collect();
+        assert!(entries[0].previous_hash.is_none());
+        assert_eq!(
+            entries[2].previous_hash.as_deref(),
+            Some(entries[1].registration_hash.as_str())
+        );
+    }
+
+    #[test]
+    fn test_chain_detects_removed_middle_entry() {
+        let mut archive = chained_archive(4);
+        archive.chronological_index.remove(1);
+        assert!(!archive.verify_chain());
+    }
+
+    #[test]
+    fn test_chain_detects_swapped_entries() {
+        let mut archive = chained_archive(4);
+        archive.chronological_index.swap(1, 2);
+        assert!(!archive.verify_chain());
+    }
+
+    #[test]
+    fn test_chain_detects_relinked_entry() {
+        let mut archive = chained_archive(3);
+        // Re-pointing an entry without recomputing its hash fails verification
+        let hash = archive.chronological_index[2].clone();
+        archive.truths.get_mut(&hash).unwrap().previous_hash = None;
+        assert!(!archive.verify_chain());
+    }
+
     #[test]
     fn test_chronological_order() {
         let mut archive = LatentArchive::new();
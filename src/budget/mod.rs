@@ -33,9 +33,17 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.4.0)
+//! 2026-10-16 - Carlos Eduardo Favini - IntegrityCheck Display/Error and explain()
+//! 2026-10-16 - Carlos Eduardo Favini - Exponential complexity, saturating estimates
+//! 2026-10-16 - Carlos Eduardo Favini - no_std: BudgetGuard (wall clock) requires `std`
+//! 2026-10-16 - Carlos Eduardo Favini - Budget from JSON / environment (BudgetConfigError)
 //! --------------------------
 
-use std::time::{Duration, Instant};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::compat::prelude::*;
 
 // =============================================================================
 // COMPUTATIONAL BUDGET
@@ -186,6 +194,156 @@ impl ComputationalBudget {
     }
 }
 
+// =============================================================================
+// CONFIGURATION (std)
+// =============================================================================
+
+/// Configuration keys: (field / JSON key, environment suffix).
+#[cfg(feature = "std")]
+const CONFIG_KEYS: [(&str, &str); 5] = [
+    ("max_bytes", "MAX_BYTES"),
+    ("max_time_ns", "MAX_TIME_NS"),
+    ("max_heap_bytes", "MAX_HEAP_BYTES"),
+    ("max_iterations", "MAX_ITERATIONS"),
+    ("max_recursion_depth", "MAX_RECURSION_DEPTH"),
+];
+
+/// Error loading a `ComputationalBudget` from configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BudgetConfigError {
+    /// Input is not a JSON object.
+    Malformed(String),
+    /// Key is not a budget field (likely a typo).
+    UnknownKey(String),
+    /// Value is not a valid limit for its key.
+    InvalidValue {
+        /// Offending key (JSON key or environment variable).
+        key: String,
+        /// Value as given.
+        value: String,
+        /// Why it was rejected.
+        reason: &'static str,
+    },
+}
+
+impl core::fmt::Display for BudgetConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Malformed(msg) => write!(f, "Malformed budget config: {}", msg),
+            Self::UnknownKey(key) => write!(f, "Unknown budget key: {}", key),
+            Self::InvalidValue { key, value, reason } => {
+                write!(f, "Invalid value {} for {}: {}", value, key, reason)
+            }
+        }
+    }
+}
+
+impl core::error::Error for BudgetConfigError {}
+
+#[cfg(feature = "std")]
+impl ComputationalBudget {
+    /// Loads a budget from a JSON object.
+    ///
+    /// Keys are the field names (`max_bytes`, `max_time_ns`,
+    /// `max_heap_bytes`, `max_iterations`, `max_recursion_depth`) with
+    /// non-negative integer values. Absent keys keep their defaults;
+    /// unknown keys are rejected. Only `max_time_ns` may be 0 (no timeout).
+    pub fn from_json(json: &str) -> Result<Self, BudgetConfigError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| BudgetConfigError::Malformed(e.to_string()))?;
+        let object = value
+            .as_object()
+            .ok_or_else(|| BudgetConfigError::Malformed("expected a JSON object".to_string()))?;
+
+        if let Some(key) = object.keys().find(|k| !CONFIG_KEYS.iter().any(|(j, _)| j == k)) {
+            return Err(BudgetConfigError::UnknownKey(key.clone()));
+        }
+
+        let mut budget = Self::default();
+        for (key, _) in CONFIG_KEYS {
+            if let Some(raw) = object.get(key) {
+                let parsed = raw.as_u64().ok_or_else(|| BudgetConfigError::InvalidValue {
+                    key: key.to_string(),
+                    value: raw.to_string(),
+                    reason: "expected a non-negative integer",
+                })?;
+                budget.set_limit(key, key, &raw.to_string(), parsed)?;
+            }
+        }
+        Ok(budget)
+    }
+
+    /// Loads a budget from environment variables.
+    ///
+    /// Reads `{prefix}_MAX_BYTES`, `{prefix}_MAX_TIME_NS`,
+    /// `{prefix}_MAX_HEAP_BYTES`, `{prefix}_MAX_ITERATIONS` and
+    /// `{prefix}_MAX_RECURSION_DEPTH` (no separator is added if the
+    /// prefix is empty or already ends with `_`). Unset variables keep
+    /// their defaults; values are validated as in `from_json`.
+    pub fn from_env(prefix: &str) -> Result<Self, BudgetConfigError> {
+        Self::from_lookup(prefix, |key| std::env::var(key))
+    }
+
+    /// Loads a budget from variables resolved by `lookup`.
+    ///
+    /// Same keys and validation as `from_env`; `lookup` has the shape of
+    /// `std::env::var`, so a map or test fixture can stand in for the
+    /// process environment.
+    pub fn from_lookup<F>(prefix: &str, mut lookup: F) -> Result<Self, BudgetConfigError>
+    where
+        F: FnMut(&str) -> Result<String, std::env::VarError>,
+    {
+        let separator = if prefix.is_empty() || prefix.ends_with('_') { "" } else { "_" };
+
+        let mut budget = Self::default();
+        for (field, suffix) in CONFIG_KEYS {
+            let key = format!("{}{}{}", prefix, separator, suffix);
+            let raw = match lookup(&key) {
+                Ok(raw) => raw,
+                Err(std::env::VarError::NotPresent) => continue,
+                Err(std::env::VarError::NotUnicode(raw)) => {
+                    return Err(BudgetConfigError::InvalidValue {
+                        key,
+                        value: raw.to_string_lossy().into_owned(),
+                        reason: "not valid UTF-8",
+                    })
+                }
+            };
+            let parsed = raw.trim().parse::<u64>().map_err(|_| BudgetConfigError::InvalidValue {
+                key: key.clone(),
+                value: raw.clone(),
+                reason: "expected a non-negative integer",
+            })?;
+            budget.set_limit(field, &key, &raw, parsed)?;
+        }
+        Ok(budget)
+    }
+
+    /// Validates and stores one limit; `key` is only used for errors.
+    fn set_limit(&mut self, field: &str, key: &str, raw: &str, value: u64) -> Result<(), BudgetConfigError> {
+        let invalid = |reason| BudgetConfigError::InvalidValue {
+            key: key.to_string(),
+            value: raw.to_string(),
+            reason,
+        };
+        if field == "max_time_ns" {
+            self.max_time_ns = value;
+            return Ok(());
+        }
+        if value == 0 {
+            return Err(invalid("must be non-zero"));
+        }
+        let value = usize::try_from(value).map_err(|_| invalid("exceeds usize"))?;
+        match field {
+            "max_bytes" => self.max_bytes = value,
+            "max_heap_bytes" => self.max_heap_bytes = value,
+            "max_iterations" => self.max_iterations = value,
+            _ => self.max_recursion_depth = value,
+        }
+        Ok(())
+    }
+}
+
 // =============================================================================
 // INTEGRITY CHECK
 // =============================================================================
@@ -261,6 +419,97 @@ impl IntegrityCheck {
     pub fn is_empty(&self) -> bool {
         matches!(self, IntegrityCheck::EmptyInput)
     }
+
+    /// Operator-facing sentence describing the check result.
+    ///
+    /// Sizes and durations are rendered in human units, e.g.
+    /// "Input of 200MB exceeds the 100MB memory budget".
+    pub fn explain(&self) -> String {
+        match self {
+            IntegrityCheck::WithinBudget => {
+                "Input can be processed within the computational budget".to_string()
+            }
+            IntegrityCheck::ExceedsMemory { requested, available } => format!(
+                "Input of {} exceeds the {} memory budget",
+                format_bytes(*requested),
+                format_bytes(*available)
+            ),
+            IntegrityCheck::ExceedsTime { estimated_ns, budget_ns } => format!(
+                "Processing would take an estimated {}, exceeding the {} time budget",
+                format_duration_ns(*estimated_ns),
+                format_duration_ns(*budget_ns)
+            ),
+            IntegrityCheck::NumericalCollapse { reason } => format!(
+                "Input cannot be computed safely: {}",
+                reason.to_string().to_lowercase()
+            ),
+            IntegrityCheck::EmptyInput => "Input is empty; there is nothing to compute".to_string(),
+            IntegrityCheck::ExceedsIterations { estimated, budget } => format!(
+                "Processing would need {} iterations, exceeding the limit of {}",
+                estimated, budget
+            ),
+        }
+    }
+}
+
+impl core::fmt::Display for IntegrityCheck {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IntegrityCheck::WithinBudget => write!(f, "Within budget"),
+            IntegrityCheck::ExceedsMemory { requested, available } => {
+                write!(f, "Exceeds memory: requested={} bytes, available={} bytes", requested, available)
+            }
+            IntegrityCheck::ExceedsTime { estimated_ns, budget_ns } => {
+                write!(f, "Exceeds time: estimated={} ns, budget={} ns", estimated_ns, budget_ns)
+            }
+            IntegrityCheck::NumericalCollapse { reason } => {
+                write!(f, "Numerical collapse: {}", reason)
+            }
+            IntegrityCheck::EmptyInput => write!(f, "Empty input"),
+            IntegrityCheck::ExceedsIterations { estimated, budget } => {
+                write!(f, "Exceeds iterations: estimated={}, budget={}", estimated, budget)
+            }
+        }
+    }
+}
+
+/// `WithinBudget` and `EmptyInput` are not failures; they only implement
+/// `Error` because the enum does. Use `is_err()` to tell them apart.
+impl core::error::Error for IntegrityCheck {}
+
+/// Renders a byte count with a binary unit (B, KB, MB, GB, TB).
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if value.fract() == 0.0 {
+        format!("{}{}", value, UNITS[unit])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+/// Renders nanoseconds with the largest fitting unit (ns, µs, ms, s).
+fn format_duration_ns(ns: u64) -> String {
+    const UNITS: [(u64, &str); 3] = [(1_000_000_000, "s"), (1_000_000, "ms"), (1_000, "µs")];
+
+    for (scale, unit) in UNITS {
+        if ns >= scale {
+            let value = ns as f64 / scale as f64;
+            return if value.fract() == 0.0 {
+                format!("{}{}", value, unit)
+            } else {
+                format!("{:.1}{}", value, unit)
+            };
+        }
+    }
+    format!("{}ns", ns)
 }
 
 /// Types of numerical instability.
@@ -290,8 +539,8 @@ pub enum NumericalIssue {
     SqrtOfNegative,
 }
 
-impl std::fmt::Display for NumericalIssue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for NumericalIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             NumericalIssue::ContainsNaN => write!(f, "Input contains NaN"),
             NumericalIssue::ContainsInfinity => write!(f, "Input contains Infinity"),
@@ -314,22 +563,30 @@ impl std::fmt::Display for NumericalIssue {
 /// It does NOT know what the bytes represent.
 /// It only asks: "Can I process this many bytes without collapse?"
 pub fn check_bytes_budget(bytes: &[u8], budget: &ComputationalBudget) -> IntegrityCheck {
+    check_size_budget(bytes.len(), budget)
+}
+
+/// Checks if an input of `size_bytes` fits within a computational budget.
+///
+/// Same rules as `check_bytes_budget`, for inputs that are not a byte
+/// slice (e.g. decoded f64 samples, 8 bytes each).
+pub fn check_size_budget(size_bytes: usize, budget: &ComputationalBudget) -> IntegrityCheck {
     // Empty input is not an error, just nothing to do
-    if bytes.is_empty() {
+    if size_bytes == 0 {
         return IntegrityCheck::EmptyInput;
     }
 
     // Check memory budget
-    if bytes.len() > budget.max_bytes {
+    if size_bytes > budget.max_bytes {
         return IntegrityCheck::ExceedsMemory {
-            requested: bytes.len(),
+            requested: size_bytes,
             available: budget.max_bytes,
         };
     }
 
     // Estimate heap usage (bytes + processing overhead)
     // Overhead estimate: ~3x input size for intermediate buffers
-    let estimated_heap = bytes.len() * 3;
+    let estimated_heap = size_bytes.saturating_mul(3);
     if estimated_heap > budget.max_heap_bytes {
         return IntegrityCheck::ExceedsMemory {
             requested: estimated_heap,
@@ -418,10 +675,15 @@ pub enum ComplexityClass {
     Quadratic,
     /// O(n³) - Cubic
     Cubic,
+    /// O(2ⁿ) - Exponential (e.g., full joint-profile enumeration)
+    Exponential,
 }
 
 impl ComplexityClass {
     /// Estimates number of operations for a given input size.
+    ///
+    /// Saturates at `usize::MAX` instead of wrapping, so huge estimates
+    /// are always rejected by `check_time_budget`.
     pub fn estimate_operations(&self, n: usize) -> usize {
         match self {
             ComplexityClass::Constant => 1,
@@ -430,10 +692,13 @@ impl ComplexityClass {
             }
             ComplexityClass::Linear => n,
             ComplexityClass::Linearithmic => {
-                if n == 0 { 1 } else { n * (n as f64).log2().ceil() as usize }
+                if n == 0 { 1 } else { n.saturating_mul((n as f64).log2().ceil() as usize) }
             }
             ComplexityClass::Quadratic => n.saturating_mul(n),
             ComplexityClass::Cubic => n.saturating_mul(n).saturating_mul(n),
+            ComplexityClass::Exponential => {
+                2usize.saturating_pow(u32::try_from(n).unwrap_or(u32::MAX))
+            }
         }
     }
 }
@@ -446,6 +711,8 @@ impl ComplexityClass {
 ///
 /// This is for internal use to monitor whether computation stays within budget.
 /// It does NOT know what is being computed - only resource consumption.
+/// Requires `std` (wall-clock time).
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct BudgetGuard {
     budget: ComputationalBudget,
@@ -454,6 +721,7 @@ pub struct BudgetGuard {
     recursion_depth: usize,
 }
 
+#[cfg(feature = "std")]
 impl BudgetGuard {
     /// Creates a new budget guard.
     pub fn new(budget: ComputationalBudget) -> Self {
@@ -509,6 +777,11 @@ impl BudgetGuard {
         self.start_time.elapsed().as_nanos() as u64
     }
 
+    /// Returns the budget being enforced.
+    pub fn budget(&self) -> &ComputationalBudget {
+        &self.budget
+    }
+
     /// Returns iterations used.
     pub fn iterations(&self) -> usize {
         self.iterations_used
@@ -618,6 +891,92 @@ mod tests {
         assert_eq!(ComplexityClass::Quadratic.estimate_operations(100), 10000);
     }
 
+    #[test]
+    fn test_integrity_check_explain() {
+        let failures = [
+            IntegrityCheck::ExceedsMemory {
+                requested: 200 * 1024 * 1024,
+                available: 100 * 1024 * 1024,
+            },
+            IntegrityCheck::ExceedsTime {
+                estimated_ns: 45_000_000_000,
+                budget_ns: 30_000_000_000,
+            },
+            IntegrityCheck::NumericalCollapse {
+                reason: NumericalIssue::ContainsNaN,
+            },
+            IntegrityCheck::ExceedsIterations {
+                estimated: 10_001,
+                budget: 10_000,
+            },
+        ];
+
+        assert_eq!(
+            failures[0].explain(),
+            "Input of 200MB exceeds the 100MB memory budget"
+        );
+
+        let explanations: Vec<String> = failures.iter().map(|f| f.explain()).collect();
+        for (i, explanation) in explanations.iter().enumerate() {
+            assert!(!explanation.is_empty());
+            assert!(explanations[i + 1..].iter().all(|other| other != explanation));
+        }
+
+        // Failures flow through `?` as errors
+        fn fails(check: IntegrityCheck) -> Result<(), Box<dyn std::error::Error>> {
+            Err(check)?;
+            Ok(())
+        }
+        let err = fails(failures[0].clone()).unwrap_err();
+        assert_eq!(err.to_string(), failures[0].to_string());
+    }
+
+    #[test]
+    fn test_integrity_check_display_stable() {
+        assert_eq!(IntegrityCheck::WithinBudget.to_string(), "Within budget");
+        assert_eq!(IntegrityCheck::EmptyInput.to_string(), "Empty input");
+        assert_eq!(
+            IntegrityCheck::ExceedsMemory { requested: 200, available: 100 }.to_string(),
+            "Exceeds memory: requested=200 bytes, available=100 bytes"
+        );
+        assert_eq!(
+            IntegrityCheck::ExceedsTime { estimated_ns: 5, budget_ns: 1 }.to_string(),
+            "Exceeds time: estimated=5 ns, budget=1 ns"
+        );
+        assert_eq!(
+            IntegrityCheck::NumericalCollapse { reason: NumericalIssue::ContainsInfinity }
+                .to_string(),
+            "Numerical collapse: Input contains Infinity"
+        );
+        assert_eq!(
+            IntegrityCheck::ExceedsIterations { estimated: 6, budget: 5 }.to_string(),
+            "Exceeds iterations: estimated=6, budget=5"
+        );
+    }
+
+    #[test]
+    fn test_complexity_saturates() {
+        assert_eq!(ComplexityClass::Exponential.estimate_operations(10), 1024);
+        assert_eq!(ComplexityClass::Exponential.estimate_operations(64), usize::MAX);
+        assert_eq!(ComplexityClass::Exponential.estimate_operations(usize::MAX), usize::MAX);
+        assert_eq!(ComplexityClass::Quadratic.estimate_operations(usize::MAX), usize::MAX);
+        assert_eq!(ComplexityClass::Cubic.estimate_operations(1 << 32), usize::MAX);
+        assert_eq!(ComplexityClass::Linearithmic.estimate_operations(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_exponential_exceeds_time() {
+        let budget = ComputationalBudget::default();
+        let result = check_time_budget(64, ComplexityClass::Exponential, &budget);
+        assert!(matches!(
+            result,
+            IntegrityCheck::ExceedsTime { budget_ns: 30_000_000_000, .. }
+        ));
+
+        // Small exponential work still fits
+        assert!(check_time_budget(16, ComplexityClass::Exponential, &budget).is_ok());
+    }
+
     #[test]
     fn test_budget_guard_iterations() {
         let budget = ComputationalBudget::new().with_max_iterations(5);
@@ -676,4 +1035,80 @@ mod tests {
 
         // The system does NOT reject based on "meaningful perception"
     }
+
+    #[test]
+    fn test_budget_from_partial_json() {
+        let budget = ComputationalBudget::from_json(r#"{"max_bytes": 4096, "max_time_ns": 0}"#).unwrap();
+        let defaults = ComputationalBudget::default();
+
+        assert_eq!(budget.max_bytes, 4096);
+        assert_eq!(budget.max_time_ns, 0);
+        assert_eq!(budget.max_heap_bytes, defaults.max_heap_bytes);
+        assert_eq!(budget.max_iterations, defaults.max_iterations);
+        assert_eq!(budget.max_recursion_depth, defaults.max_recursion_depth);
+
+        assert_eq!(ComputationalBudget::from_json("{}").unwrap(), defaults);
+    }
+
+    #[test]
+    fn test_budget_from_json_rejects_malformed() {
+        let err = ComputationalBudget::from_json(r#"{"max_iterations": "many"}"#).unwrap_err();
+        assert!(matches!(
+            err,
+            BudgetConfigError::InvalidValue { ref key, reason: "expected a non-negative integer", .. }
+                if key == "max_iterations"
+        ));
+
+        let err = ComputationalBudget::from_json(r#"{"max_bytes": 0}"#).unwrap_err();
+        assert!(matches!(err, BudgetConfigError::InvalidValue { reason: "must be non-zero", .. }));
+
+        assert_eq!(
+            ComputationalBudget::from_json(r#"{"max_byte": 1}"#),
+            Err(BudgetConfigError::UnknownKey("max_byte".to_string()))
+        );
+        assert!(matches!(
+            ComputationalBudget::from_json("[1, 2]"),
+            Err(BudgetConfigError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_budget_from_env() {
+        use std::collections::HashMap;
+        use std::env::VarError;
+
+        // A map stands in for the process environment
+        let vars: HashMap<&str, &str> = [
+            ("DGC_TEST_BUDGET_MAX_ITERATIONS", " 250 "),
+            ("DGC_TEST_BUDGET_MAX_TIME_NS", "5000"),
+            ("DGC_TEST_BUDGET_BAD_MAX_RECURSION_DEPTH", "-3"),
+        ]
+        .into_iter()
+        .collect();
+        let lookup = |key: &str| vars.get(key).map(|v| v.to_string()).ok_or(VarError::NotPresent);
+
+        let budget = ComputationalBudget::from_lookup("DGC_TEST_BUDGET", lookup).unwrap();
+        assert_eq!(budget.max_iterations, 250);
+        assert_eq!(budget.max_time_ns, 5000);
+        assert_eq!(budget.max_bytes, ComputationalBudget::default().max_bytes);
+        assert_eq!(ComputationalBudget::from_lookup("DGC_TEST_BUDGET_", lookup).unwrap(), budget);
+
+        let err = ComputationalBudget::from_lookup("DGC_TEST_BUDGET_BAD", lookup).unwrap_err();
+        assert!(matches!(
+            err,
+            BudgetConfigError::InvalidValue { ref key, .. } if key == "DGC_TEST_BUDGET_BAD_MAX_RECURSION_DEPTH"
+        ));
+
+        let not_unicode = |_: &str| Err(VarError::NotUnicode(std::ffi::OsString::from("x")));
+        assert!(matches!(
+            ComputationalBudget::from_lookup("DGC", not_unicode),
+            Err(BudgetConfigError::InvalidValue { reason: "not valid UTF-8", .. })
+        ));
+
+        // Reading only: nothing set under this prefix keeps the defaults
+        assert_eq!(
+            ComputationalBudget::from_env("DGC_TEST_BUDGET_UNSET_7F3A").unwrap(),
+            ComputationalBudget::default()
+        );
+    }
 }
@@ -38,19 +38,21 @@
 
 use crate::sensory::{RawInput, SensoryCortex, CortexOutput};
 use crate::motors::{
-    CognitiveMotor,
+    MotorType, evaluate_motor,
     PraxisMotor, PraxisInput,
     NashMotor, NashInput,
-    ChaosMotor, ChaosInput,
+    ChaosMotor, ChaosInput, DivergenceMethod,
     MeristicMotor, MeristicInput,
+    ClampReport,
 };
 use crate::math::craft::{CraftPerformance, CpResult};
 use crate::memory::{
     MCI, CanonicalContext, CanonicalCodon, Origin,
     EvaluativeSignature, ActivationCondition, ReplayableProvenance,
-    LearningEngine, EpistemicTrigger, LearningResult,
+    LearningEngine, EpistemicTrigger, LearningResult, DEFAULT_STAGNATION_THRESHOLD,
 };
 use crate::cognitive::dna::{StructuredDNA, DnaBuilder, AtomicAction};
+use crate::cognitive::{ObservationReport, MotorSignatures, TransportCode};
 use sha2::{Sha256, Digest};
 use std::collections::BTreeMap;
 
@@ -79,6 +81,11 @@ pub struct CycleOutput {
     pub baseline_cp: f64,
     /// Whether MCI was consulted
     pub mci_consulted: bool,
+    /// Technical certificate of this cycle (L-004)
+    pub report: ObservationReport,
+    /// Motor score clamping in this cycle (audit companion to `report`,
+    /// which keeps its five L-004 fields)
+    pub clamp_report: ClampReport,
 }
 
 /// All four motor scores.
@@ -103,6 +110,14 @@ impl MotorScores {
     }
 }
 
+/// Scores and signatures from one canonical-order motor pass.
+struct MotorEvaluation {
+    scores: MotorScores,
+    nash_applicable: bool,
+    signatures: MotorSignatures,
+    clamps: ClampReport,
+}
+
 /// Context for motor evaluation (provided by GDO).
 #[derive(Debug, Clone)]
 pub struct MotorContext {
@@ -200,7 +215,7 @@ impl CognitiveCycle {
             chaos: ChaosMotor::new(),
             meristic: MeristicMotor::new(),
             mci: MCI::unlimited(),
-            learning: LearningEngine::new(3), // Trigger after 3 stagnations
+            learning: LearningEngine::new(DEFAULT_STAGNATION_THRESHOLD),
             cycle_counter: 0,
         }
     }
@@ -212,6 +227,18 @@ impl CognitiveCycle {
         cycle
     }
     
+    /// Create with custom stagnation threshold (LEI-AF-11-01).
+    pub fn with_stagnation_threshold(threshold: usize) -> Self {
+        let mut cycle = Self::new();
+        cycle.learning = LearningEngine::new(threshold);
+        cycle
+    }
+    
+    /// Get reference to the learning engine (for inspection).
+    pub fn learning(&self) -> &LearningEngine {
+        &self.learning
+    }
+    
     /// Get reference to MCI (for inspection).
     pub fn mci(&self) -> &MCI {
         &self.mci
@@ -269,57 +296,8 @@ impl CognitiveCycle {
         }
 
         // E3: Quadrimotor Evaluation - CANONICAL ORDER
-        
-        // Motor 1/4: Praxis (truth observed)
-        let praxis_input = PraxisInput {
-            proposed: context.proposed.clone(),
-            necessary: context.necessary.clone(),
-            context_vector: context.context_vector.clone(),
-            history_centroid: context.history_centroid.clone(),
-        };
-        let praxis_output = self.praxis.evaluate(&praxis_input);
-
-        // Motor 2/4: Nash (equilibrium, conditional)
-        let (nash_score, nash_applicable) = if context.player_count >= 2 && !context.payoffs.is_empty() {
-            let nash_input = NashInput {
-                num_players: context.player_count,
-                action_sizes: context.action_sizes.clone(),
-                payoffs: context.payoffs.clone(),
-                strategies: context.strategies.clone(),
-                scale: context.scale,
-            };
-            let nash_output = self.nash.evaluate(&nash_input);
-            (nash_output.score, true)
-        } else {
-            (1.0, false)
-        };
-
-        // Motor 3/4: Chaos (robustness)
-        let chaos_input = ChaosInput {
-            reference_trajectory: context.reference_trajectory.clone(),
-            perturbed_trajectory: context.perturbed_trajectory.clone(),
-            delta_0: context.delta_0,
-            dt: context.dt,
-            epsilon_tolerance: None,
-        };
-        let chaos_output = self.chaos.evaluate(&chaos_input);
-
-        // Motor 4/4: Meristic (POSTERIOR - LEI-AF-10-07)
-        let meristic_input = MeristicInput {
-            current_embedding: context.current_embedding.clone(),
-            historical_embeddings: context.historical_embeddings.clone(),
-            domain_characteristics: context.domain_characteristics.clone(),
-            exploration_depth: context.exploration_depth,
-            novelty_weight: context.novelty_weight,
-        };
-        let meristic_output = self.meristic.evaluate(&meristic_input);
-
-        let motor_scores = MotorScores {
-            praxis: praxis_output.score,
-            nash: nash_score,
-            chaos: chaos_output.score,
-            meristic: meristic_output.score,
-        };
+        let MotorEvaluation { scores: motor_scores, nash_applicable, signatures, clamps } =
+            self.evaluate_motors(context);
 
         // E4: Integration - Calculate CP (AF-10.5)
         let cp_result = CraftPerformance::calculate(
@@ -387,6 +365,7 @@ impl CognitiveCycle {
         
         // Legacy DNA fingerprint
         let dna_fingerprint = Self::generate_legacy_dna(&perception, &motor_scores, cp_value);
+        let report = Self::build_report(cycle_id, input_fingerprint, signatures, &structured_dna);
 
         CycleOutput {
             perception,
@@ -400,6 +379,8 @@ impl CognitiveCycle {
             learning_result,
             baseline_cp,
             mci_consulted,
+            report,
+            clamp_report: clamps,
         }
     }
     
@@ -422,52 +403,8 @@ impl CognitiveCycle {
         let origin = if mci_consulted { Origin::Recombined } else { Origin::External };
         
         // Motors evaluation (same as process)
-        let praxis_input = PraxisInput {
-            proposed: context.proposed.clone(),
-            necessary: context.necessary.clone(),
-            context_vector: context.context_vector.clone(),
-            history_centroid: context.history_centroid.clone(),
-        };
-        let praxis_output = self.praxis.evaluate(&praxis_input);
-
-        let (nash_score, nash_applicable) = if context.player_count >= 2 && !context.payoffs.is_empty() {
-            let nash_input = NashInput {
-                num_players: context.player_count,
-                action_sizes: context.action_sizes.clone(),
-                payoffs: context.payoffs.clone(),
-                strategies: context.strategies.clone(),
-                scale: context.scale,
-            };
-            let nash_output = self.nash.evaluate(&nash_input);
-            (nash_output.score, true)
-        } else {
-            (1.0, false)
-        };
-
-        let chaos_input = ChaosInput {
-            reference_trajectory: context.reference_trajectory.clone(),
-            perturbed_trajectory: context.perturbed_trajectory.clone(),
-            delta_0: context.delta_0,
-            dt: context.dt,
-            epsilon_tolerance: None,
-        };
-        let chaos_output = self.chaos.evaluate(&chaos_input);
-
-        let meristic_input = MeristicInput {
-            current_embedding: context.current_embedding.clone(),
-            historical_embeddings: context.historical_embeddings.clone(),
-            domain_characteristics: context.domain_characteristics.clone(),
-            exploration_depth: context.exploration_depth,
-            novelty_weight: context.novelty_weight,
-        };
-        let meristic_output = self.meristic.evaluate(&meristic_input);
-
-        let motor_scores = MotorScores {
-            praxis: praxis_output.score,
-            nash: nash_score,
-            chaos: chaos_output.score,
-            meristic: meristic_output.score,
-        };
+        let MotorEvaluation { scores: motor_scores, nash_applicable, signatures, clamps } =
+            self.evaluate_motors(context);
 
         let cp_result = CraftPerformance::calculate(
             motor_scores.praxis,
@@ -496,6 +433,7 @@ impl CognitiveCycle {
         let structured_dna = builder.build();
         
         let dna_fingerprint = Self::generate_legacy_dna(&perception, &motor_scores, cp_value);
+        let report = Self::build_report(cycle_id, input_fingerprint, signatures, &structured_dna);
 
         CycleOutput {
             perception,
@@ -509,9 +447,200 @@ impl CognitiveCycle {
             learning_result: None,
             baseline_cp,
             mci_consulted,
+            report,
+            clamp_report: clamps,
         }
     }
     
+    /// Evaluate a structured motor context and report the result (L-004).
+    ///
+    /// The frame is the canonical byte encoding of `context`, so the same
+    /// context always yields the same perception, scores, CP, DNA and
+    /// `ObservationReport`. Motors run in canonical order and each output
+    /// is captured as a SHA-256 signature in `MotorSignatures`.
+    ///
+    /// This makes no decisions: nothing is learned, MCI is not modified
+    /// and the CP is reported, never acted upon. Choosing what to do with
+    /// the output is GDO responsibility.
+    pub fn run(&self, context: &MotorContext) -> CycleOutput {
+        let frame = Self::encode_context(context);
+        self.process_readonly(&frame, context)
+    }
+
+    /// Run the four motors in canonical order (LEI-AF-10-07).
+    ///
+    /// Nash is only evaluated with at least two players and a payoff
+    /// table; otherwise it contributes the neutral score 1.0.
+    fn evaluate_motors(&self, context: &MotorContext) -> MotorEvaluation {
+        // Motor 1/4: Praxis (truth observed)
+        let praxis_input = PraxisInput {
+            proposed: context.proposed.clone(),
+            necessary: context.necessary.clone(),
+            context_vector: context.context_vector.clone(),
+            history_centroid: context.history_centroid.clone(),
+        };
+        let praxis_output = evaluate_motor(MotorType::Praxis, &self.praxis, &praxis_input);
+
+        // Motor 2/4: Nash (equilibrium, conditional)
+        let nash_output = if context.player_count >= 2 && !context.payoffs.is_empty() {
+            let nash_input = NashInput {
+                num_players: context.player_count,
+                action_sizes: context.action_sizes.clone(),
+                payoffs: context.payoffs.clone(),
+                strategies: context.strategies.clone(),
+                scale: context.scale,
+            };
+            Some(evaluate_motor(MotorType::Nash, &self.nash, &nash_input))
+        } else {
+            None
+        };
+        let (nash_score, nash_applicable, nash_signature) = match &nash_output {
+            Some(output) => (output.score, true, Self::hash_motor_output(output)),
+            None => (1.0, false, Self::hash_motor_output(&"nash:not-applicable")),
+        };
+
+        // Motor 3/4: Chaos (robustness)
+        let chaos_input = ChaosInput {
+            reference_trajectory: context.reference_trajectory.clone(),
+            perturbed_trajectory: context.perturbed_trajectory.clone(),
+            delta_0: context.delta_0,
+            dt: context.dt,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+        let chaos_output = evaluate_motor(MotorType::Chaos, &self.chaos, &chaos_input);
+
+        // Motor 4/4: Meristic (POSTERIOR - LEI-AF-10-07)
+        let meristic_input = MeristicInput {
+            current_embedding: context.current_embedding.clone(),
+            historical_embeddings: context.historical_embeddings.clone(),
+            domain_characteristics: context.domain_characteristics.clone(),
+            exploration_depth: context.exploration_depth,
+            novelty_weight: context.novelty_weight,
+        };
+        let meristic_output =
+            evaluate_motor(MotorType::Meristic, &self.meristic, &meristic_input);
+
+        MotorEvaluation {
+            scores: MotorScores {
+                praxis: praxis_output.score,
+                nash: nash_score,
+                chaos: chaos_output.score,
+                meristic: meristic_output.score,
+            },
+            nash_applicable,
+            signatures: MotorSignatures {
+                praxis: Self::hash_motor_output(&praxis_output),
+                nash: nash_signature,
+                chaos: Self::hash_motor_output(&chaos_output),
+                meristic: Self::hash_motor_output(&meristic_output),
+            },
+            clamps: ClampReport::from_outputs(
+                &praxis_output,
+                nash_output.as_ref(),
+                &chaos_output,
+                &meristic_output,
+            ),
+        }
+    }
+
+    fn hash_motor_output<T: serde::Serialize>(output: &T) -> [u8; 32] {
+        let bytes = serde_json::to_vec(output).unwrap_or_default();
+        Sha256::digest(&bytes).into()
+    }
+
+    fn build_report(
+        cycle_id: [u8; 16],
+        frame_fingerprint: [u8; 32],
+        motor_signatures: MotorSignatures,
+        dna: &StructuredDNA,
+    ) -> ObservationReport {
+        ObservationReport {
+            cycle_id,
+            frame_fingerprint,
+            protocol_markers: vec![TransportCode::BOF, TransportCode::EOF],
+            motor_signatures,
+            dna_fingerprint: dna.fingerprint,
+        }
+    }
+
+    /// Canonical little-endian encoding of every context field.
+    fn encode_context(context: &MotorContext) -> Vec<u8> {
+        fn put_f64s(out: &mut Vec<u8>, values: &[f64]) {
+            out.extend_from_slice(&(values.len() as u64).to_le_bytes());
+            for v in values {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        fn put_strings(out: &mut Vec<u8>, values: &[String]) {
+            out.extend_from_slice(&(values.len() as u64).to_le_bytes());
+            for v in values {
+                out.extend_from_slice(&(v.len() as u64).to_le_bytes());
+                out.extend_from_slice(v.as_bytes());
+            }
+        }
+
+        let mut out = Vec::new();
+        put_strings(&mut out, &context.proposed);
+        put_strings(&mut out, &context.necessary);
+        put_f64s(&mut out, &context.context_vector);
+        put_f64s(&mut out, &context.history_centroid);
+
+        out.extend_from_slice(&(context.player_count as u64).to_le_bytes());
+        out.extend_from_slice(&(context.action_sizes.len() as u64).to_le_bytes());
+        for size in &context.action_sizes {
+            out.extend_from_slice(&(*size as u64).to_le_bytes());
+        }
+        out.extend_from_slice(&(context.payoffs.len() as u64).to_le_bytes());
+        for row in &context.payoffs {
+            out.extend_from_slice(&(row.len() as u64).to_le_bytes());
+            for p in row {
+                out.extend_from_slice(&p.to_le_bytes());
+            }
+        }
+        out.extend_from_slice(&(context.strategies.len() as u64).to_le_bytes());
+        for row in &context.strategies {
+            out.extend_from_slice(&(row.len() as u64).to_le_bytes());
+            for p in row {
+                out.extend_from_slice(&p.to_le_bytes());
+            }
+        }
+        out.extend_from_slice(&context.scale.to_le_bytes());
+
+        out.extend_from_slice(&(context.reference_trajectory.len() as u64).to_le_bytes());
+        for point in &context.reference_trajectory {
+            put_f64s(&mut out, point);
+        }
+        out.extend_from_slice(&(context.perturbed_trajectory.len() as u64).to_le_bytes());
+        for point in &context.perturbed_trajectory {
+            put_f64s(&mut out, point);
+        }
+        out.extend_from_slice(&context.delta_0.to_le_bytes());
+        out.extend_from_slice(&context.dt.to_le_bytes());
+
+        put_f64s(&mut out, &context.current_embedding);
+        out.extend_from_slice(&(context.historical_embeddings.len() as u64).to_le_bytes());
+        for embedding in &context.historical_embeddings {
+            put_f64s(&mut out, embedding);
+        }
+        match &context.domain_characteristics {
+            Some(map) => {
+                out.push(1);
+                out.extend_from_slice(&(map.len() as u64).to_le_bytes());
+                // BTreeMap iterates in key order
+                for (key, value) in map {
+                    out.extend_from_slice(&(key.len() as u64).to_le_bytes());
+                    out.extend_from_slice(key.as_bytes());
+                    out.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            None => out.push(0),
+        }
+        out.extend_from_slice(&(context.exploration_depth as u64).to_le_bytes());
+        out.extend_from_slice(&context.novelty_weight.to_le_bytes());
+        out
+    }
+
     fn generate_cycle_id(&self, data: &[u8]) -> [u8; 16] {
         let mut hasher = Sha256::new();
         hasher.update(self.cycle_counter.to_le_bytes());
@@ -568,6 +697,7 @@ impl Default for CognitiveCycle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::motors::MotorType;
 
     #[test]
     fn test_complete_cycle() {
@@ -610,6 +740,20 @@ mod tests {
         assert!(output.cp_value >= 0.0 && output.cp_value <= 1.0);
     }
     
+    #[test]
+    fn test_clamp_report_matches_scores() {
+        let cycle = CognitiveCycle::new();
+        let output = cycle.run(&MotorContext::default());
+        let clamps = &output.clamp_report;
+        
+        assert_eq!(clamps.entry(MotorType::Praxis).final_score, output.motor_scores.praxis);
+        assert_eq!(clamps.entry(MotorType::Chaos).final_score, output.motor_scores.chaos);
+        assert_eq!(clamps.entry(MotorType::Meristic).final_score, output.motor_scores.meristic);
+        // Nash not applicable: neutral, never clamped
+        assert_eq!(*clamps.entry(MotorType::Nash), crate::motors::ClampEntry::unclamped(1.0));
+        assert_eq!(clamps.any_clamped(), !clamps.clamped_motors().is_empty());
+    }
+    
     /// AF-11: Test learning integration
     #[test]
     fn test_learning_integration() {
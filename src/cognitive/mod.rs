@@ -22,6 +22,7 @@ pub use dna::{
 };
 
 use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
 
 /// Technical certificate of cognitive cycle (L-004).
 /// 
@@ -40,6 +41,39 @@ pub struct ObservationReport {
     pub dna_fingerprint: [u8; 32],
 }
 
+impl ObservationReport {
+    /// Sort `protocol_markers` by their u16 value (canonical order).
+    pub fn normalize(&mut self) {
+        self.protocol_markers.sort_by_key(|m| m.value());
+    }
+    
+    /// SHA-256 of the whole report in canonical field order.
+    ///
+    /// Order: cycle_id, frame_fingerprint, protocol_markers (sorted by
+    /// u16 value, little-endian), motor signatures (praxis, nash, chaos,
+    /// meristic), dna_fingerprint. Markers are sorted on a copy, so two
+    /// reports with the same markers in different order hash equally
+    /// whether or not `normalize` was called.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut markers: Vec<u16> = self.protocol_markers.iter().map(|m| m.value()).collect();
+        markers.sort_unstable();
+        
+        let mut hasher = Sha256::new();
+        hasher.update(self.cycle_id);
+        hasher.update(self.frame_fingerprint);
+        hasher.update((markers.len() as u64).to_le_bytes());
+        for marker in markers {
+            hasher.update(marker.to_le_bytes());
+        }
+        hasher.update(self.motor_signatures.praxis);
+        hasher.update(self.motor_signatures.nash);
+        hasher.update(self.motor_signatures.chaos);
+        hasher.update(self.motor_signatures.meristic);
+        hasher.update(self.dna_fingerprint);
+        hasher.finalize().into()
+    }
+}
+
 /// Motor vector signatures (hashes, not values).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct MotorSignatures {
@@ -87,8 +121,85 @@ impl TransportCode {
     pub fn is_delimiter(self) -> bool {
         matches!(self, Self::BOF | Self::EOF | Self::BOFR | Self::EOFR)
     }
+    
+    /// Validate that a code sequence is a well-formed frame stream.
+    ///
+    /// # Rules
+    /// - Every envelope opens with BOF and closes with EOF
+    /// - Envelopes may follow one another but never nest
+    /// - BOFR/EOFR appear strictly inside an envelope and are balanced
+    ///   (nesting allowed) before its EOF
+    /// - VERSION/CHECKSUM are only valid inside an envelope
+    /// - An empty sequence has no stimulus (MissingBof)
+    pub fn validate_sequence(codes: &[TransportCode]) -> Result<(), FrameError> {
+        if codes.is_empty() {
+            return Err(FrameError::MissingBof);
+        }
+        
+        let mut in_envelope = false;
+        let mut fragment_depth = 0usize;
+        
+        for (position, &code) in codes.iter().enumerate() {
+            match (code, in_envelope) {
+                (Self::BOF, false) => in_envelope = true,
+                (Self::BOF, true) => return Err(FrameError::UnexpectedCode { code, position }),
+                (_, false) => return Err(FrameError::MissingBof),
+                (Self::EOF, true) => {
+                    if fragment_depth != 0 {
+                        return Err(FrameError::UnbalancedFragment);
+                    }
+                    in_envelope = false;
+                }
+                (Self::BOFR, true) => fragment_depth += 1,
+                (Self::EOFR, true) => {
+                    if fragment_depth == 0 {
+                        return Err(FrameError::UnbalancedFragment);
+                    }
+                    fragment_depth -= 1;
+                }
+                (Self::VERSION | Self::CHECKSUM, true) => {}
+            }
+        }
+        
+        if in_envelope {
+            if fragment_depth != 0 {
+                return Err(FrameError::UnbalancedFragment);
+            }
+            return Err(FrameError::MissingEof);
+        }
+        
+        Ok(())
+    }
 }
 
+/// Frame sequence errors (L-008).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    /// Code appeared outside a BOF/EOF envelope (or sequence empty)
+    MissingBof,
+    /// Envelope opened with BOF but never closed with EOF
+    MissingEof,
+    /// BOFR/EOFR not balanced within the envelope
+    UnbalancedFragment,
+    /// Code not allowed at this position (e.g. nested BOF)
+    UnexpectedCode { code: TransportCode, position: usize },
+}
+
+impl std::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameError::MissingBof => write!(f, "Missing BOF before frame content"),
+            FrameError::MissingEof => write!(f, "Missing EOF after frame content"),
+            FrameError::UnbalancedFragment => write!(f, "Unbalanced BOFR/EOFR fragment markers"),
+            FrameError::UnexpectedCode { code, position } => {
+                write!(f, "Unexpected {:?} at position {}", code, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
 // PerceptualFrame: Lives in GDO, not GDC. BOF/EOF delimit its transmission.
 
 #[cfg(test)]
@@ -107,6 +218,93 @@ mod tests {
         assert_eq!(r.protocol_markers.len(), 2);
     }
 
+    fn sample_report() -> ObservationReport {
+        ObservationReport {
+            cycle_id: [1; 16],
+            frame_fingerprint: [2; 32],
+            protocol_markers: vec![TransportCode::BOF, TransportCode::BOFR, TransportCode::EOFR, TransportCode::EOF],
+            motor_signatures: MotorSignatures { praxis: [3; 32], nash: [4; 32], chaos: [5; 32], meristic: [6; 32] },
+            dna_fingerprint: [7; 32],
+        }
+    }
+
+    #[test]
+    fn test_report_fingerprint_marker_order_independent() {
+        let a = sample_report();
+        let mut b = sample_report();
+        b.protocol_markers.reverse();
+        assert_ne!(a, b);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+
+        b.normalize();
+        assert_eq!(b.protocol_markers[0], TransportCode::BOF);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+    }
+
+    #[test]
+    fn test_report_fingerprint_sensitive_to_every_field() {
+        let base = sample_report().fingerprint();
+
+        let mut r = sample_report();
+        r.cycle_id[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.frame_fingerprint[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.protocol_markers.push(TransportCode::VERSION);
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.motor_signatures.praxis[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.motor_signatures.nash[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.motor_signatures.chaos[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.motor_signatures.meristic[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+
+        let mut r = sample_report();
+        r.dna_fingerprint[0] ^= 1;
+        assert_ne!(r.fingerprint(), base);
+    }
+
+    #[test]
+    fn test_validate_sequence_valid() {
+        use TransportCode::*;
+        assert_eq!(TransportCode::validate_sequence(&[BOF, BOFR, EOFR, EOF]), Ok(()));
+        assert_eq!(TransportCode::validate_sequence(&[BOF, EOF, BOF, VERSION, EOF]), Ok(()));
+        assert_eq!(TransportCode::validate_sequence(&[BOF, BOFR, BOFR, EOFR, EOFR, EOF]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_sequence_unbalanced_fragment() {
+        use TransportCode::*;
+        assert_eq!(TransportCode::validate_sequence(&[BOF, BOFR, EOF]), Err(FrameError::UnbalancedFragment));
+        assert_eq!(TransportCode::validate_sequence(&[BOF, EOFR, EOF]), Err(FrameError::UnbalancedFragment));
+    }
+
+    #[test]
+    fn test_validate_sequence_missing_markers() {
+        use TransportCode::*;
+        assert_eq!(TransportCode::validate_sequence(&[BOFR, EOFR, EOF]), Err(FrameError::MissingBof));
+        assert_eq!(TransportCode::validate_sequence(&[]), Err(FrameError::MissingBof));
+        assert_eq!(TransportCode::validate_sequence(&[BOF, BOFR, EOFR]), Err(FrameError::MissingEof));
+        assert_eq!(
+            TransportCode::validate_sequence(&[BOF, BOF, EOF]),
+            Err(FrameError::UnexpectedCode { code: BOF, position: 1 })
+        );
+    }
+
     #[test]
     fn test_transport_code_roundtrip() {
         assert_eq!(TransportCode::from_value(0x0001), Some(TransportCode::BOF));
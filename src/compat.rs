@@ -0,0 +1,101 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: std / no_std Compatibility
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 0.7.0
+//! Description: Crate-private prelude for the modules that also build
+//! without `std` (motors, budget checks, correlation math).
+//! With `std` it re-exports the usual alloc types; without it, it adds
+//! `libm`-backed float methods that `core` does not provide.
+//! Layer: Community
+//! Dependencies: alloc, libm (no_std only)
+//! Affected Components: motors, budget, correlation
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation (no_std carve-out)
+//! --------------------------
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("building without `std` requires the `libm` feature for float math");
+
+/// Items glob-imported by no_std-capable modules.
+pub(crate) mod prelude {
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+
+    pub use super::HashMap;
+
+    #[cfg(not(feature = "std"))]
+    pub use super::FloatMath;
+}
+
+/// `std::collections::HashMap`, or an ordered map without `std`.
+#[cfg(feature = "std")]
+pub use std::collections::HashMap;
+
+/// `std::collections::HashMap`, or an ordered map without `std`.
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::BTreeMap as HashMap;
+
+/// Float methods that live in `std`, backed by `libm` without it.
+///
+/// Method names match the inherent `f64` methods, so call sites are
+/// identical in both builds. Results may differ from `std` in the last
+/// bit, so replay fingerprints are only comparable within one build.
+#[cfg(not(feature = "std"))]
+pub trait FloatMath {
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn exp(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+impl FloatMath for f64 {
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+
+    fn log2(self) -> Self {
+        libm::log2(self)
+    }
+
+    fn exp(self) -> Self {
+        libm::exp(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        libm::pow(self, f64::from(n))
+    }
+
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+
+    fn ceil(self) -> Self {
+        libm::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+
+    fn fract(self) -> Self {
+        self - libm::trunc(self)
+    }
+}
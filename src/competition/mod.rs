@@ -28,59 +28,39 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - MotorDynamicsSequence temporal stability
+//! 2026-10-16 - Carlos Eduardo Favini - RelevanceMode with information-gain relevance
+//! 2026-10-16 - Carlos Eduardo Favini - MotorDynamics::to_dashboard_json
+//! 2026-10-16 - Carlos Eduardo Favini - Dominance history is a BoundedHistory
+//! 2026-10-16 - Carlos Eduardo Favini - Configurable dominance floor
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
-/// Identifier for the four cognitive motors.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum MotorType {
-    /// Praxeological motor (M_P)
-    Praxis,
-    /// Nash equilibrium motor (M_N)
-    Nash,
-    /// Chaos/stability motor (M_C)
-    Chaos,
-    /// Meristic generation motor (M_M)
-    Meristic,
+use crate::core_types::BoundedHistory;
+pub use crate::motors::MotorType;
+
+/// How `MotorCompetition` measures the relevance of each motor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RelevanceMode {
+    /// Distance from the neutral score 0.5 (0.0 or 1.0 = maximum relevance).
+    #[default]
+    DistanceFromNeutral,
+
+    /// Reduction of ensemble uncertainty (score variance) the motor provides.
+    ///
+    /// Compares the variance of the other three motors with the variance
+    /// once this motor joins them. A motor that confirms the ensemble
+    /// reduces uncertainty and scores up to 1.0; an outlier that adds
+    /// uncertainty scores 0.0.
+    InformationGain,
 }
 
-impl MotorType {
-    /// Returns all motor types in canonical order.
-    pub fn all() -> [MotorType; 4] {
-        [Self::Praxis, Self::Nash, Self::Chaos, Self::Meristic]
-    }
-
-    /// Returns the index of this motor (0-3).
-    pub fn index(&self) -> usize {
-        match self {
-            Self::Praxis => 0,
-            Self::Nash => 1,
-            Self::Chaos => 2,
-            Self::Meristic => 3,
-        }
-    }
-
-    /// Returns the motor type from an index.
-    pub fn from_index(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::Praxis),
-            1 => Some(Self::Nash),
-            2 => Some(Self::Chaos),
-            3 => Some(Self::Meristic),
-            _ => None,
-        }
-    }
+/// Default minimum relevance for a motor to be declared dominant.
+pub const DEFAULT_DOMINANCE_FLOOR: f64 = 0.1;
 
-    /// Returns the name of the motor.
-    pub fn name(&self) -> &'static str {
-        match self {
-            Self::Praxis => "Praxis",
-            Self::Nash => "Nash",
-            Self::Chaos => "Chaos",
-            Self::Meristic => "Meristic",
-        }
-    }
+fn default_dominance_floor() -> f64 {
+    DEFAULT_DOMINANCE_FLOOR
 }
 
 /// Tracks the competition dynamics between motors.
@@ -93,7 +73,8 @@ pub struct MotorCompetition {
     /// Higher = motor provides more discrimination
     pub motor_relevance: [f64; 4],
 
-    /// Which motor "won" this cycle (most informative)
+    /// Which motor "won" this cycle (most informative).
+    /// `None` when no relevance reaches `dominance_floor`.
     pub dominant_motor: Option<MotorType>,
 
     /// Consensus score: how much motors agree (0 = total disagreement, 1 = perfect agreement)
@@ -103,14 +84,42 @@ pub struct MotorCompetition {
     pub score_variance: f64,
 
     /// History of dominance (for tracking patterns)
-    pub dominance_history: Vec<MotorType>,
+    pub dominance_history: BoundedHistory<MotorType, 100>,
+
+    /// How `motor_relevance` is computed
+    #[serde(default)]
+    pub relevance_mode: RelevanceMode,
+
+    /// Minimum relevance for a motor to be declared dominant
+    #[serde(default = "default_dominance_floor")]
+    pub dominance_floor: f64,
 }
 
 impl MotorCompetition {
     /// Creates a new competition analysis from motor scores.
     pub fn from_scores(scores: [f64; 4]) -> Self {
-        let relevance = Self::compute_relevance(&scores);
-        let dominant = Self::find_dominant(&relevance);
+        Self::from_scores_with_mode(scores, RelevanceMode::default())
+    }
+
+    /// Creates a competition analysis with an explicit relevance mode.
+    ///
+    /// The mode is kept for subsequent `update` calls.
+    pub fn from_scores_with_mode(scores: [f64; 4], mode: RelevanceMode) -> Self {
+        Self::from_scores_with_settings(scores, mode, DEFAULT_DOMINANCE_FLOOR)
+    }
+
+    /// Creates a competition analysis with an explicit dominance floor.
+    ///
+    /// If no motor's relevance reaches `floor`, `dominant_motor` is
+    /// `None` regardless of how the motors rank against each other. The
+    /// floor is kept for subsequent `update` calls.
+    pub fn from_scores_with_floor(scores: [f64; 4], floor: f64) -> Self {
+        Self::from_scores_with_settings(scores, RelevanceMode::default(), floor)
+    }
+
+    fn from_scores_with_settings(scores: [f64; 4], mode: RelevanceMode, floor: f64) -> Self {
+        let relevance = Self::compute_relevance(&scores, mode);
+        let dominant = Self::find_dominant(&relevance, floor);
         let consensus = Self::compute_consensus(&scores);
         let variance = Self::compute_variance(&scores);
 
@@ -121,30 +130,36 @@ impl MotorCompetition {
             consensus_score: consensus,
             score_variance: variance,
             dominance_history: dominant.into_iter().collect(),
+            relevance_mode: mode,
+            dominance_floor: floor,
         }
     }
 
     /// Updates with new scores, tracking history.
     pub fn update(&mut self, scores: [f64; 4]) {
         self.current_scores = scores;
-        self.motor_relevance = Self::compute_relevance(&scores);
-        self.dominant_motor = Self::find_dominant(&self.motor_relevance);
+        self.motor_relevance = Self::compute_relevance(&scores, self.relevance_mode);
+        self.dominant_motor = Self::find_dominant(&self.motor_relevance, self.dominance_floor);
         self.consensus_score = Self::compute_consensus(&scores);
         self.score_variance = Self::compute_variance(&scores);
 
         if let Some(dominant) = self.dominant_motor {
+            // Keeps only the last 100 entries
             self.dominance_history.push(dominant);
-            // Keep only last 100 entries
-            if self.dominance_history.len() > 100 {
-                self.dominance_history.remove(0);
-            }
         }
     }
 
-    /// Computes relevance of each motor.
+    /// Computes relevance of each motor under the given mode.
+    fn compute_relevance(scores: &[f64; 4], mode: RelevanceMode) -> [f64; 4] {
+        match mode {
+            RelevanceMode::DistanceFromNeutral => Self::distance_relevance(scores),
+            RelevanceMode::InformationGain => Self::information_gain_relevance(scores),
+        }
+    }
+
     /// Relevance is based on how much the motor deviates from neutral (0.5).
     /// A motor that always returns 0.5 provides no information.
-    fn compute_relevance(scores: &[f64; 4]) -> [f64; 4] {
+    fn distance_relevance(scores: &[f64; 4]) -> [f64; 4] {
         let mut relevance = [0.0; 4];
         for (i, &score) in scores.iter().enumerate() {
             // Distance from neutral (0.5)
@@ -155,12 +170,40 @@ impl MotorCompetition {
         relevance
     }
 
+    /// Relevance is the relative variance reduction a motor contributes.
+    ///
+    /// With `m` and `v` the mean and variance of the other three scores,
+    /// adding score `x` gives `var_all / v = 3/4 + 3(x - m)^2 / (16 v)`.
+    /// The reduction `1 - var_all / v` is therefore at most 1/4 (when
+    /// `x == m`); it is rescaled to [0, 1] and clamped at 0 for motors
+    /// that increase the variance. If the other three already agree
+    /// (`v == 0`) there is no uncertainty to reduce and relevance is 0.
+    fn information_gain_relevance(scores: &[f64; 4]) -> [f64; 4] {
+        let mut relevance = [0.0; 4];
+        for (i, &score) in scores.iter().enumerate() {
+            let others: Vec<f64> = scores
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &s)| s)
+                .collect();
+            let mean = others.iter().sum::<f64>() / 3.0;
+            let variance = others.iter().map(|&s| (s - mean).powi(2)).sum::<f64>() / 3.0;
+
+            if variance > f64::EPSILON {
+                let reduction = 1.0 - 3.0 * (score - mean).powi(2) / (4.0 * variance);
+                relevance[i] = reduction.clamp(0.0, 1.0);
+            }
+        }
+        relevance
+    }
+
     /// Finds the dominant motor (highest relevance).
-    fn find_dominant(relevance: &[f64; 4]) -> Option<MotorType> {
+    fn find_dominant(relevance: &[f64; 4], floor: f64) -> Option<MotorType> {
         let max_relevance = relevance.iter().cloned().fold(0.0_f64, f64::max);
 
         // Only declare dominance if relevance is significant
-        if max_relevance < 0.1 {
+        if max_relevance < floor {
             return None;
         }
 
@@ -404,6 +447,147 @@ impl MotorDynamics {
             health,
         }
     }
+
+    /// Flat JSON view for dashboards (presentation only).
+    ///
+    /// Motors are keyed by name, alliances are listed strongest first,
+    /// and consensus / variance sit at the top level:
+    ///
+    /// ```text
+    /// { "consensus", "variance", "dominant_motor",
+    ///   "motors": { "<name>": { "score", "relevance", "dominance_ratio" } },
+    ///   "alliances": [ { "motors": [a, b], "agreement" } ],
+    ///   "clusters": [ { "motors": [...], "internal_agreement" } ],
+    ///   "dominance_history": [...], "health": { ... } }
+    /// ```
+    pub fn to_dashboard_json(&self) -> String {
+        let competition = &self.competition;
+        let ratios = competition.dominance_ratios();
+
+        let motors: serde_json::Map<String, serde_json::Value> = MotorType::all()
+            .iter()
+            .map(|motor| {
+                let i = motor.index();
+                (
+                    motor.name().to_string(),
+                    serde_json::json!({
+                        "score": competition.current_scores[i],
+                        "relevance": competition.motor_relevance[i],
+                        "dominance_ratio": ratios[i],
+                    }),
+                )
+            })
+            .collect();
+
+        let alliances: Vec<serde_json::Value> = self
+            .cooperation
+            .strongest_alliances()
+            .into_iter()
+            .map(|(a, b, agreement)| {
+                serde_json::json!({ "motors": [a.name(), b.name()], "agreement": agreement })
+            })
+            .collect();
+
+        let clusters: Vec<serde_json::Value> = self
+            .cooperation
+            .clusters
+            .iter()
+            .map(|c| {
+                let names: Vec<&str> = c.motors.iter().map(MotorType::name).collect();
+                serde_json::json!({ "motors": names, "internal_agreement": c.internal_agreement })
+            })
+            .collect();
+
+        let history: Vec<&str> = competition.dominance_history.iter().map(MotorType::name).collect();
+
+        serde_json::json!({
+            "consensus": competition.consensus_score,
+            "variance": competition.score_variance,
+            "dominant_motor": competition.dominant_motor.map(|m| m.name()),
+            "motors": motors,
+            "alliances": alliances,
+            "clusters": clusters,
+            "dominance_history": history,
+            "health": {
+                "balanced": self.health.balanced,
+                "monopoly_risk": self.health.monopoly_risk,
+                "unstable": self.health.unstable,
+            },
+        })
+        .to_string()
+    }
+}
+
+/// Motor dynamics observed across a sequence of score vectors.
+///
+/// `MotorDynamics::analyze` sees a single snapshot, so its `unstable` flag
+/// can only reflect disagreement. This tracker feeds successive scores
+/// through `MotorCompetition::update` and derives instability from how
+/// often the dominant motor flips.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MotorDynamicsSequence {
+    /// Rolling competition (holds the dominance history).
+    pub competition: MotorCompetition,
+
+    /// Cooperation analysis of the latest scores.
+    pub cooperation: MotorCooperation,
+
+    /// 1 - (dominance flips / transitions) over the history.
+    /// 1.0 = same dominant motor throughout, 0.0 = flips every cycle.
+    pub temporal_stability: f64,
+
+    /// Health derived from the latest snapshot and the history.
+    pub health: DynamicsHealth,
+}
+
+impl MotorDynamicsSequence {
+    /// Temporal stability below which the dynamics are considered unstable.
+    pub const UNSTABLE_BELOW: f64 = 0.5;
+
+    /// Starts a sequence from the first score vector.
+    pub fn from_scores(scores: [f64; 4]) -> Self {
+        let competition = MotorCompetition::from_scores(scores);
+        let cooperation = MotorCooperation::from_scores(&scores);
+        let temporal_stability = Self::compute_temporal_stability(&competition.dominance_history);
+        let health = Self::compute_health(&competition, temporal_stability);
+
+        Self {
+            competition,
+            cooperation,
+            temporal_stability,
+            health,
+        }
+    }
+
+    /// Ingests the next score vector.
+    pub fn ingest(&mut self, scores: [f64; 4]) {
+        self.competition.update(scores);
+        self.cooperation = MotorCooperation::from_scores(&scores);
+        self.temporal_stability =
+            Self::compute_temporal_stability(&self.competition.dominance_history);
+        self.health = Self::compute_health(&self.competition, self.temporal_stability);
+    }
+
+    /// Fraction of consecutive dominance entries that did not flip.
+    ///
+    /// Returns 1.0 with fewer than two entries (no transitions observed).
+    fn compute_temporal_stability(history: &BoundedHistory<MotorType, 100>) -> f64 {
+        if history.len() < 2 {
+            return 1.0;
+        }
+
+        let flips = history.iter().zip(history.iter().skip(1)).filter(|(a, b)| a != b).count();
+        1.0 - flips as f64 / (history.len() - 1) as f64
+    }
+
+    /// Snapshot balance/monopoly plus history-based instability.
+    fn compute_health(competition: &MotorCompetition, temporal_stability: f64) -> DynamicsHealth {
+        DynamicsHealth {
+            balanced: competition.consensus_score > 0.6 && !competition.is_strongly_dominant(),
+            monopoly_risk: competition.is_strongly_dominant(),
+            unstable: temporal_stability < Self::UNSTABLE_BELOW,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -448,6 +632,67 @@ mod tests {
         assert!(competition.is_strongly_dominant());
     }
 
+    #[test]
+    fn test_dominance_floor() {
+        // Nash is the most relevant motor, at 0.16
+        let scores = [0.52, 0.58, 0.47, 0.5];
+
+        let default = MotorCompetition::from_scores(scores);
+        assert_eq!(default.dominance_floor, DEFAULT_DOMINANCE_FLOOR);
+        let explicit = MotorCompetition::from_scores_with_floor(scores, DEFAULT_DOMINANCE_FLOOR);
+        assert_eq!(default.dominant_motor, explicit.dominant_motor);
+        assert_eq!(default.dominant_motor, Some(MotorType::Nash));
+
+        let low = MotorCompetition::from_scores_with_floor(scores, 0.05);
+        assert_eq!(low.dominant_motor, Some(MotorType::Nash));
+
+        let mut high = MotorCompetition::from_scores_with_floor(scores, 0.2);
+        assert_eq!(high.dominant_motor, None);
+        assert!(high.dominance_history.is_empty());
+
+        // The floor persists across updates
+        high.update([0.55, 0.5, 0.5, 0.5]);
+        assert_eq!(high.dominant_motor, None);
+        high.update([0.9, 0.5, 0.5, 0.5]);
+        assert_eq!(high.dominant_motor, Some(MotorType::Praxis));
+
+        // Serialized competitions from before the floor existed use the default
+        let mut json = serde_json::to_value(&default).unwrap();
+        json.as_object_mut().unwrap().remove("dominance_floor");
+        let restored: MotorCompetition = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.dominance_floor, DEFAULT_DOMINANCE_FLOOR);
+    }
+
+    #[test]
+    fn test_relevance_modes_disagree_on_dominant() {
+        // Praxis is the most extreme score but the ensemble outlier;
+        // Nash sits closest to the others and reduces uncertainty most.
+        let scores = [0.95, 0.6, 0.55, 0.5];
+
+        let distance = MotorCompetition::from_scores(scores);
+        assert_eq!(distance.relevance_mode, RelevanceMode::DistanceFromNeutral);
+        assert_eq!(distance.dominant_motor, Some(MotorType::Praxis));
+
+        let gain = MotorCompetition::from_scores_with_mode(scores, RelevanceMode::InformationGain);
+        assert_eq!(gain.dominant_motor, Some(MotorType::Nash));
+        assert_eq!(gain.motor_relevance[0], 0.0);
+        assert!(gain.motor_relevance.iter().all(|r| (0.0..=1.0).contains(r)));
+        // Consensus and variance do not depend on the mode
+        assert_eq!(gain.consensus_score, distance.consensus_score);
+    }
+
+    #[test]
+    fn test_information_gain_mode_persists_across_updates() {
+        let mut competition =
+            MotorCompetition::from_scores_with_mode([0.5; 4], RelevanceMode::InformationGain);
+        // No ensemble uncertainty: nothing to reduce, no dominance
+        assert_eq!(competition.motor_relevance, [0.0; 4]);
+        assert_eq!(competition.dominant_motor, None);
+
+        competition.update([0.95, 0.6, 0.55, 0.5]);
+        assert_eq!(competition.dominant_motor, Some(MotorType::Nash));
+    }
+
     #[test]
     fn test_cooperation_high_agreement() {
         // Praxis=0.9, Nash=0.9 (agree), Chaos=0.3, Meristic=0.3 (agree)
@@ -483,4 +728,61 @@ mod tests {
         let ratios = competition.dominance_ratios();
         assert!(ratios[0] > ratios[1]); // Praxis dominated more
     }
+
+    #[test]
+    fn test_dashboard_json_shape() {
+        let dynamics = MotorDynamics::analyze([0.9, 0.85, 0.2, 0.5]);
+        let json: serde_json::Value = serde_json::from_str(&dynamics.to_dashboard_json()).unwrap();
+
+        for name in ["Praxis", "Nash", "Chaos", "Meristic"] {
+            assert!(json["motors"][name]["score"].is_number());
+            assert!(json["motors"][name]["relevance"].is_number());
+        }
+        assert_eq!(json["motors"]["Nash"]["score"], 0.85);
+        assert_eq!(json["consensus"], dynamics.competition.consensus_score);
+        assert_eq!(json["variance"], dynamics.competition.score_variance);
+
+        // Praxis and Nash agree most, so their alliance comes first
+        let alliances = json["alliances"].as_array().unwrap();
+        assert_eq!(alliances.len(), 6);
+        assert_eq!(alliances[0]["motors"], serde_json::json!(["Praxis", "Nash"]));
+        let agreements: Vec<f64> = alliances.iter().map(|a| a["agreement"].as_f64().unwrap()).collect();
+        assert!(agreements.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_sequence_alternating_dominance_is_unstable() {
+        let mut sequence = MotorDynamicsSequence::from_scores([1.0, 0.5, 0.5, 0.5]);
+        for i in 0..9 {
+            if i % 2 == 0 {
+                sequence.ingest([0.5, 1.0, 0.5, 0.5]);
+            } else {
+                sequence.ingest([1.0, 0.5, 0.5, 0.5]);
+            }
+        }
+
+        assert_eq!(sequence.competition.dominance_history.len(), 10);
+        assert!(sequence.temporal_stability < 0.01);
+        assert!(sequence.health.unstable);
+    }
+
+    #[test]
+    fn test_sequence_steady_dominance_is_stable() {
+        let mut sequence = MotorDynamicsSequence::from_scores([0.95, 0.5, 0.6, 0.5]);
+        for _ in 0..9 {
+            sequence.ingest([0.95, 0.5, 0.6, 0.5]);
+        }
+
+        assert!((sequence.temporal_stability - 1.0).abs() < 1e-12);
+        assert!(!sequence.health.unstable);
+        assert_eq!(sequence.competition.most_frequent_dominant(), Some(MotorType::Praxis));
+    }
+
+    #[test]
+    fn test_sequence_single_snapshot_is_stable() {
+        // High disagreement alone does not make a single snapshot unstable
+        let sequence = MotorDynamicsSequence::from_scores([0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(sequence.temporal_stability, 1.0);
+        assert!(!sequence.health.unstable);
+    }
 }
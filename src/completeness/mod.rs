@@ -8,7 +8,7 @@
 //! Description: Accepting incompleteness as a valid cognitive state.
 //! "Thinking is not concluding - it is sustaining ambiguities."
 //! Layer: Community
-//! Dependencies: sensory
+//! Dependencies: sensory, hierarchy (MotorScores), motors (MotorType), budget (IntegrityCheck)
 //! Affected Components: cognitive output
 //!
 //! --------------------------
@@ -27,10 +27,19 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Motor/signal consistency validator
+//! 2026-10-16 - Carlos Eduardo Favini - certainty_index for ranking perceptions
+//! 2026-10-16 - Carlos Eduardo Favini - From<IntegrityCheck> (budget failures as Partial)
+//! 2026-10-16 - Carlos Eduardo Favini - from_integrity_check replaces From (WithinBudget is None)
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
+use crate::budget::IntegrityCheck;
+use crate::hierarchy::MotorScores;
+use crate::motors::MotorType;
+use crate::sensory::SensorySignals;
+
 /// Abstraction levels that can be complete or incomplete.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AbstractionLevel {
@@ -81,6 +90,8 @@ pub enum MissingSignal {
     StatisticalTestInconclusive,
     /// Conflicting indicators
     ConflictingIndicators,
+    /// Analysis not run: the computational budget was exceeded
+    BudgetExceeded,
 }
 
 /// Types of cognitive conflicts.
@@ -101,6 +112,24 @@ pub enum ConflictType {
     AmbiguousProtoAgency,
 }
 
+/// Discount applied per missing signal in `certainty_index`.
+pub const MISSING_SIGNAL_DISCOUNT: f64 = 0.1;
+
+/// Largest `certainty_index` a contradictory perception can reach.
+pub const CONTRADICTION_CEILING: f64 = 0.1;
+
+/// Fraction of the abstraction levels present in `levels` (duplicates count once).
+fn level_coverage<'a>(levels: impl IntoIterator<Item = &'a AbstractionLevel>) -> f64 {
+    let all = AbstractionLevel::all();
+    let mut seen = [false; 4];
+    for level in levels {
+        if let Some(i) = all.iter().position(|l| l == level) {
+            seen[i] = true;
+        }
+    }
+    seen.iter().filter(|&&s| s).count() as f64 / all.len() as f64
+}
+
 /// The completeness state of a cognitive perception.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CognitiveCompleteness {
@@ -155,6 +184,33 @@ impl CognitiveCompleteness {
         }
     }
 
+    /// Expresses a failed budget check as a completeness state.
+    ///
+    /// A failed check stops perception before any level runs, so every
+    /// failure is `Partial` with no completed levels and all levels
+    /// inconclusive; only the missing signals differ:
+    ///
+    /// - `EmptyInput` → `InsufficientSamples`
+    /// - `NumericalCollapse` → `EntropyInconclusive`, `StatisticalTestInconclusive`
+    /// - `ExceedsMemory` / `ExceedsTime` / `ExceedsIterations` → `BudgetExceeded`
+    ///
+    /// `WithinBudget` gives `None`: the check only admits the perception,
+    /// it says nothing about how complete it will be.
+    pub fn from_integrity_check(check: &IntegrityCheck) -> Option<Self> {
+        let missing = match check {
+            IntegrityCheck::WithinBudget => return None,
+            IntegrityCheck::EmptyInput => vec![MissingSignal::InsufficientSamples],
+            IntegrityCheck::NumericalCollapse { .. } => vec![
+                MissingSignal::EntropyInconclusive,
+                MissingSignal::StatisticalTestInconclusive,
+            ],
+            IntegrityCheck::ExceedsMemory { .. }
+            | IntegrityCheck::ExceedsTime { .. }
+            | IntegrityCheck::ExceedsIterations { .. } => vec![MissingSignal::BudgetExceeded],
+        };
+        Some(Self::partial(Vec::new(), AbstractionLevel::all().to_vec(), missing))
+    }
+
     /// Creates a contradictory state.
     pub fn contradictory(conflict: ConflictType, affected: Vec<AbstractionLevel>) -> Self {
         Self::Contradictory {
@@ -203,6 +259,36 @@ impl CognitiveCompleteness {
         }
     }
 
+    /// Single [0, 1] certainty value for ranking perceptions.
+    ///
+    /// Unlike `confidence`, it weighs what is missing and what conflicts.
+    /// With f = fraction of the four levels involved:
+    ///
+    /// - Complete: 1
+    /// - Partial: f_completed × (1 - MISSING_SIGNAL_DISCOUNT)^missing_signals
+    /// - Provisional: confidence × (1 + f_tentative) / 2
+    /// - Contradictory: CONTRADICTION_CEILING × (1 - f_affected)
+    ///
+    /// A contradiction therefore always ranks below any provisional or
+    /// partial result with a substantial share of the levels.
+    pub fn certainty_index(&self) -> f64 {
+        let index = match self {
+            Self::Complete => 1.0,
+            Self::Partial { completed_levels, missing_signals, .. } => {
+                level_coverage(completed_levels)
+                    * (1.0 - MISSING_SIGNAL_DISCOUNT).powi(missing_signals.len() as i32)
+            }
+            Self::Provisional { confidence, tentative_results, .. } => {
+                let coverage = level_coverage(tentative_results.iter().map(|(level, _)| level));
+                confidence * (1.0 + coverage) / 2.0
+            }
+            Self::Contradictory { affected_levels, .. } => {
+                CONTRADICTION_CEILING * (1.0 - level_coverage(affected_levels))
+            }
+        };
+        index.clamp(0.0, 1.0)
+    }
+
     /// Returns a human-readable summary.
     pub fn summary(&self) -> String {
         match self {
@@ -228,6 +314,80 @@ impl CognitiveCompleteness {
     }
 }
 
+/// A normalized value at or above this is a strong claim.
+pub const STRONG_CLAIM_THRESHOLD: f64 = 0.8;
+
+/// A normalized value at or below this is a strong negative claim.
+pub const WEAK_CLAIM_THRESHOLD: f64 = 0.2;
+
+/// Detects structural claims that contradict each other for one perception.
+///
+/// Compares motor scores against the raw sensory signals and reports
+/// each inconsistency with the levels it affects, ready for
+/// `CompletenessBuilder::add_conflict`. Observation only: nothing is
+/// resolved or discarded. Checks, in this fixed order:
+///
+/// 1. `PatternStructureConflict` - signals show pattern (periodicity or
+///    local structure) but M_M is weak, or M_M is strong on a signal
+///    with no pattern that passes the randomness test.
+/// 2. `EntropyCompressibilityConflict` - entropy and compressibility
+///    are both strong.
+/// 3. `PeriodicityRandomnessConflict` - periodicity detected while the
+///    runs test says random.
+/// 4. `MotorDisagreement` - a motor contradicts the signals. Currently
+///    M_C only: strong stability on a non-stationary or high-entropy
+///    signal, or weak stability on a stationary low-entropy signal.
+///
+/// `AmbiguousProtoAgency` needs proto-agency triggers and is never
+/// produced here.
+pub fn detect_conflicts(
+    scores: &MotorScores,
+    signals: &SensorySignals,
+) -> Vec<(ConflictType, Vec<AbstractionLevel>)> {
+    let mut conflicts = Vec::new();
+
+    let pattern_present = signals.has_periodicity() || signals.has_local_structure();
+    let structure_claimed = scores.meristic >= STRONG_CLAIM_THRESHOLD;
+    let structure_denied = scores.meristic <= WEAK_CLAIM_THRESHOLD;
+    if (pattern_present && structure_denied)
+        || (!pattern_present && signals.randomness_test_passed && structure_claimed)
+    {
+        conflicts.push((
+            ConflictType::PatternStructureConflict,
+            vec![AbstractionLevel::Pattern, AbstractionLevel::Structure],
+        ));
+    }
+
+    if signals.entropy >= STRONG_CLAIM_THRESHOLD && signals.compressibility >= STRONG_CLAIM_THRESHOLD {
+        conflicts.push((
+            ConflictType::EntropyCompressibilityConflict,
+            vec![AbstractionLevel::Carrier, AbstractionLevel::Structure],
+        ));
+    }
+
+    if signals.periodicity_detected && signals.randomness_test_passed {
+        conflicts.push((
+            ConflictType::PeriodicityRandomnessConflict,
+            vec![AbstractionLevel::Pattern],
+        ));
+    }
+
+    let disordered = !signals.stationarity_test_passed || signals.entropy >= STRONG_CLAIM_THRESHOLD;
+    let ordered = signals.stationarity_test_passed && signals.has_low_entropy();
+    let chaos_contradicts = (scores.chaotic >= STRONG_CLAIM_THRESHOLD && disordered)
+        || (scores.chaotic <= WEAK_CLAIM_THRESHOLD && ordered);
+    if chaos_contradicts {
+        conflicts.push((
+            ConflictType::MotorDisagreement {
+                motors: vec![MotorType::Chaos.name().to_string()],
+            },
+            vec![AbstractionLevel::Carrier, AbstractionLevel::Structure],
+        ));
+    }
+
+    conflicts
+}
+
 /// A tentative result for a level.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TentativeResult {
@@ -403,6 +563,53 @@ mod tests {
         assert!(state.confidence() > 0.0 && state.confidence() < 1.0);
     }
 
+    #[test]
+    fn test_integrity_check_to_completeness() {
+        use crate::budget::NumericalIssue;
+
+        let missing_for = |check: IntegrityCheck| {
+            match CognitiveCompleteness::from_integrity_check(&check) {
+                Some(CognitiveCompleteness::Partial {
+                    completed_levels,
+                    inconclusive_levels,
+                    missing_signals,
+                }) => {
+                    assert!(completed_levels.is_empty());
+                    assert_eq!(inconclusive_levels, AbstractionLevel::all().to_vec());
+                    missing_signals
+                }
+                other => panic!("expected Partial, got {:?}", other),
+            }
+        };
+
+        assert!(CognitiveCompleteness::from_integrity_check(&IntegrityCheck::WithinBudget).is_none());
+        assert_eq!(
+            missing_for(IntegrityCheck::EmptyInput),
+            vec![MissingSignal::InsufficientSamples]
+        );
+        assert_eq!(
+            missing_for(IntegrityCheck::NumericalCollapse {
+                reason: NumericalIssue::ContainsNaN,
+            }),
+            vec![
+                MissingSignal::EntropyInconclusive,
+                MissingSignal::StatisticalTestInconclusive,
+            ]
+        );
+        for check in [
+            IntegrityCheck::ExceedsMemory { requested: 200, available: 100 },
+            IntegrityCheck::ExceedsTime { estimated_ns: 2_000, budget_ns: 1_000 },
+            IntegrityCheck::ExceedsIterations { estimated: 20, budget: 10 },
+        ] {
+            assert_eq!(missing_for(check), vec![MissingSignal::BudgetExceeded]);
+        }
+        assert_eq!(
+            CognitiveCompleteness::from_integrity_check(&IntegrityCheck::EmptyInput)
+                .map(|state| state.certainty_index()),
+            Some(0.0)
+        );
+    }
+
     #[test]
     fn test_contradictory_state() {
         let state = CognitiveCompleteness::contradictory(
@@ -484,4 +691,145 @@ mod tests {
         );
         assert!(partial.summary().contains("PARTIAL"));
     }
+
+    #[test]
+    fn test_certainty_index_ranking() {
+        assert_eq!(CognitiveCompleteness::complete().certainty_index(), 1.0);
+
+        let contradictory = CognitiveCompleteness::contradictory(
+            ConflictType::PatternStructureConflict,
+            vec![AbstractionLevel::Pattern, AbstractionLevel::Structure],
+        );
+        assert!(contradictory.certainty_index() <= CONTRADICTION_CEILING);
+        assert!(contradictory.certainty_index() < 0.1);
+
+        let missing = vec![MissingSignal::InsufficientSamples];
+        let three = CognitiveCompleteness::partial(
+            vec![AbstractionLevel::Carrier, AbstractionLevel::Pattern, AbstractionLevel::Structure],
+            vec![AbstractionLevel::ProtoAgency],
+            missing.clone(),
+        );
+        let one = CognitiveCompleteness::partial(
+            vec![AbstractionLevel::Carrier],
+            vec![AbstractionLevel::Pattern, AbstractionLevel::Structure, AbstractionLevel::ProtoAgency],
+            missing,
+        );
+        assert!(three.certainty_index() > one.certainty_index());
+        assert!((three.certainty_index() - 0.75 * 0.9).abs() < 1e-12);
+        assert!(one.certainty_index() > contradictory.certainty_index());
+
+        // Distinct from confidence(): missing signals lower the index
+        assert!(three.certainty_index() < three.confidence());
+    }
+
+    #[test]
+    fn test_certainty_index_provisional_coverage() {
+        let tentative = |level| (level, TentativeResult::new(TentativeConclusion::LikelySignal, 0.8, vec![]));
+        let narrow = CognitiveCompleteness::provisional(0.8, vec![], vec![tentative(AbstractionLevel::Carrier)]);
+        let wide = CognitiveCompleteness::provisional(
+            0.8,
+            vec![],
+            AbstractionLevel::all().into_iter().map(tentative).collect(),
+        );
+
+        assert!((wide.certainty_index() - 0.8).abs() < 1e-12);
+        assert!((narrow.certainty_index() - 0.8 * 1.25 / 2.0).abs() < 1e-12);
+    }
+
+    /// Signals of a stationary, moderately ordered carrier with no pattern.
+    fn neutral_signals() -> SensorySignals {
+        let mut signals = SensorySignals::empty();
+        signals.sample_count = 256;
+        signals.entropy = 0.6;
+        signals.compressibility = 0.3;
+        signals.randomness_test_passed = false;
+        signals
+    }
+
+    fn conflict_types(scores: &MotorScores, signals: &SensorySignals) -> Vec<ConflictType> {
+        detect_conflicts(scores, signals).into_iter().map(|(c, _)| c).collect()
+    }
+
+    #[test]
+    fn test_consistent_perception_has_no_conflicts() {
+        let scores = MotorScores::new(0.5, 0.5, 0.5, 0.5);
+        assert!(detect_conflicts(&scores, &neutral_signals()).is_empty());
+    }
+
+    #[test]
+    fn test_pattern_structure_conflict() {
+        let mut signals = neutral_signals();
+        signals.local_global_entropy_ratio = 0.5;
+
+        let denied = MotorScores::new(0.5, 0.5, 0.5, 0.1);
+        assert_eq!(conflict_types(&denied, &signals), vec![ConflictType::PatternStructureConflict]);
+        let agreed = MotorScores::new(0.5, 0.5, 0.5, 0.9);
+        assert!(conflict_types(&agreed, &signals).is_empty());
+
+        // Structure claimed on a random, patternless signal
+        let mut random = neutral_signals();
+        random.randomness_test_passed = true;
+        assert_eq!(conflict_types(&agreed, &random), vec![ConflictType::PatternStructureConflict]);
+    }
+
+    #[test]
+    fn test_entropy_compressibility_conflict() {
+        let scores = MotorScores::new(0.5, 0.5, 0.5, 0.5);
+        let mut signals = neutral_signals();
+        signals.entropy = 0.7;
+        signals.compressibility = 0.9;
+        assert!(conflict_types(&scores, &signals).is_empty());
+
+        signals.entropy = 0.85;
+        let (conflict, levels) = detect_conflicts(&scores, &signals).remove(0);
+        assert_eq!(conflict, ConflictType::EntropyCompressibilityConflict);
+        assert_eq!(levels, vec![AbstractionLevel::Carrier, AbstractionLevel::Structure]);
+    }
+
+    #[test]
+    fn test_periodicity_randomness_conflict() {
+        let scores = MotorScores::new(0.5, 0.5, 0.5, 0.5);
+        let mut signals = neutral_signals();
+        signals.periodicity_detected = true;
+        assert!(conflict_types(&scores, &signals).is_empty());
+
+        signals.randomness_test_passed = true;
+        assert_eq!(conflict_types(&scores, &signals), vec![ConflictType::PeriodicityRandomnessConflict]);
+    }
+
+    #[test]
+    fn test_chaos_motor_disagreement() {
+        let chaos = ConflictType::MotorDisagreement { motors: vec!["Chaos".to_string()] };
+
+        // "Perfectly stable" on a non-stationary carrier
+        let stable = MotorScores::new(0.5, 0.5, 0.95, 0.5);
+        let mut signals = neutral_signals();
+        assert!(conflict_types(&stable, &signals).is_empty());
+        signals.stationarity_test_passed = false;
+        assert_eq!(conflict_types(&stable, &signals), vec![chaos.clone()]);
+
+        // "Unstable" on a stationary, ordered carrier
+        let unstable = MotorScores::new(0.5, 0.5, 0.1, 0.5);
+        let mut ordered = neutral_signals();
+        ordered.entropy = 0.3;
+        assert_eq!(conflict_types(&unstable, &ordered), vec![chaos]);
+    }
+
+    #[test]
+    fn test_conflicts_feed_builder() {
+        let scores = MotorScores::new(0.5, 0.5, 0.95, 0.1);
+        let mut signals = neutral_signals();
+        signals.periodicity_detected = true;
+        signals.randomness_test_passed = true;
+        signals.stationarity_test_passed = false;
+
+        let conflicts = detect_conflicts(&scores, &signals);
+        assert_eq!(conflicts.len(), 3);
+
+        let state = conflicts
+            .into_iter()
+            .fold(CompletenessBuilder::new(), |b, (c, levels)| b.add_conflict(c, levels))
+            .build();
+        assert!(state.has_contradictions());
+    }
 }
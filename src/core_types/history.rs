@@ -0,0 +1,204 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Bounded History
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 0.7.0
+//! Description: Fixed-capacity history buffer shared by the observability
+//!              trackers. Keeps the last N entries in a `VecDeque`, so
+//!              evicting the oldest entry is O(1). Serializes as a plain
+//!              sequence, the same shape as the `Vec` it replaces; on
+//!              deserialization only the last N entries are kept.
+//! Layer: Community
+//! Dependencies: serde
+//! Affected Components: competition, observability
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation
+//! --------------------------
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Last `N` values pushed, oldest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundedHistory<T, const N: usize> {
+    items: VecDeque<T>,
+}
+
+impl<T, const N: usize> BoundedHistory<T, N> {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::with_capacity(N),
+        }
+    }
+
+    /// Maximum number of entries kept.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends a value, evicting and returning the oldest one when full.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value);
+        }
+        let evicted = if self.items.len() == N {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(value);
+        evicted
+    }
+
+    /// Number of entries currently held.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if nothing has been pushed (or everything was evicted).
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Entry at `index`, counting from the oldest.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Most recent entry.
+    pub fn last(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    /// Iterates from oldest to newest.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+impl<T: Clone, const N: usize> BoundedHistory<T, N> {
+    /// Copies the entries into a `Vec`, oldest first.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.items.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> Default for BoundedHistory<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for BoundedHistory<T, N> {
+    /// Keeps the last `N` items of the iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut history = Self::new();
+        for value in iter {
+            history.push(value);
+        }
+        history
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BoundedHistory<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for BoundedHistory<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.items.len()))?;
+        for value in &self.items {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedHistory<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HistoryVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for HistoryVisitor<T, N> {
+            type Value = BoundedHistory<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut history = BoundedHistory::new();
+                while let Some(value) = seq.next_element()? {
+                    history.push(value);
+                }
+                Ok(history)
+            }
+        }
+
+        deserializer.deserialize_seq(HistoryVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_past_capacity_evicts_oldest() {
+        let mut history: BoundedHistory<u32, 3> = BoundedHistory::new();
+        assert_eq!(history.capacity(), 3);
+        assert_eq!(history.push(1), None);
+        assert_eq!(history.push(2), None);
+        assert_eq!(history.push(3), None);
+        assert_eq!(history.push(4), Some(1));
+        assert_eq!(history.push(5), Some(2));
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.to_vec(), vec![3, 4, 5]);
+        assert_eq!(history.last(), Some(&5));
+        assert_eq!(history.get(0), Some(&3));
+
+        // Eviction never grows the backing buffer
+        let allocated = history.items.capacity();
+        for i in 0..1000 {
+            history.push(i);
+        }
+        assert_eq!(history.items.capacity(), allocated);
+        assert_eq!(history.to_vec(), vec![997, 998, 999]);
+    }
+
+    #[test]
+    fn test_serializes_as_sequence() {
+        let history: BoundedHistory<u32, 4> = (0..6).collect();
+        let json = serde_json::to_string(&history).unwrap();
+        assert_eq!(json, "[2,3,4,5]");
+        assert_eq!(json, serde_json::to_string(&history.to_vec()).unwrap());
+
+        // Deserializing a longer sequence keeps the most recent entries
+        let narrow: BoundedHistory<u32, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(narrow.to_vec(), vec![4, 5]);
+
+        let empty: BoundedHistory<u32, 0> = (0..3).collect();
+        assert!(empty.is_empty());
+    }
+}
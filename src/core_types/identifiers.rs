@@ -36,6 +36,8 @@
 
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
 /// Digital Genome namespace for deterministic UUID generation.
@@ -239,6 +241,73 @@ impl Default for BrainId {
     }
 }
 
+/// Error parsing an identifier from its hex form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdParseError {
+    /// Input is not exactly 32 characters long.
+    InvalidLength(usize),
+    /// Input contains a non-hex character at the given position.
+    InvalidCharacter(usize),
+}
+
+impl fmt::Display for IdParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdParseError::InvalidLength(len) => {
+                write!(f, "Invalid id length: {} (expected 32 hex characters)", len)
+            }
+            IdParseError::InvalidCharacter(pos) => {
+                write!(f, "Invalid hex character at position {}", pos)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdParseError {}
+
+/// Parses 32 hex characters (no hyphens) into a UUID.
+fn parse_hex_uuid(s: &str) -> Result<Uuid, IdParseError> {
+    if s.len() != 32 {
+        return Err(IdParseError::InvalidLength(s.len()));
+    }
+    if let Some(pos) = s.bytes().position(|b| !b.is_ascii_hexdigit()) {
+        return Err(IdParseError::InvalidCharacter(pos));
+    }
+    let mut bytes = [0u8; 16];
+    hex::decode_to_slice(s, &mut bytes).map_err(|_| IdParseError::InvalidCharacter(0))?;
+    Ok(Uuid::from_bytes(bytes))
+}
+
+/// Implements the canonical text form for an identifier type:
+/// `Display` as 32 lowercase hex characters, `FromStr` parsing it back,
+/// and `short()` for log lines.
+macro_rules! impl_id_text {
+    ($($ty:ident),*) => {$(
+        impl $ty {
+            /// Returns the first 8 hex characters (for log lines).
+            pub fn short(&self) -> String {
+                self.to_string()[..8].to_string()
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0.simple())
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = IdParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_hex_uuid(s).map(Self)
+            }
+        }
+    )*};
+}
+
+impl_id_text!(ActionId, DnaId, SynapseId, NeuronId, BrainId);
+
 /// Contextual signature for traceability.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ContextSignature {
@@ -299,6 +368,35 @@ mod tests {
         assert_ne!(id1, id2, "Random IDs should be different");
     }
 
+    #[test]
+    fn test_id_display_from_str_roundtrip() {
+        let action = ActionId::new_deterministic(b"action-001");
+        let text = action.to_string();
+        assert_eq!(text.len(), 32);
+        assert_eq!(text, text.to_lowercase());
+        assert_eq!(text.parse::<ActionId>(), Ok(action));
+
+        let dna = DnaId::new_deterministic(b"dna-001");
+        assert_eq!(dna.to_string().parse::<DnaId>(), Ok(dna));
+    }
+
+    #[test]
+    fn test_id_short_is_prefix() {
+        let dna = DnaId::new_deterministic(b"dna-001");
+        let short = dna.short();
+        assert_eq!(short.len(), 8);
+        assert!(dna.to_string().starts_with(&short));
+    }
+
+    #[test]
+    fn test_id_parse_rejects_malformed() {
+        assert_eq!("abc".parse::<ActionId>(), Err(IdParseError::InvalidLength(3)));
+        let hyphenated = ActionId::new_deterministic(b"x").0.hyphenated().to_string();
+        assert_eq!(hyphenated.parse::<ActionId>(), Err(IdParseError::InvalidLength(36)));
+        let bad = format!("{}z", "0".repeat(31));
+        assert_eq!(bad.parse::<DnaId>(), Err(IdParseError::InvalidCharacter(31)));
+    }
+
     #[test]
     fn test_as_uuid() {
         let id = ActionId::new_deterministic(b"test");
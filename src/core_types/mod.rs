@@ -16,8 +16,11 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
+//! 2026-10-16 - Carlos Eduardo Favini - BoundedHistory
 //! --------------------------
 
+mod history;
 mod identifiers;
 
+pub use history::*;
 pub use identifiers::*;
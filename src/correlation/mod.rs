@@ -28,15 +28,31 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Jacobi eigenvalues and effective rank
+//! 2026-10-16 - Carlos Eduardo Favini - Weighted observation and decay in CooccurrenceTracker
+//! 2026-10-16 - Carlos Eduardo Favini - Greedy multi-step path prediction
+//! 2026-10-16 - Carlos Eduardo Favini - Builds without `std` (HashMap is a BTreeMap there)
+//! 2026-10-16 - Carlos Eduardo Favini - hash_pattern_with for a pluggable hasher
+//! 2026-10-16 - Carlos Eduardo Favini - OnlineCorrelation (streaming observations)
+//! 2026-10-16 - Carlos Eduardo Favini - CorrelationMatrix::from_observations_weighted
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+
+use crate::compat::prelude::*;
+use crate::hashing::{DefaultHasher, Hasher};
 
 /// Hash of a detected pattern (content-addressable)
 pub type PatternHash = String;
 
+/// Largest dimension for which `CorrelationMatrix::eigenvalues` runs.
+///
+/// Jacobi rotation is O(n³) per sweep; feature sets here are small.
+pub const MAX_EIGEN_DIMENSION: usize = 64;
+
+/// Maximum Jacobi sweeps before giving up on further convergence.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
 /// A correlation matrix between features.
 /// No labels - just mathematical relationships.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,7 +72,21 @@ impl CorrelationMatrix {
     /// Creates a new correlation matrix from observations.
     /// Each observation is a vector of feature values.
     pub fn from_observations(observations: &[Vec<f64>]) -> Option<Self> {
-        if observations.is_empty() {
+        Self::from_observations_weighted(observations, &vec![1.0; observations.len()])
+    }
+
+    /// Creates a correlation matrix from weighted observations.
+    ///
+    /// `weights[k]` scales observation `k` in the means and covariances
+    /// (both normalized by the sum of weights), e.g. to favor recent
+    /// observations. Returns `None` if the weights do not match the
+    /// observations one-to-one, any weight is negative or not finite, or
+    /// all weights are zero. All-1 weights give `from_observations`.
+    pub fn from_observations_weighted(observations: &[Vec<f64>], weights: &[f64]) -> Option<Self> {
+        if observations.is_empty() || weights.len() != observations.len() {
+            return None;
+        }
+        if !weights.iter().all(|w| w.is_finite() && *w >= 0.0) {
             return None;
         }
 
@@ -70,17 +100,20 @@ impl CorrelationMatrix {
             return None;
         }
 
-        let n = observations.len() as f64;
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
 
-        // Calculate means
+        // Calculate weighted means
         let mut means = vec![0.0; dimension];
-        for obs in observations {
+        for (obs, &w) in observations.iter().zip(weights) {
             for (i, &val) in obs.iter().enumerate() {
-                means[i] += val;
+                means[i] += w * val;
             }
         }
         for mean in &mut means {
-            *mean /= n;
+            *mean /= total_weight;
         }
 
         // Calculate correlation matrix
@@ -92,15 +125,16 @@ impl CorrelationMatrix {
                 let mut var_i = 0.0;
                 let mut var_j = 0.0;
 
-                for obs in observations {
+                // The 1 / Σw normalization cancels in the ratio below
+                for (obs, &w) in observations.iter().zip(weights) {
                     let di = obs[i] - means[i];
                     let dj = obs[j] - means[j];
-                    cov += di * dj;
+                    cov += w * di * dj;
                     if i == j {
-                        var_i += di * di;
+                        var_i += w * di * di;
                     } else {
-                        var_i += di * di;
-                        var_j += dj * dj;
+                        var_i += w * di * di;
+                        var_j += w * dj * dj;
                     }
                 }
 
@@ -149,20 +183,201 @@ impl CorrelationMatrix {
         }
         results
     }
+
+    /// Eigenvalues of the correlation matrix, sorted descending.
+    ///
+    /// Computed with the cyclic Jacobi method (deterministic, no external
+    /// linear algebra). Returns an empty vector if `dimension` exceeds
+    /// `MAX_EIGEN_DIMENSION`.
+    pub fn eigenvalues(&self) -> Vec<f64> {
+        let n = self.dimension;
+        if n > MAX_EIGEN_DIMENSION {
+            return Vec::new();
+        }
+
+        let mut a = self.correlations.clone();
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off_diagonal: f64 = (0..n)
+                .flat_map(|p| ((p + 1)..n).map(move |q| (p, q)))
+                .map(|(p, q)| a[p][q] * a[p][q])
+                .sum();
+            if off_diagonal < 1e-24 {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q].abs() < f64::MIN_POSITIVE {
+                        continue;
+                    }
+
+                    // Rotation angle that zeroes a[p][q]
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut() {
+                        let (kp, kq) = (row[p], row[q]);
+                        row[p] = c * kp - s * kq;
+                        row[q] = s * kp + c * kq;
+                    }
+                    let (head, tail) = a.split_at_mut(q);
+                    for (pk, qk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        let (vp, vq) = (*pk, *qk);
+                        *pk = c * vp - s * vq;
+                        *qk = s * vp + c * vq;
+                    }
+                }
+            }
+        }
+
+        let mut eigenvalues: Vec<f64> = (0..n).map(|i| a[i][i]).collect();
+        eigenvalues.sort_by(|x, y| y.total_cmp(x));
+        eigenvalues
+    }
+
+    /// Number of top components that explain `variance_fraction` of the
+    /// total variance (sum of non-negative eigenvalues).
+    ///
+    /// `variance_fraction` is clamped to [0, 1]. Returns 0 if no
+    /// eigenvalues are available.
+    pub fn effective_rank(&self, variance_fraction: f64) -> usize {
+        let eigenvalues: Vec<f64> = self.eigenvalues().into_iter().map(|e| e.max(0.0)).collect();
+        let total: f64 = eigenvalues.iter().sum();
+        if total <= 0.0 {
+            return 0;
+        }
+
+        let target = variance_fraction.clamp(0.0, 1.0) * total;
+        let mut explained = 0.0;
+        for (i, eigenvalue) in eigenvalues.iter().enumerate() {
+            explained += eigenvalue;
+            // Relative tolerance so a fraction of exactly 1.0 is reachable
+            if explained >= target - 1e-12 * total {
+                return i + 1;
+            }
+        }
+        eigenvalues.len()
+    }
+}
+
+/// Streaming counterpart of `CorrelationMatrix::from_observations`.
+///
+/// Keeps running means and the centered sums of squares and
+/// cross-products (Welford's update), so observations can arrive one at
+/// a time without storing them. The dimension is fixed by the first
+/// observation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OnlineCorrelation {
+    /// Number of observations incorporated
+    pub observation_count: usize,
+
+    /// Running mean of each feature
+    means: Vec<f64>,
+
+    /// Centered sums of products: [i][j] = Σ (x_i - mean_i)(x_j - mean_j)
+    comoments: Vec<Vec<f64>>,
+}
+
+impl OnlineCorrelation {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of features, or 0 before the first observation.
+    pub fn dimension(&self) -> usize {
+        self.means.len()
+    }
+
+    /// Incorporates one observation.
+    ///
+    /// Returns false (and changes nothing) for an empty observation or
+    /// one whose dimension differs from the first observation's.
+    pub fn observe(&mut self, obs: &[f64]) -> bool {
+        if obs.is_empty() {
+            return false;
+        }
+        if self.observation_count == 0 {
+            self.means = vec![0.0; obs.len()];
+            self.comoments = vec![vec![0.0; obs.len()]; obs.len()];
+        } else if obs.len() != self.dimension() {
+            return false;
+        }
+
+        self.observation_count += 1;
+        let n = self.observation_count as f64;
+
+        // Deviations from the old mean, then from the updated mean
+        let before: Vec<f64> = obs.iter().zip(&self.means).map(|(x, m)| x - m).collect();
+        for (mean, delta) in self.means.iter_mut().zip(&before) {
+            *mean += delta / n;
+        }
+        let after: Vec<f64> = obs.iter().zip(&self.means).map(|(x, m)| x - m).collect();
+
+        for (row, di) in self.comoments.iter_mut().zip(&before) {
+            for (c, dj) in row.iter_mut().zip(&after) {
+                *c += di * dj;
+            }
+        }
+        true
+    }
+
+    /// Correlation matrix of everything observed so far.
+    ///
+    /// Matches `CorrelationMatrix::from_observations` on the same data
+    /// up to rounding. None before the first observation.
+    pub fn matrix(&self) -> Option<CorrelationMatrix> {
+        if self.observation_count == 0 {
+            return None;
+        }
+
+        let dimension = self.dimension();
+        let c = &self.comoments;
+        let correlations = (0..dimension)
+            .map(|i| {
+                (0..dimension)
+                    .map(|j| {
+                        if i == j {
+                            return 1.0;
+                        }
+                        let denom = (c[i][i] * c[j][j]).sqrt();
+                        if denom > f64::EPSILON {
+                            // Average both halves so the result is exactly symmetric
+                            0.5 * (c[i][j] + c[j][i]) / denom
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(CorrelationMatrix {
+            correlations,
+            dimension,
+            observation_count: self.observation_count,
+        })
+    }
 }
 
 /// Tracks cooccurrence of patterns without labels.
+///
+/// Counts are fractional so that observations can be weighted and
+/// stored history can decay (exponential forgetting between batches).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CooccurrenceTracker {
-    /// How many times each pattern was observed
-    pub pattern_counts: HashMap<PatternHash, usize>,
+    /// Accumulated weight of each pattern's observations
+    pub pattern_counts: HashMap<PatternHash, f64>,
 
-    /// How many times two patterns occurred together
+    /// Accumulated weight of two patterns occurring together
     /// Key is (hash1, hash2) where hash1 < hash2 lexicographically
-    pub pair_counts: HashMap<(PatternHash, PatternHash), usize>,
+    pub pair_counts: HashMap<(PatternHash, PatternHash), f64>,
 
-    /// Total observations
-    pub total_observations: usize,
+    /// Total accumulated observation weight
+    pub total_observations: f64,
 }
 
 impl CooccurrenceTracker {
@@ -173,11 +388,22 @@ impl CooccurrenceTracker {
 
     /// Records observation of a set of patterns occurring together.
     pub fn observe(&mut self, patterns: &[PatternHash]) {
-        self.total_observations += 1;
+        self.observe_weighted(patterns, 1.0);
+    }
+
+    /// Records a weighted observation of patterns occurring together.
+    ///
+    /// Non-finite or non-positive weights are ignored.
+    pub fn observe_weighted(&mut self, patterns: &[PatternHash], weight: f64) {
+        if !weight.is_finite() || weight <= 0.0 {
+            return;
+        }
+
+        self.total_observations += weight;
 
         // Count individual patterns
         for pattern in patterns {
-            *self.pattern_counts.entry(pattern.clone()).or_insert(0) += 1;
+            *self.pattern_counts.entry(pattern.clone()).or_insert(0.0) += weight;
         }
 
         // Count pairs
@@ -188,23 +414,43 @@ impl CooccurrenceTracker {
                 } else {
                     (patterns[j].clone(), patterns[i].clone())
                 };
-                *self.pair_counts.entry((p1, p2)).or_insert(0) += 1;
+                *self.pair_counts.entry((p1, p2)).or_insert(0.0) += weight;
             }
         }
     }
 
+    /// Multiplies every stored count (individual, pairwise and total) by `factor`.
+    ///
+    /// Call between batches to make older observations fade. Frequencies
+    /// are ratios, so decay alone leaves them unchanged; it only reduces
+    /// the weight of the past relative to later observations.
+    /// Factors outside (0, 1] are ignored.
+    pub fn decay(&mut self, factor: f64) {
+        if !(factor > 0.0 && factor <= 1.0) {
+            return;
+        }
+
+        self.total_observations *= factor;
+        for count in self.pattern_counts.values_mut() {
+            *count *= factor;
+        }
+        for count in self.pair_counts.values_mut() {
+            *count *= factor;
+        }
+    }
+
     /// Returns the frequency of a pattern (0.0 to 1.0).
     pub fn frequency(&self, pattern: &PatternHash) -> f64 {
-        if self.total_observations == 0 {
+        if self.total_observations <= 0.0 {
             return 0.0;
         }
-        let count = self.pattern_counts.get(pattern).copied().unwrap_or(0);
-        count as f64 / self.total_observations as f64
+        let count = self.pattern_counts.get(pattern).copied().unwrap_or(0.0);
+        count / self.total_observations
     }
 
     /// Returns the cooccurrence frequency of two patterns.
     pub fn cooccurrence_frequency(&self, p1: &PatternHash, p2: &PatternHash) -> f64 {
-        if self.total_observations == 0 {
+        if self.total_observations <= 0.0 {
             return 0.0;
         }
         let key = if p1 < p2 {
@@ -212,14 +458,14 @@ impl CooccurrenceTracker {
         } else {
             (p2.clone(), p1.clone())
         };
-        let count = self.pair_counts.get(&key).copied().unwrap_or(0);
-        count as f64 / self.total_observations as f64
+        let count = self.pair_counts.get(&key).copied().unwrap_or(0.0);
+        count / self.total_observations
     }
 
     /// Returns the conditional probability P(p2 | p1).
     pub fn conditional_probability(&self, p1: &PatternHash, p2: &PatternHash) -> f64 {
-        let p1_count = self.pattern_counts.get(p1).copied().unwrap_or(0);
-        if p1_count == 0 {
+        let p1_count = self.pattern_counts.get(p1).copied().unwrap_or(0.0);
+        if p1_count <= 0.0 {
             return 0.0;
         }
 
@@ -228,8 +474,8 @@ impl CooccurrenceTracker {
         } else {
             (p2.clone(), p1.clone())
         };
-        let pair_count = self.pair_counts.get(&key).copied().unwrap_or(0);
-        pair_count as f64 / p1_count as f64
+        let pair_count = self.pair_counts.get(&key).copied().unwrap_or(0.0);
+        pair_count / p1_count
     }
 
     /// Returns the pointwise mutual information between two patterns.
@@ -333,17 +579,58 @@ impl TransformationTracker {
             })
             .collect();
 
-        predictions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        predictions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Equal));
         predictions.truncate(top_k);
         predictions
     }
+
+    /// Returns the most likely `steps`-hop continuation starting at `from`.
+    ///
+    /// Greedily follows the highest-probability outgoing transition at each
+    /// hop (ties broken by smallest pattern hash, for determinism). Each
+    /// entry carries the cumulative probability of the path so far.
+    /// Cycles are allowed but bounded by `steps`; the path stops early at
+    /// a pattern with no outgoing transitions.
+    pub fn predict_path(&self, from: &PatternHash, steps: usize) -> Vec<(PatternHash, f64)> {
+        // `steps` is caller-supplied; don't let it size the allocation
+        let mut path = Vec::with_capacity(steps.min(self.transformations.len()));
+        let mut current = from.clone();
+        let mut cumulative = 1.0;
+
+        for _ in 0..steps {
+            let next = self
+                .transformations
+                .keys()
+                .filter(|(f, _)| *f == current)
+                .map(|(_, to)| (to, self.transformation_probability(&current, to)))
+                .max_by(|a, b| a.1.total_cmp(&b.1).then_with(|| b.0.cmp(a.0)));
+
+            let Some((to, probability)) = next else {
+                break;
+            };
+
+            cumulative *= probability;
+            current = to.clone();
+            path.push((current.clone(), cumulative));
+        }
+
+        path
+    }
 }
 
-/// Computes a content-addressable hash for a pattern.
+/// Computes a content-addressable hash for a pattern (SHA-256).
 pub fn hash_pattern(values: &[f64]) -> PatternHash {
-    let mut hasher = Sha256::new();
+    hash_pattern_with::<DefaultHasher>(values)
+}
+
+/// Computes a pattern hash with an explicit hasher.
+///
+/// Hex digest over each value's little-endian IEEE 754 bytes. Hashes
+/// from different hashers must not be mixed in one tracker.
+pub fn hash_pattern_with<H: Hasher>(values: &[f64]) -> PatternHash {
+    let mut hasher = H::default();
     for v in values {
-        hasher.update(v.to_le_bytes());
+        hasher.update(&v.to_le_bytes());
     }
     hex::encode(hasher.finalize())
 }
@@ -390,6 +677,122 @@ mod tests {
         assert!(matrix.get(0, 1).unwrap() < -0.9);
     }
 
+    #[test]
+    fn test_weighted_correlation() {
+        // First half: y = x; second half: y = -x
+        let observations: Vec<Vec<f64>> = (0..20)
+            .map(|k| {
+                let x = f64::from(k % 10) - 4.5;
+                vec![x, if k < 10 { x } else { -x }]
+            })
+            .collect();
+
+        let unweighted = CorrelationMatrix::from_observations(&observations).unwrap();
+        let equal = CorrelationMatrix::from_observations_weighted(&observations, &[1.0; 20]).unwrap();
+        assert_eq!(unweighted.correlations, equal.correlations);
+        assert!(unweighted.get(0, 1).unwrap().abs() < 1e-12);
+
+        let mut weights = [1.0; 20];
+        weights[..10].fill(20.0);
+        let favored = CorrelationMatrix::from_observations_weighted(&observations, &weights).unwrap();
+        assert!(favored.get(0, 1).unwrap() > 0.8);
+
+        weights[..10].fill(0.0);
+        let only_second = CorrelationMatrix::from_observations_weighted(&observations, &weights).unwrap();
+        assert!((only_second.get(0, 1).unwrap() + 1.0).abs() < 1e-12);
+
+        assert!(CorrelationMatrix::from_observations_weighted(&observations, &[1.0; 19]).is_none());
+        weights[3] = -1.0;
+        assert!(CorrelationMatrix::from_observations_weighted(&observations, &weights).is_none());
+        assert!(CorrelationMatrix::from_observations_weighted(&observations, &[0.0; 20]).is_none());
+    }
+
+    #[test]
+    fn test_online_correlation_matches_batch() {
+        let observations: Vec<Vec<f64>> = (0..50)
+            .map(|i| {
+                let t = i as f64;
+                vec![1000.0 + t, (t * 0.7).sin(), 3.0 - 0.5 * t + (t * 1.3).sin(), 5.0]
+            })
+            .collect();
+        let batch = CorrelationMatrix::from_observations(&observations).unwrap();
+
+        let mut online = OnlineCorrelation::new();
+        assert!(online.matrix().is_none());
+        for obs in &observations {
+            assert!(online.observe(obs));
+        }
+        let streamed = online.matrix().unwrap();
+
+        assert_eq!(streamed.dimension, batch.dimension);
+        assert_eq!(streamed.observation_count, batch.observation_count);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((streamed.correlations[i][j] - batch.correlations[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_online_correlation_rejects_dimension_change() {
+        let mut online = OnlineCorrelation::new();
+        assert!(!online.observe(&[]));
+        assert!(online.observe(&[0.0, 1.0]));
+        assert!(online.observe(&[1.0, 0.0]));
+        assert!(!online.observe(&[2.0, 2.0, 2.0]));
+        assert!(!online.observe(&[2.0]));
+
+        let matrix = online.matrix().unwrap();
+        assert_eq!(online.dimension(), 2);
+        assert_eq!(matrix.observation_count, 2);
+        assert!((matrix.get(0, 1).unwrap() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_eigenvalues_block_correlated() {
+        // Features 0-2 strongly correlated (ρ = 0.9), feature 3 independent
+        let matrix = CorrelationMatrix {
+            correlations: vec![
+                vec![1.0, 0.9, 0.9, 0.0],
+                vec![0.9, 1.0, 0.9, 0.0],
+                vec![0.9, 0.9, 1.0, 0.0],
+                vec![0.0, 0.0, 0.0, 1.0],
+            ],
+            dimension: 4,
+            observation_count: 0,
+        };
+
+        // Block eigenvalues: 1 + 2ρ, 1 - ρ, 1 - ρ; independent feature: 1
+        let eigenvalues = matrix.eigenvalues();
+        let expected = [2.8, 1.0, 0.1, 0.1];
+        assert_eq!(eigenvalues.len(), 4);
+        for (got, want) in eigenvalues.iter().zip(expected.iter()) {
+            assert!((got - want).abs() < 1e-9, "got {:?}", eigenvalues);
+        }
+
+        // Dominant component carries the correlated block (70% of variance)
+        assert_eq!(matrix.effective_rank(0.7), 1);
+        assert_eq!(matrix.effective_rank(0.9), 2);
+        assert_eq!(matrix.effective_rank(1.0), 4);
+    }
+
+    #[test]
+    fn test_eigenvalues_from_observations_sum_to_dimension() {
+        let observations = vec![
+            vec![0.1, 0.2, 0.9],
+            vec![0.4, 0.5, 0.3],
+            vec![0.7, 0.9, 0.5],
+            vec![0.2, 0.1, 0.8],
+        ];
+        let matrix = CorrelationMatrix::from_observations(&observations).unwrap();
+        let eigenvalues = matrix.eigenvalues();
+
+        // Trace is preserved by rotations
+        let trace: f64 = eigenvalues.iter().sum();
+        assert!((trace - 3.0).abs() < 1e-9);
+        assert!(eigenvalues.windows(2).all(|w| w[0] >= w[1]));
+    }
+
     #[test]
     fn test_cooccurrence_tracker() {
         let mut tracker = CooccurrenceTracker::new();
@@ -398,11 +801,58 @@ mod tests {
         tracker.observe(&["A".to_string(), "B".to_string()]);
         tracker.observe(&["A".to_string(), "C".to_string()]);
 
-        assert_eq!(tracker.total_observations, 3);
+        assert_eq!(tracker.total_observations, 3.0);
         assert!((tracker.frequency(&"A".to_string()) - 1.0).abs() < 0.01);
         assert!((tracker.frequency(&"B".to_string()) - 2.0 / 3.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_cooccurrence_decay_favors_recent() {
+        let a = "A".to_string();
+        let b = "B".to_string();
+        let mut tracker = CooccurrenceTracker::new();
+
+        for _ in 0..9 {
+            tracker.observe(std::slice::from_ref(&a));
+        }
+        tracker.observe(&[a.clone(), b.clone()]);
+        let before = tracker.frequency(&b);
+        assert!((before - 0.1).abs() < 1e-12);
+
+        // Decay alone preserves frequencies
+        tracker.decay(0.1);
+        assert!((tracker.frequency(&b) - before).abs() < 1e-12);
+        assert!((tracker.total_observations - 1.0).abs() < 1e-12);
+
+        // Recent observations now dominate
+        for _ in 0..3 {
+            tracker.observe(&[a.clone(), b.clone()]);
+        }
+        let after = tracker.frequency(&b);
+        assert!(after > 0.7, "expected recent B to dominate, got {}", after);
+
+        // Fractional counts keep PMI and conditionals well-defined
+        assert!(tracker.pointwise_mutual_information(&a, &b).is_finite());
+        assert!((tracker.conditional_probability(&a, &b) - after).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cooccurrence_weighted_and_invalid_inputs() {
+        let a = "A".to_string();
+        let b = "B".to_string();
+        let mut tracker = CooccurrenceTracker::new();
+
+        tracker.observe_weighted(std::slice::from_ref(&a), 3.0);
+        tracker.observe_weighted(std::slice::from_ref(&b), 1.0);
+        tracker.observe_weighted(std::slice::from_ref(&b), f64::NAN);
+        tracker.observe_weighted(std::slice::from_ref(&b), -1.0);
+        tracker.decay(0.0);
+        tracker.decay(1.5);
+
+        assert!((tracker.total_observations - 4.0).abs() < 1e-12);
+        assert!((tracker.frequency(&a) - 0.75).abs() < 1e-12);
+    }
+
     #[test]
     fn test_conditional_probability() {
         let mut tracker = CooccurrenceTracker::new();
@@ -432,6 +882,49 @@ mod tests {
         assert_eq!(predictions[0].0, "B");
     }
 
+    #[test]
+    fn test_predict_path_chain() {
+        let mut tracker = TransformationTracker::new();
+        let p = |s: &str| s.to_string();
+
+        // A→B (3 of 4), A→D (1 of 4), B→C (2 of 3), B→A (1 of 3); C is a dead end
+        for _ in 0..3 {
+            tracker.observe(p("A"), p("B"), 1.0);
+        }
+        tracker.observe(p("A"), p("D"), 1.0);
+        tracker.observe(p("B"), p("C"), 1.0);
+        tracker.observe(p("B"), p("C"), 1.0);
+        tracker.observe(p("B"), p("A"), 1.0);
+
+        let path = tracker.predict_path(&p("A"), 5);
+
+        // Stops early at dead-end C
+        assert_eq!(path.len(), 2);
+        assert_eq!(path[0].0, "B");
+        assert_eq!(path[1].0, "C");
+        assert!((path[0].1 - 0.75).abs() < 1e-12);
+        assert!((path[1].1 - 0.75 * 2.0 / 3.0).abs() < 1e-12);
+        assert!(path[1].1 < path[0].1);
+
+        assert!(tracker.predict_path(&p("A"), 0).is_empty());
+        assert!(tracker.predict_path(&p("Z"), 3).is_empty());
+
+        // A huge step budget must not pre-allocate; the walk still ends at C
+        assert_eq!(tracker.predict_path(&p("A"), usize::MAX), path);
+    }
+
+    #[test]
+    fn test_predict_path_cycle_capped() {
+        let mut tracker = TransformationTracker::new();
+        tracker.observe("A".to_string(), "B".to_string(), 1.0);
+        tracker.observe("B".to_string(), "A".to_string(), 1.0);
+
+        let path = tracker.predict_path(&"A".to_string(), 4);
+        let hops: Vec<&str> = path.iter().map(|(h, _)| h.as_str()).collect();
+        assert_eq!(hops, vec!["B", "A", "B", "A"]);
+        assert!(path.iter().all(|(_, p)| (*p - 1.0).abs() < 1e-12));
+    }
+
     #[test]
     fn test_hash_pattern() {
         let values1 = vec![1.0, 2.0, 3.0];
@@ -453,4 +946,31 @@ mod tests {
         assert_eq!(quantize(1.0, 4), 3);
         assert_eq!(quantize(1.5, 4), 3); // Clamped
     }
+
+    #[test]
+    fn test_hash_pattern_default_hasher_unchanged() {
+        use sha2::{Digest, Sha256};
+
+        let values = [0.25f64, -1.0, 3.5];
+        let mut sha = Sha256::new();
+        for v in values {
+            sha.update(v.to_le_bytes());
+        }
+        let expected = hex::encode(sha.finalize());
+
+        assert_eq!(hash_pattern(&values), expected);
+        assert_eq!(hash_pattern_with::<crate::hashing::Sha256Hasher>(&values), expected);
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_hash_pattern_blake3() {
+        use crate::hashing::Blake3Hasher;
+
+        let a = hash_pattern_with::<Blake3Hasher>(&[0.1, 0.2]);
+        assert_eq!(a, hash_pattern_with::<Blake3Hasher>(&[0.1, 0.2]));
+        assert_eq!(a.len(), 64);
+        assert_ne!(a, hash_pattern(&[0.1, 0.2]));
+        assert_ne!(a, hash_pattern_with::<Blake3Hasher>(&[0.2, 0.1]));
+    }
 }
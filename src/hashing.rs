@@ -0,0 +1,108 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Content Hashing
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 0.7.0
+//! Description: Pluggable 256-bit content hasher for pattern hashes and
+//! context / DNA fingerprints. SHA-256 is the default and reproduces
+//! every existing hash; BLAKE3 is available behind the `blake3` feature
+//! for throughput. Hashes from different hashers are not comparable.
+//! Layer: Community
+//! Dependencies: sha2, blake3 (optional)
+//! Affected Components: correlation, memory/context, hierarchy/dna
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation
+//! --------------------------
+
+use sha2::Digest;
+
+/// Incremental hasher producing a 256-bit digest.
+///
+/// Not to be confused with `core::hash::Hasher`: this is a
+/// cryptographic content hash, stable across platforms and runs.
+pub trait Hasher: Default {
+    /// Algorithm name (for audit records).
+    const ALGORITHM: &'static str;
+
+    /// Feeds bytes into the hash.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Consumes the hasher and returns the digest.
+    fn finalize(self) -> [u8; 32];
+
+    /// One-shot digest of `bytes`.
+    fn digest(bytes: &[u8]) -> [u8; 32] {
+        let mut hasher = Self::default();
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+}
+
+/// SHA-256 (the default hasher).
+#[derive(Debug, Clone, Default)]
+pub struct Sha256Hasher(sha2::Sha256);
+
+impl Hasher for Sha256Hasher {
+    const ALGORITHM: &'static str = "sha256";
+
+    fn update(&mut self, bytes: &[u8]) {
+        Digest::update(&mut self.0, bytes);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        Digest::finalize(self.0).into()
+    }
+}
+
+/// BLAKE3 (256-bit output).
+#[cfg(feature = "blake3")]
+#[derive(Debug, Clone, Default)]
+pub struct Blake3Hasher(blake3::Hasher);
+
+#[cfg(feature = "blake3")]
+impl Hasher for Blake3Hasher {
+    const ALGORITHM: &'static str = "blake3";
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
+
+/// Hasher used by every entry point without an explicit one.
+pub type DefaultHasher = Sha256Hasher;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_matches_sha2() {
+        let expected: [u8; 32] = sha2::Sha256::digest(b"digital genome").into();
+        assert_eq!(Sha256Hasher::digest(b"digital genome"), expected);
+
+        // Incremental updates equal a one-shot digest
+        let mut hasher = Sha256Hasher::default();
+        hasher.update(b"digital ");
+        hasher.update(b"genome");
+        assert_eq!(hasher.finalize(), expected);
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_blake3_stable_and_distinct() {
+        let first = Blake3Hasher::digest(b"digital genome");
+        assert_eq!(first, Blake3Hasher::digest(b"digital genome"));
+        assert_eq!(first, *blake3::hash(b"digital genome").as_bytes());
+        assert_ne!(first, Sha256Hasher::digest(b"digital genome"));
+        assert_ne!(first, Blake3Hasher::digest(b"digital genomf"));
+    }
+}
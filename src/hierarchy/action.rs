@@ -18,6 +18,8 @@
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - SHA-256 + deterministic serialization
+//! 2026-10-16 - Carlos Eduardo Favini - Typed validation errors (ObservedActionError)
+//! 2026-10-16 - Carlos Eduardo Favini - ActionSequenceBuilder
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
@@ -26,6 +28,9 @@ use std::collections::BTreeMap;
 
 use crate::core_types::ActionId;
 
+/// Default maximum size of an action payload (canonical JSON bytes).
+pub const DEFAULT_MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
+
 /// Level 0: Observed Action.
 ///
 /// The most fundamental unit in the Digital Genome hierarchy.
@@ -79,6 +84,8 @@ pub enum ActionError {
         expected: String,
         computed: String,
     },
+    /// Observation rejected before creation.
+    Invalid(ObservedActionError),
 }
 
 impl std::fmt::Display for ActionError {
@@ -97,11 +104,55 @@ impl std::fmt::Display for ActionError {
                     expected, computed
                 )
             }
+            ActionError::Invalid(e) => write!(f, "Invalid observation: {}", e),
         }
     }
 }
 
-impl std::error::Error for ActionError {}
+impl std::error::Error for ActionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ActionError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ObservedActionError> for ActionError {
+    fn from(e: ObservedActionError) -> Self {
+        ActionError::Invalid(e)
+    }
+}
+
+/// Validation failure for a malformed observation.
+///
+/// Checked deterministically before the action is hashed, so a
+/// malformed observation never becomes an immutable record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObservedActionError {
+    /// Timestamp is before the epoch.
+    NegativeTimestamp { value: i64 },
+    /// Canonical JSON payload exceeds the configured maximum.
+    PayloadTooLarge { max: usize, got: usize },
+    /// Source identifier is empty or whitespace.
+    EmptySource,
+}
+
+impl std::fmt::Display for ObservedActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObservedActionError::NegativeTimestamp { value } => {
+                write!(f, "Negative timestamp: {}", value)
+            }
+            ObservedActionError::PayloadTooLarge { max, got } => {
+                write!(f, "Payload too large: {} bytes (max {})", got, max)
+            }
+            ObservedActionError::EmptySource => write!(f, "Empty source identifier"),
+        }
+    }
+}
+
+impl std::error::Error for ObservedActionError {}
 
 impl ObservedAction {
     /// Creates a new observed action.
@@ -114,13 +165,29 @@ impl ObservedAction {
     ///
     /// # Returns
     /// Result containing a new ObservedAction with computed SHA-256 hash,
-    /// or an ActionError if serialization fails.
+    /// or an ActionError if validation or serialization fails. Payloads
+    /// are limited to `DEFAULT_MAX_PAYLOAD_BYTES`.
     pub fn new(
         source_id: String,
         timestamp_ns: i64,
         context: BTreeMap<String, f64>,
         payload: serde_json::Value,
     ) -> Result<Self, ActionError> {
+        Self::with_max_payload(source_id, timestamp_ns, context, payload, DEFAULT_MAX_PAYLOAD_BYTES)
+    }
+
+    /// Creates a new observed action with a custom payload size limit.
+    ///
+    /// Validation order is fixed: source, timestamp, payload size.
+    pub fn with_max_payload(
+        source_id: String,
+        timestamp_ns: i64,
+        context: BTreeMap<String, f64>,
+        payload: serde_json::Value,
+        max_payload_bytes: usize,
+    ) -> Result<Self, ActionError> {
+        Self::validate(&source_id, timestamp_ns, &payload, max_payload_bytes)?;
+
         let id = ActionId::new();
 
         let content_hash = Self::compute_sha256(&source_id, timestamp_ns, &context, &payload)?;
@@ -135,6 +202,28 @@ impl ObservedAction {
         })
     }
 
+    /// Checks an observation before it is hashed.
+    fn validate(
+        source_id: &str,
+        timestamp_ns: i64,
+        payload: &serde_json::Value,
+        max_payload_bytes: usize,
+    ) -> Result<(), ActionError> {
+        if source_id.trim().is_empty() {
+            return Err(ObservedActionError::EmptySource.into());
+        }
+        if timestamp_ns < 0 {
+            return Err(ObservedActionError::NegativeTimestamp { value: timestamp_ns }.into());
+        }
+        let size = serde_json::to_vec(payload)
+            .map_err(|e| ActionError::PayloadSerializationFailed(e.to_string()))?
+            .len();
+        if size > max_payload_bytes {
+            return Err(ObservedActionError::PayloadTooLarge { max: max_payload_bytes, got: size }.into());
+        }
+        Ok(())
+    }
+
     /// Computes the SHA-256 hash of the action's immutable components.
     ///
     /// This hash serves as the Foucaultian signature - cryptographic
@@ -214,6 +303,11 @@ impl ActionSequence {
         Self { actions: Vec::new() }
     }
 
+    /// Starts a builder (the documented construction path).
+    pub fn builder() -> ActionSequenceBuilder {
+        ActionSequenceBuilder::default()
+    }
+
     /// Creates a sequence from existing actions.
     pub fn from_actions(actions: Vec<ObservedAction>) -> Self {
         Self { actions }
@@ -234,6 +328,11 @@ impl ActionSequence {
         self.actions.is_empty()
     }
 
+    /// Iterates over the actions in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, ObservedAction> {
+        self.actions.iter()
+    }
+
     /// Verifies integrity of all actions in the sequence.
     pub fn verify_all(&self) -> Result<(), ActionError> {
         for action in &self.actions {
@@ -249,10 +348,106 @@ impl Default for ActionSequence {
     }
 }
 
+impl<'a> IntoIterator for &'a ActionSequence {
+    type Item = &'a ObservedAction;
+    type IntoIter = std::slice::Iter<'a, ObservedAction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.actions.iter()
+    }
+}
+
+/// Builder for `ActionSequence`.
+///
+/// Actions keep their insertion order; anything derived from the
+/// sequence (e.g. DNA fingerprints) sees them exactly as pushed. With
+/// `dedup_consecutive`, an action pushed twice in a row (same `id`) is
+/// kept once. Repeated observations of the same content have distinct
+/// ids and are all kept: each is its own event.
+#[derive(Debug, Clone, Default)]
+pub struct ActionSequenceBuilder {
+    actions: Vec<ObservedAction>,
+    dedup_consecutive: bool,
+}
+
+impl ActionSequenceBuilder {
+    /// Appends an action.
+    pub fn push(mut self, action: ObservedAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Appends actions in iteration order.
+    pub fn extend<I: IntoIterator<Item = ObservedAction>>(mut self, actions: I) -> Self {
+        self.actions.extend(actions);
+        self
+    }
+
+    /// Collapses runs of the same action (same `id`) into one.
+    pub fn dedup_consecutive(mut self) -> Self {
+        self.dedup_consecutive = true;
+        self
+    }
+
+    /// Builds the sequence.
+    pub fn build(self) -> ActionSequence {
+        let mut actions = self.actions;
+        if self.dedup_consecutive {
+            actions.dedup_by(|a, b| a.id == b.id);
+        }
+        ActionSequence { actions }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn sample_action(timestamp_ns: i64) -> ObservedAction {
+        ObservedAction::new(
+            "source".to_string(),
+            timestamp_ns,
+            BTreeMap::new(),
+            serde_json::json!({"t": timestamp_ns}),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_builder_preserves_order() {
+        let actions: Vec<ObservedAction> = (1..=5).map(sample_action).collect();
+        let sequence = ActionSequence::builder()
+            .push(actions[0].clone())
+            .extend(actions[1..4].iter().cloned())
+            .push(actions[4].clone())
+            .build();
+
+        assert_eq!(sequence.len(), 5);
+        assert!(!sequence.is_empty());
+        let stamps: Vec<i64> = sequence.iter().map(|a| a.timestamp_ns).collect();
+        assert_eq!(stamps, vec![1, 2, 3, 4, 5]);
+        assert!(ActionSequence::builder().build().is_empty());
+    }
+
+    #[test]
+    fn test_builder_dedup_consecutive() {
+        let a = sample_action(1);
+        let b = sample_action(2);
+        let repeat = sample_action(1); // same content, new observation
+
+        let build = |dedup: bool| {
+            let builder = ActionSequence::builder()
+                .extend([a.clone(), a.clone(), b.clone(), b.clone(), a.clone(), repeat.clone()]);
+            if dedup { builder.dedup_consecutive() } else { builder }.build()
+        };
+
+        assert_eq!(build(false).len(), 6);
+
+        let deduped = build(true);
+        let ids: Vec<&ActionId> = (&deduped).into_iter().map(|x| &x.id).collect();
+        assert_eq!(ids, vec![&a.id, &b.id, &a.id, &repeat.id]);
+    }
+
     #[test]
     fn test_sha256_determinism() {
         let mut context = BTreeMap::new();
@@ -398,4 +593,64 @@ mod tests {
         // Hashes must be identical regardless of insertion order
         assert_eq!(action1.content_hash, action2.content_hash);
     }
+
+    #[test]
+    fn test_rejects_empty_source() {
+        let result = ObservedAction::new(" ".to_string(), 0, BTreeMap::new(), serde_json::json!(null));
+        assert_eq!(result.unwrap_err(), ActionError::Invalid(ObservedActionError::EmptySource));
+    }
+
+    #[test]
+    fn test_rejects_negative_timestamp() {
+        let result = ObservedAction::new("source".to_string(), -1, BTreeMap::new(), serde_json::json!(null));
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::Invalid(ObservedActionError::NegativeTimestamp { value: -1 })
+        );
+    }
+
+    #[test]
+    fn test_rejects_payload_too_large() {
+        let payload = serde_json::json!({"data": "x".repeat(32)});
+        let size = serde_json::to_vec(&payload).unwrap().len();
+
+        let result = ObservedAction::with_max_payload(
+            "source".to_string(),
+            0,
+            BTreeMap::new(),
+            payload.clone(),
+            size - 1,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::Invalid(ObservedActionError::PayloadTooLarge { max: size - 1, got: size })
+        );
+
+        // Exactly at the limit is accepted
+        let action = ObservedAction::with_max_payload("source".to_string(), 0, BTreeMap::new(), payload, size)
+            .unwrap();
+        assert!(action.is_valid());
+    }
+
+    #[test]
+    fn test_valid_construction_unchanged() {
+        let action = ObservedAction::new(
+            "source".to_string(),
+            0,
+            BTreeMap::new(),
+            serde_json::json!({"test": true}),
+        )
+        .unwrap();
+        let bounded = ObservedAction::with_max_payload(
+            "source".to_string(),
+            0,
+            BTreeMap::new(),
+            serde_json::json!({"test": true}),
+            DEFAULT_MAX_PAYLOAD_BYTES,
+        )
+        .unwrap();
+
+        assert_eq!(action.content_hash, bounded.content_hash);
+        assert!(action.verify_integrity().is_ok());
+    }
 }
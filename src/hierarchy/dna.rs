@@ -27,11 +27,14 @@
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - Added ephemeral comprehension documentation (v1.3.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Added fingerprint_with for pluggable hashers
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
 use crate::core_types::DnaId;
+use crate::hashing::{DefaultHasher, Hasher};
+use crate::motors::{ChaosOutput, MeristicOutput, MotorType, NashOutput, PraxisOutput};
 use super::action::ActionSequence;
 
 /// Level 1: Golden DNA.
@@ -104,8 +107,37 @@ pub struct MotorScores {
     /// Meristic Motor score (M_M).
     /// In Community Edition, this reflects structural creativity potential.
     pub meristic: f64,
+
+    /// Which motor scores were clamped by their motor
+    /// (praxeological, nash, chaotic, meristic).
+    #[serde(default)]
+    pub clamped: [bool; 4],
+}
+
+/// Error from `MotorScores::new_checked`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotorScoreError {
+    /// Score outside [0, 1] or non-finite.
+    OutOfRange {
+        /// Offending motor.
+        motor: MotorType,
+        /// Rejected value.
+        value: f64,
+    },
 }
 
+impl std::fmt::Display for MotorScoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MotorScoreError::OutOfRange { motor, value } => {
+                write!(f, "{} score {} is outside [0, 1]", motor, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MotorScoreError {}
+
 impl MotorScores {
     /// Creates a new MotorScores instance.
     pub fn new(praxeological: f64, nash: f64, chaotic: f64, meristic: f64) -> Self {
@@ -114,16 +146,58 @@ impl MotorScores {
             nash,
             chaotic,
             meristic,
+            clamped: [false; 4],
         }
     }
 
+    /// Creates scores, rejecting any value outside [0, 1] or non-finite.
+    pub fn new_checked(
+        praxeological: f64,
+        nash: f64,
+        chaotic: f64,
+        meristic: f64,
+    ) -> Result<Self, MotorScoreError> {
+        let scores = [praxeological, nash, chaotic, meristic];
+        for (motor, value) in MotorType::all().into_iter().zip(scores) {
+            if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+                return Err(MotorScoreError::OutOfRange { motor, value });
+            }
+        }
+        Ok(Self::new(praxeological, nash, chaotic, meristic))
+    }
+
+    /// Collects scores from the four motor outputs, recording which
+    /// ones the motors had to clamp.
+    pub fn from_outputs(
+        praxis: &PraxisOutput,
+        nash: &NashOutput,
+        chaos: &ChaosOutput,
+        meristic: &MeristicOutput,
+    ) -> Self {
+        Self {
+            praxeological: praxis.score,
+            nash: nash.score,
+            chaotic: chaos.score,
+            meristic: meristic.score,
+            clamped: [
+                praxis.was_clamped,
+                nash.was_clamped,
+                chaos.was_clamped,
+                meristic.was_clamped,
+            ],
+        }
+    }
+
+    /// Returns true if any motor clamped its score.
+    pub fn any_clamped(&self) -> bool {
+        self.clamped.iter().any(|&c| c)
+    }
+
     /// Calculates Craft Performance using the non-compensatory formula.
     /// CP = M_P × M_N × M_C × M_M
     ///
     /// If any motor is below threshold, CP is zero (absolute veto).
     pub fn calculate_cp(&self) -> f64 {
-        use crate::math::VETO_THRESHOLD;
-        
         // Check for veto before calculation
         if self.has_veto() {
             return 0.0;
@@ -132,7 +206,7 @@ impl MotorScores {
         let cp = self.praxeological * self.nash * self.chaotic * self.meristic;
         
         // Final CP below threshold also triggers veto
-        if cp < VETO_THRESHOLD {
+        if crate::math::is_cp_vetoed(cp) {
             return 0.0;
         }
         
@@ -157,6 +231,7 @@ impl Default for MotorScores {
             nash: 0.0,
             chaotic: 0.0,
             meristic: 0.0,
+            clamped: [false; 4],
         }
     }
 }
@@ -204,6 +279,60 @@ impl GoldenDna {
     pub fn action_count(&self) -> usize {
         self.action_sequence.len()
     }
+
+    /// Canonical SHA-256 fingerprint of the DNA content.
+    ///
+    /// The random `id` is excluded, so two strands with the same content
+    /// share a fingerprint. Fixed order, each field length-prefixed or
+    /// fixed-width (little-endian):
+    /// 1. action count, then each action content hash
+    /// 2. motor scores (praxeological, nash, chaotic, meristic) as IEEE 754 bits
+    /// 3. craft_performance as IEEE 754 bits
+    /// 4. generation
+    /// 5. lineage count, then each parent id
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.fingerprint_with::<DefaultHasher>()
+    }
+
+    /// `fingerprint` computed with an explicit hasher.
+    pub fn fingerprint_with<H: Hasher>(&self) -> [u8; 32] {
+        let mut hasher = H::default();
+
+        hasher.update(&(self.action_sequence.actions.len() as u64).to_le_bytes());
+        for action in &self.action_sequence.actions {
+            hasher.update(&(action.content_hash.len() as u64).to_le_bytes());
+            hasher.update(action.content_hash.as_bytes());
+        }
+
+        for score in [
+            self.motor_scores.praxeological,
+            self.motor_scores.nash,
+            self.motor_scores.chaotic,
+            self.motor_scores.meristic,
+            self.craft_performance,
+        ] {
+            hasher.update(&score.to_bits().to_le_bytes());
+        }
+
+        hasher.update(&self.generation.to_le_bytes());
+
+        hasher.update(&(self.lineage.len() as u64).to_le_bytes());
+        for parent in &self.lineage {
+            hasher.update(parent.to_string().as_bytes());
+        }
+
+        hasher.finalize()
+    }
+
+    /// Hex encoding of `fingerprint`.
+    pub fn content_fingerprint(&self) -> String {
+        hex::encode(self.fingerprint())
+    }
+
+    /// Equality by content (ignores the random `id`).
+    pub fn content_eq(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
 }
 
 /// A codon represents a minimal functional unit within DNA.
@@ -226,3 +355,159 @@ impl Codon {
         Self { id, embedding, tags }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hierarchy::ObservedAction;
+    use std::collections::BTreeMap;
+
+    fn sample_dna() -> GoldenDna {
+        let action = ObservedAction::new(
+            "sensor".to_string(),
+            42,
+            BTreeMap::new(),
+            serde_json::json!({"v": 1}),
+        )
+        .unwrap();
+        GoldenDna::new(
+            ActionSequence::from_actions(vec![action]),
+            MotorScores::new(0.9, 0.8, 0.7, 0.6),
+            1,
+            vec![DnaId::new()],
+        )
+    }
+
+    fn motor_outputs() -> (PraxisOutput, NashOutput, ChaosOutput, MeristicOutput) {
+        use crate::motors::*;
+
+        let praxis = PraxisMotor::new().evaluate(&PraxisInput {
+            proposed: vec!["a".into(), "b".into()],
+            necessary: vec!["a".into(), "b".into()],
+            context_vector: vec![1.0, 0.0],
+            history_centroid: vec![1.0, 0.0],
+        });
+        let nash = NashMotor::new().evaluate(&NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![100, 0], vec![100, 0]],
+            scale: 100,
+        });
+        let chaos = ChaosMotor::new().evaluate(&ChaosInput {
+            reference_trajectory: vec![vec![0.0], vec![0.1], vec![0.2]],
+            perturbed_trajectory: vec![vec![0.01], vec![0.11], vec![0.21]],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        });
+        let meristic = MeristicMotor::new().evaluate(&MeristicInput {
+            current_embedding: vec![0.5, 0.5, 0.5],
+            historical_embeddings: vec![],
+            domain_characteristics: None,
+            exploration_depth: 3,
+            novelty_weight: 0.5,
+        });
+        (praxis, nash, chaos, meristic)
+    }
+
+    #[test]
+    fn test_from_outputs_propagates_clamping() {
+        let (praxis, nash, mut chaos, meristic) = motor_outputs();
+
+        let scores = MotorScores::from_outputs(&praxis, &nash, &chaos, &meristic);
+        assert_eq!(scores.praxeological, praxis.score);
+        assert_eq!(scores.nash, nash.score);
+        assert_eq!(scores.chaotic, chaos.score);
+        assert_eq!(scores.meristic, meristic.score);
+        assert!(!scores.clamped[2]);
+
+        chaos.was_clamped = true;
+        let scores = MotorScores::from_outputs(&praxis, &nash, &chaos, &meristic);
+        assert_eq!(scores.clamped, [false, false, true, false]);
+        assert!(scores.any_clamped());
+    }
+
+    #[test]
+    fn test_new_checked_rejects_out_of_range() {
+        assert!(MotorScores::new_checked(0.0, 0.5, 1.0, 0.3).is_ok());
+        assert_eq!(
+            MotorScores::new_checked(0.5, 1.2, 0.5, 0.5).unwrap_err(),
+            MotorScoreError::OutOfRange { motor: MotorType::Nash, value: 1.2 }
+        );
+        assert!(matches!(
+            MotorScores::new_checked(0.5, 0.5, 0.5, f64::NAN),
+            Err(MotorScoreError::OutOfRange { motor: MotorType::Meristic, .. })
+        ));
+        assert!(MotorScores::new_checked(-0.1, 0.5, 0.5, 0.5).is_err());
+        assert!(MotorScores::new_checked(0.5, 0.5, f64::INFINITY, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_equal_content_same_fingerprint() {
+        let a = sample_dna();
+        let mut b = a.clone();
+        b.id = DnaId::new();
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert!(a.content_eq(&b));
+        assert_eq!(a.content_fingerprint().len(), 64);
+    }
+
+    #[test]
+    fn test_default_hasher_matches_fingerprint() {
+        let dna = sample_dna();
+        assert_eq!(dna.fingerprint(), dna.fingerprint_with::<crate::hashing::Sha256Hasher>());
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_blake3_fingerprint() {
+        use crate::hashing::Blake3Hasher;
+
+        let a = sample_dna();
+        let b = a.clone();
+        assert_eq!(a.fingerprint_with::<Blake3Hasher>(), b.fingerprint_with::<Blake3Hasher>());
+        assert_ne!(a.fingerprint_with::<Blake3Hasher>(), a.fingerprint());
+    }
+
+    #[test]
+    fn test_mutating_any_field_changes_fingerprint() {
+        let base = sample_dna();
+        let fp = base.fingerprint();
+
+        let mut dna = base.clone();
+        dna.action_sequence.actions.clear();
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.motor_scores.praxeological = 0.91;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.motor_scores.nash = 0.81;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.motor_scores.chaotic = 0.71;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.motor_scores.meristic = 0.61;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.craft_performance = 0.5;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.generation = 2;
+        assert_ne!(dna.fingerprint(), fp);
+
+        let mut dna = base.clone();
+        dna.lineage.push(DnaId::new());
+        assert_ne!(dna.fingerprint(), fp);
+        assert!(!dna.content_eq(&base));
+    }
+}
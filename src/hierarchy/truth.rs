@@ -16,13 +16,37 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
+//! 2026-10-16 - Carlos Eduardo Favini - Detached attestation on Foucaultian truths
+//! 2026-10-16 - Carlos Eduardo Favini - Platonic truth lineage (parent_hash, lineage walk)
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 
 use super::action::ObservedAction;
 use super::dna::GoldenDna;
 
+/// Detached signature over a truth's registration hash.
+///
+/// The crate does not interpret the signature; verification is
+/// delegated to the caller's crypto (ed25519, ECDSA, HSM, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attestation {
+    /// Identifier of the signing entity (key id, certificate subject...).
+    pub signer_id: String,
+
+    /// Raw signature bytes.
+    pub signature: Vec<u8>,
+}
+
+impl Attestation {
+    /// Creates a new attestation.
+    pub fn new(signer_id: String, signature: Vec<u8>) -> Self {
+        Self { signer_id, signature }
+    }
+}
+
 /// Foucaultian Truth: What WAS.
 ///
 /// Represents immutable historical facts. Once registered, a
@@ -50,6 +74,16 @@ pub struct FoucaultianTruth {
 
     /// The registering entity.
     pub registrar: String,
+
+    /// Registration hash of the preceding truth in a chained archive.
+    /// When set, it is folded into `registration_hash`.
+    #[serde(default)]
+    pub previous_hash: Option<String>,
+
+    /// Optional detached signature over `registration_hash`.
+    /// Not part of `verify()`; see `verify_attestation()`.
+    #[serde(default)]
+    pub attestation: Option<Attestation>,
 }
 
 impl FoucaultianTruth {
@@ -65,9 +99,43 @@ impl FoucaultianTruth {
             registration_hash,
             registered_at_ns,
             registrar,
+            previous_hash: None,
+            attestation: None,
+        }
+    }
+
+    /// Attaches a detached attestation, replacing any existing one.
+    ///
+    /// The signature must cover the current `registration_hash`;
+    /// re-linking the truth afterwards invalidates it.
+    pub fn attest(&mut self, attestation: Attestation) {
+        self.attestation = Some(attestation);
+    }
+
+    /// Computes the registration hash for a content hash and chain link.
+    ///
+    /// Unchained: the content hash itself. Chained: hex SHA-256 of
+    /// `content:{content_hash}|previous:{previous_hash}`.
+    pub fn compute_registration_hash(content_hash: &str, previous_hash: Option<&str>) -> String {
+        match previous_hash {
+            None => content_hash.to_string(),
+            Some(previous) => {
+                let data = format!("content:{}|previous:{}", content_hash, previous);
+                let mut hasher = Sha256::new();
+                hasher.update(data.as_bytes());
+                hex::encode(hasher.finalize())
+            }
         }
     }
 
+    /// Links this truth after `previous_hash` and recomputes its
+    /// registration hash accordingly.
+    pub fn link_to(&mut self, previous_hash: Option<String>) {
+        self.registration_hash =
+            Self::compute_registration_hash(&self.raw_fact.content_hash, previous_hash.as_deref());
+        self.previous_hash = previous_hash;
+    }
+
     /// Verifies the integrity of this truth.
     ///
     /// Performs complete chain verification:
@@ -83,8 +151,34 @@ impl FoucaultianTruth {
         }
 
         // Step 2: Verify registration hash matches content hash
+        // (and the chain link, if any)
         // This ensures the registration is for this specific content
-        self.registration_hash == self.raw_fact.content_hash
+        self.registration_hash
+            == Self::compute_registration_hash(
+                &self.raw_fact.content_hash,
+                self.previous_hash.as_deref(),
+            )
+    }
+
+    /// Verifies the detached attestation with caller-provided crypto.
+    ///
+    /// `verify_fn` receives `(message, signature, signer_id)`, where the
+    /// message is the UTF-8 bytes of the hex `registration_hash`.
+    ///
+    /// # Returns
+    /// `false` if there is no attestation or `verify()` fails (the
+    /// signature would then cover a corrupted record); otherwise the
+    /// result of `verify_fn`.
+    pub fn verify_attestation(&self, verify_fn: impl Fn(&[u8], &[u8], &str) -> bool) -> bool {
+        let Some(attestation) = &self.attestation else {
+            return false;
+        };
+        self.verify()
+            && verify_fn(
+                self.registration_hash.as_bytes(),
+                &attestation.signature,
+                &attestation.signer_id,
+            )
     }
 
     /// Verifies only the raw fact integrity without checking registration.
@@ -121,6 +215,11 @@ pub struct PlatonicTruth {
 
     /// Evidence supporting this synthesis (Foucaultian truths).
     pub supporting_evidence: Vec<String>, // Hashes of Foucaultian truths
+
+    /// `truth_hash` of the Platonic truth this one supersedes.
+    /// `None` for the root of a lineage.
+    #[serde(default)]
+    pub parent_hash: Option<String>,
 }
 
 impl PlatonicTruth {
@@ -136,6 +235,7 @@ impl PlatonicTruth {
             version,
             synthesized_at_ns,
             supporting_evidence,
+            parent_hash: None,
         }
     }
 
@@ -146,9 +246,77 @@ impl PlatonicTruth {
             version: 1,
             synthesized_at_ns,
             supporting_evidence: Vec::new(),
+            parent_hash: None,
+        }
+    }
+
+    /// Creates the successor of this truth.
+    ///
+    /// The successor has `version + 1` and `parent_hash` set to this
+    /// truth's `truth_hash`. This truth itself is left unchanged.
+    pub fn evolve(
+        &self,
+        ideal: GoldenDna,
+        synthesized_at_ns: i64,
+        supporting_evidence: Vec<String>,
+    ) -> Self {
+        Self {
+            ideal,
+            version: self.version + 1,
+            synthesized_at_ns,
+            supporting_evidence,
+            parent_hash: Some(self.truth_hash()),
         }
     }
 
+    /// Returns true if this truth has no parent.
+    pub fn is_root(&self) -> bool {
+        self.parent_hash.is_none()
+    }
+
+    /// Canonical hex SHA-256 identifying this truth in a lineage.
+    ///
+    /// Hashes `ideal:{fingerprint}|version:{v}|synthesized:{ns}|evidence:{h1,h2,..}|parent:{hash}`,
+    /// using the ideal's content fingerprint (its random id is excluded)
+    /// and an empty parent for a root.
+    pub fn truth_hash(&self) -> String {
+        let data = format!(
+            "ideal:{}|version:{}|synthesized:{}|evidence:{}|parent:{}",
+            self.ideal.content_fingerprint(),
+            self.version,
+            self.synthesized_at_ns,
+            self.supporting_evidence.join(","),
+            self.parent_hash.as_deref().unwrap_or(""),
+        );
+        let mut hasher = Sha256::new();
+        hasher.update(data.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Reconstructs the ancestry of this truth from a set of known truths.
+    ///
+    /// Follows `parent_hash` links through `known` and returns the chain
+    /// oldest first, ending with `self`. The walk stops at a root, at a
+    /// parent missing from `known`, or at a repeated hash.
+    pub fn lineage<'a>(&'a self, known: &'a [PlatonicTruth]) -> Vec<&'a PlatonicTruth> {
+        let by_hash: HashMap<String, &PlatonicTruth> =
+            known.iter().map(|t| (t.truth_hash(), t)).collect();
+
+        let mut chain = vec![self];
+        let mut seen = HashSet::from([self.truth_hash()]);
+        let mut current = self;
+        while let Some(parent) = current.parent_hash.as_ref().and_then(|h| by_hash.get(h)) {
+            if !seen.insert(parent.truth_hash()) {
+                break;
+            }
+            chain.push(parent);
+            current = parent;
+        }
+
+        chain.reverse();
+        chain
+    }
+
     /// Returns the Craft Performance of the ideal.
     pub fn craft_performance(&self) -> f64 {
         self.ideal.craft_performance
@@ -208,3 +376,134 @@ impl TruthComparison {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::action::ActionSequence;
+    use super::super::dna::MotorScores;
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    fn create_truth() -> FoucaultianTruth {
+        let action = ObservedAction::new(
+            "sensor".to_string(),
+            1_000,
+            BTreeMap::new(),
+            serde_json::json!({"value": 1}),
+        )
+        .unwrap();
+        let hash = action.content_hash.clone();
+        FoucaultianTruth::new(action, hash, 2_000, "registrar".to_string())
+    }
+
+    // Stub "signature": the message reversed, accepted only for `signer`.
+    fn stub_verify(message: &[u8], signature: &[u8], signer_id: &str) -> bool {
+        signer_id == "signer" && message.iter().rev().eq(signature.iter())
+    }
+
+    fn stub_sign(truth: &FoucaultianTruth, signer_id: &str) -> Attestation {
+        let signature = truth.registration_hash.bytes().rev().collect();
+        Attestation::new(signer_id.to_string(), signature)
+    }
+
+    #[test]
+    fn test_unsigned_truth() {
+        let truth = create_truth();
+        assert!(truth.verify());
+        assert!(!truth.verify_attestation(|_, _, _| true));
+    }
+
+    #[test]
+    fn test_attestation_routes_to_verify_fn() {
+        let mut truth = create_truth();
+        truth.attest(stub_sign(&truth, "signer"));
+
+        let seen = RefCell::new(None);
+        let accepted = truth.verify_attestation(|message, signature, signer_id| {
+            *seen.borrow_mut() = Some((message.to_vec(), signature.to_vec(), signer_id.to_string()));
+            stub_verify(message, signature, signer_id)
+        });
+
+        assert!(accepted);
+        let (message, signature, signer_id) = seen.into_inner().unwrap();
+        assert_eq!(message, truth.registration_hash.as_bytes());
+        assert_eq!(signature, truth.attestation.as_ref().unwrap().signature);
+        assert_eq!(signer_id, "signer");
+        assert!(!truth.verify_attestation(|_, _, _| false));
+    }
+
+    #[test]
+    fn test_attestation_rejections() {
+        // Unknown signer
+        let mut truth = create_truth();
+        truth.attest(stub_sign(&truth, "intruder"));
+        assert!(!truth.verify_attestation(stub_verify));
+
+        // Re-linking changes the signed message
+        let mut truth = create_truth();
+        truth.attest(stub_sign(&truth, "signer"));
+        truth.link_to(Some("previous".to_string()));
+        assert!(truth.verify());
+        assert!(!truth.verify_attestation(stub_verify));
+
+        // Corrupted record is rejected before verify_fn is consulted
+        let mut truth = create_truth();
+        truth.attest(stub_sign(&truth, "signer"));
+        truth.raw_fact.source_id = "tampered".to_string();
+        assert!(!truth.verify_attestation(|_, _, _| true));
+    }
+
+    fn create_ideal(score: f64) -> GoldenDna {
+        GoldenDna::primordial(ActionSequence::new(), MotorScores::new(score, score, score, score))
+    }
+
+    #[test]
+    fn test_platonic_lineage_three_generations() {
+        let root = PlatonicTruth::initial(create_ideal(0.5), 100);
+        let second = root.evolve(create_ideal(0.6), 200, vec!["evidence-a".to_string()]);
+        let third = second.evolve(create_ideal(0.7), 300, vec!["evidence-b".to_string()]);
+
+        assert!(root.is_root());
+        assert_eq!(root.parent_hash, None);
+        assert_eq!(second.parent_hash, Some(root.truth_hash()));
+        assert_eq!(third.parent_hash, Some(second.truth_hash()));
+        assert_eq!((root.version, second.version, third.version), (1, 2, 3));
+
+        // Order of the known set does not matter
+        let known = vec![third.clone(), root.clone(), second.clone()];
+        let versions: Vec<u64> = third.lineage(&known).iter().map(|t| t.version).collect();
+        assert_eq!(versions, vec![1, 2, 3]);
+
+        let chain = third.lineage(&known);
+        assert!(chain[0].is_root());
+        assert_eq!(chain[2].truth_hash(), third.truth_hash());
+    }
+
+    #[test]
+    fn test_platonic_lineage_missing_parent() {
+        let root = PlatonicTruth::initial(create_ideal(0.5), 100);
+        let second = root.evolve(create_ideal(0.6), 200, Vec::new());
+        let third = second.evolve(create_ideal(0.7), 300, Vec::new());
+
+        // Without the middle generation the walk stops at `third`
+        let chain = third.lineage(std::slice::from_ref(&root));
+        assert_eq!(chain.len(), 1);
+        assert_eq!(root.lineage(&[]).len(), 1);
+    }
+
+    #[test]
+    fn test_attestation_serde_default() {
+        let mut truth = create_truth();
+        truth.attest(stub_sign(&truth, "signer"));
+        let json = serde_json::to_string(&truth).unwrap();
+        let restored: FoucaultianTruth = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.attestation, truth.attestation);
+
+        let mut value = serde_json::to_value(&truth).unwrap();
+        value.as_object_mut().unwrap().remove("attestation");
+        let legacy: FoucaultianTruth = serde_json::from_value(value).unwrap();
+        assert!(legacy.attestation.is_none());
+        assert!(legacy.verify());
+    }
+}
@@ -39,40 +39,62 @@
 //!              AF-11 (Aprendizado), AF-12 (MCI), AO-18 (Autorreferência)
 //! 2026-01-29 - Carlos Eduardo Favini - MVP-7: Validação Final (v0.7.0)
 //!              MCI integrado no CognitiveCycle, LEI-AF-12-04 completo
+//! 2026-10-16 - Carlos Eduardo Favini - no_std carve-out (`std` default feature)
 //! --------------------------
+//!
+//! # no_std
+//! With `default-features = false, features = ["libm"]` the crate is
+//! `no_std` + `alloc` and exposes only the pure-math pieces: `motors`,
+//! `budget` (without the time-based `BudgetGuard`), `correlation` and
+//! `hashing`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod compat;
 
 // =============================================================================
 // CORE MODULES - COMMUNITY EDITION
 // =============================================================================
 
 /// Core type definitions (identifiers, base types)
+#[cfg(feature = "std")]
 pub mod core_types;
 
 /// Biological hierarchy (Action, DNA, Synapse, Neuron, Brain, Truth)
+#[cfg(feature = "std")]
 pub mod hierarchy;
 
 /// Cognitive motors (Praxeological, Nash, Chaotic, Meristic)
 pub mod motors;
 
 /// Mathematical foundations (Craft Performance formula)
+#[cfg(feature = "std")]
 pub mod math;
 
 /// Latent Archive (Foucaultian memory)
+#[cfg(feature = "std")]
 pub mod archive;
 
 /// Selection criteria (Golden Index)
+#[cfg(feature = "std")]
 pub mod selection;
 
 /// Topological structures (synaptic connections)
+#[cfg(feature = "std")]
 pub mod topology;
 
 /// Traits for Enterprise integration
+#[cfg(feature = "std")]
 pub mod traits;
 
 /// Replay harness for deterministic execution and verification (v0.3.0)
+#[cfg(feature = "std")]
 pub mod replay;
 
 /// Sensory cortex for abstraction hierarchy (v1.1.0)
+#[cfg(feature = "std")]
 pub mod sensory;
 
 // =============================================================================
@@ -82,13 +104,19 @@ pub mod sensory;
 /// Inference by correlation (Insight #3)
 pub mod correlation;
 
+/// Pluggable content hashing (SHA-256 default, optional BLAKE3)
+pub mod hashing;
+
 /// Motor competition and cooperation (Insight #6)
+#[cfg(feature = "std")]
 pub mod competition;
 
 /// Cognitive observability / metacognition (Insight #7)
+#[cfg(feature = "std")]
 pub mod observability;
 
 /// Cognitive completeness states (Insight #10)
+#[cfg(feature = "std")]
 pub mod completeness;
 
 // =============================================================================
@@ -118,12 +146,15 @@ pub mod budget;
 /// - Maturation is NOT memory (no recall of previous inputs)
 /// - Maturation IS confined to the perceptual cycle
 /// - Maturation IS discarded entirely at the end
+#[cfg(feature = "std")]
 pub mod maturation;
 
 /// Cognitive communication structures (L-003, L-004, L-008)
+#[cfg(feature = "std")]
 pub mod cognitive;
 
 /// Universal Notation Language and GD-QMN (L-009)
+#[cfg(feature = "std")]
 pub mod unl;
 
 // =============================================================================
@@ -141,31 +172,46 @@ pub mod unl;
 /// - MCI is NOT observation (does not store raw inputs)
 /// - MCI IS internal (stores evaluated Codons)
 /// - Origin IS deterministic (consistent under replay)
+#[cfg(feature = "std")]
 pub mod memory;
 
 // =============================================================================
 // RE-EXPORTS
 // =============================================================================
 
+#[cfg(feature = "std")]
 pub use core_types::*;
-pub use math::craft::{CraftPerformance, CpResult};
-pub use replay::{ReplayContext, ReplaySession, ReplayEvent, ReplayVerifier};
+#[cfg(feature = "std")]
+pub use math::craft::{Aggregation, CraftPerformance, CpResult, CP_EQUALITY_THRESHOLD, VETO_THRESHOLD, is_cp_vetoed};
+#[cfg(feature = "std")]
+pub use replay::{
+    DeterministicRng, ReplayCheckpoint, ReplayContext, ReplaySession, ReplayEvent, ReplayVerifier,
+    ReplayError, SignedSession, CompareOptions,
+};
+#[cfg(feature = "std")]
 pub use sensory::{
-    CommunityOutput, CortexOutput, MatureOutput, 
-    RawInput, SensoryCortex, SensorySignals,
+    CommunityOutput, CortexConfig, CortexDiff, CortexOutput, MatureOutput, OutputSchemaError,
+    RawInput, SensoryCortex, SensorySignals, SignalAccumulator,
     PerceptualState, StateHistory, StateTransition,
 };
 
 // v1.2.0 exports
-pub use correlation::{CorrelationMatrix, CooccurrenceTracker, TransformationTracker};
-pub use competition::{MotorCompetition, MotorCooperation, MotorDynamics, MotorType};
+pub use correlation::{CorrelationMatrix, CooccurrenceTracker, OnlineCorrelation, TransformationTracker};
+#[cfg(feature = "std")]
+pub use competition::{
+    MotorCompetition, MotorCooperation, MotorDynamics, MotorDynamicsSequence, MotorType,
+    RelevanceMode,
+};
+#[cfg(feature = "std")]
 pub use observability::{CognitiveObservability, HealthIndicators, ProgressTracker};
-pub use completeness::{CognitiveCompleteness, AbstractionLevel, ConflictType, MissingSignal};
+#[cfg(feature = "std")]
+pub use completeness::{CognitiveCompleteness, AbstractionLevel, ConflictType, MissingSignal, detect_conflicts};
 
 // v0.5.0 exports (cognitive cycle)
+#[cfg(feature = "std")]
 pub use cognitive::{
     CognitiveCycle, CycleOutput, MotorContext, MotorScores,
-    TransportCode, ObservationReport, MotorSignatures,
+    TransportCode, ObservationReport, MotorSignatures, FrameError,
     // v0.6.0: Structured DNA
     StructuredDNA, DnaBuilder, AtomicAction, Uncertainty, MeristicSuggestion,
 };
@@ -173,26 +219,32 @@ pub use cognitive::{
 // v1.4.0 exports
 pub use budget::{
     ComputationalBudget, IntegrityCheck, NumericalIssue,
-    ComplexityClass, BudgetGuard,
-    check_bytes_budget, check_numerical_stability, check_time_budget,
+    ComplexityClass,
+    check_bytes_budget, check_size_budget, check_numerical_stability, check_time_budget,
 };
+#[cfg(feature = "std")]
+pub use budget::{BudgetConfigError, BudgetGuard};
 
 // v1.5.0 exports
+#[cfg(feature = "std")]
 pub use maturation::{
-    MaturationConfig, MaturationState, StopReason,
+    ConvergencePolicy, MaturationConfig, MaturationState, MetricThresholds, StopReason,
     RefinementStep, RefinementMetrics,
 };
 
 // v0.6.0 exports (MVP-6: Cognitive Memory)
+#[cfg(feature = "std")]
 pub use memory::{
     // Context (LEI-AF-12-02)
     CanonicalContext,
     // Codon (LEI-AF-12-01)
-    CanonicalCodon, Origin, EvaluativeSignature, ActivationCondition, ReplayableProvenance,
+    CanonicalCodon, Origin, EvaluativeSignature, EvaluativeSignatureBuilder, SignatureError,
+    ActivationCondition, ReplayableProvenance,
     // MCI (AF-12)
-    MCI, MciQueryResult, MciStats, MciError,
+    MCI, MciQueryResult, MciStats, MciError, EvictionPolicy, Incorporation, SharedMci,
     // Learning (AF-11)
     LearningEngine, LearningResult, EpistemicTrigger, RejectionReason, StagnationDetector,
+    DEFAULT_STAGNATION_THRESHOLD,
 };
 
 // =============================================================================
@@ -202,7 +254,7 @@ pub use memory::{
 // If any type fails to be Send + Sync, the build will fail.
 // See THREADING.md for the complete threading policy.
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod thread_safety_tests {
     use super::*;
 
@@ -213,6 +265,7 @@ mod thread_safety_tests {
         assert_send_sync::<SensoryCortex>();
         assert_send_sync::<RawInput>();
         assert_send_sync::<SensorySignals>();
+        assert_send_sync::<SignalAccumulator>();
         assert_send_sync::<CommunityOutput>();
     }
 
@@ -228,6 +281,7 @@ mod thread_safety_tests {
         assert_send_sync::<MotorCompetition>();
         assert_send_sync::<MotorCooperation>();
         assert_send_sync::<MotorDynamics>();
+        assert_send_sync::<MotorDynamicsSequence>();
         assert_send_sync::<MotorType>();
     }
 
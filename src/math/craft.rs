@@ -43,10 +43,16 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Canonical veto threshold
 //! 2025-01-02 - Carlos Eduardo Favini - Added was_clamped signaling (v0.2.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Formal VETO_THRESHOLD documentation (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Alternative aggregation modes (Aggregation)
+//! 2026-10-16 - Carlos Eduardo Favini - CraftPerformanceResult::verify_consistency
+//! 2026-10-16 - Carlos Eduardo Favini - is_cp_vetoed predicate
+//! 2026-10-16 - Carlos Eduardo Favini - CP_EQUALITY_THRESHOLD derived from VETO_THRESHOLD
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
+use crate::motors::MotorType;
+
 /// Canonical veto threshold for Craft Performance.
 ///
 /// # Value
@@ -64,6 +70,22 @@ use serde::{Deserialize, Serialize};
 /// See module documentation for full specification.
 pub const VETO_THRESHOLD: f64 = 1e-15;
 
+/// Equality zone for comparing two CP values: `sqrt(VETO_THRESHOLD)`.
+///
+/// Two viable CPs closer than this are treated as equal. Written as a
+/// literal because `f64::sqrt` is not `const`; a test keeps it equal to
+/// `VETO_THRESHOLD.sqrt()`.
+pub const CP_EQUALITY_THRESHOLD: f64 = 3.162277660168379e-8;
+
+/// The veto rule: true when `cp < VETO_THRESHOLD`.
+///
+/// Use this instead of comparing against the threshold directly so every
+/// caller applies the same rule. NaN is not below the threshold and is
+/// left to validation.
+pub fn is_cp_vetoed(cp: f64) -> bool {
+    cp < VETO_THRESHOLD
+}
+
 /// Craft Performance (CP) calculator.
 ///
 /// The formula CP = M_P × M_N × M_C × M_M implements the
@@ -213,7 +235,7 @@ impl CraftPerformance {
         let cp = m_p * m_n * m_c * m_m;
 
         // Check final product against threshold
-        if cp < VETO_THRESHOLD {
+        if is_cp_vetoed(cp) {
             return CpResult::Vetoed {
                 value: cp,
                 cause: VetoCause::FinalProduct,
@@ -281,6 +303,87 @@ impl CraftPerformance {
     pub const fn threshold() -> f64 {
         VETO_THRESHOLD
     }
+
+    /// Evaluates a score set with the canonical product aggregation.
+    ///
+    /// Equivalent to `compute_with(scores, Aggregation::Product)`.
+    pub fn compute(scores: &MotorScoreSet) -> CraftPerformanceResult {
+        Self::compute_with(scores, Aggregation::Product)
+    }
+
+    /// Evaluates a score set with the chosen aggregation policy.
+    ///
+    /// Invalid inputs and individual motor vetoes behave exactly as in
+    /// [`CraftPerformanceResult::from_scores`]: no aggregation mode can
+    /// compensate for a motor below `VETO_THRESHOLD`. Only the final
+    /// aggregated value differs between modes, and it is vetoed if it
+    /// falls below the threshold.
+    pub fn compute_with(scores: &MotorScoreSet, mode: Aggregation) -> CraftPerformanceResult {
+        let base = CraftPerformanceResult::from_scores(*scores);
+        if mode == Aggregation::Product || !base.valid || scores.is_vetoed() {
+            return CraftPerformanceResult { aggregation: mode, ..base };
+        }
+
+        let value = mode.aggregate(scores.as_array());
+        if is_cp_vetoed(value) {
+            return CraftPerformanceResult {
+                scores: *scores,
+                cp: 0.0,
+                vetoed: true,
+                veto_sources: Vec::new(),
+                valid: true,
+                was_clamped: false,
+                aggregation: mode,
+            };
+        }
+
+        let was_clamped = value > 1.0;
+        CraftPerformanceResult {
+            scores: *scores,
+            cp: value.clamp(0.0, 1.0),
+            vetoed: false,
+            veto_sources: Vec::new(),
+            valid: true,
+            was_clamped,
+            aggregation: mode,
+        }
+    }
+}
+
+/// Policy used to combine the four motor scores into CP.
+///
+/// `Product` is the canonical formula. The other modes are less punishing
+/// when a single motor is low but non-zero. All modes map [0, 1]^4 into
+/// [0, 1] and return 0.0 (never NaN) when any score is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Aggregation {
+    /// M_P × M_N × M_C × M_M (canonical).
+    #[default]
+    Product,
+    /// Fourth root of the product.
+    GeometricMean,
+    /// 4 / Σ(1/M); zero if any score is zero.
+    HarmonicMean,
+    /// Lowest motor score.
+    Min,
+}
+
+impl Aggregation {
+    /// Combines scores in canonical order [M_P, M_N, M_C, M_M].
+    pub fn aggregate(&self, scores: [f64; 4]) -> f64 {
+        match self {
+            Aggregation::Product => scores.iter().product(),
+            Aggregation::GeometricMean => scores.iter().product::<f64>().powf(0.25),
+            Aggregation::HarmonicMean => {
+                if scores.iter().any(|&s| s <= 0.0) {
+                    0.0
+                } else {
+                    scores.len() as f64 / scores.iter().map(|s| 1.0 / s).sum::<f64>()
+                }
+            }
+            Aggregation::Min => scores.iter().copied().fold(f64::INFINITY, f64::min),
+        }
+    }
 }
 
 /// Complete set of motor scores.
@@ -380,6 +483,68 @@ impl MotorScoreSet {
 
         min
     }
+
+    /// Returns the scores in canonical order [M_P, M_N, M_C, M_M].
+    pub fn as_array(&self) -> [f64; 4] {
+        [self.praxeological, self.nash, self.chaotic, self.meristic]
+    }
+
+    /// Computes the per-motor sensitivity of CP.
+    ///
+    /// See [`CpSensitivity`].
+    pub fn sensitivity(&self) -> CpSensitivity {
+        CpSensitivity::from_scores(self.as_array())
+    }
+}
+
+/// Sensitivity of CP to each motor score.
+///
+/// Since CP = M_P × M_N × M_C × M_M, the partial derivative with respect
+/// to one motor is the product of the other three. The motor with the
+/// largest gradient is the bottleneck: a marginal increase in its score
+/// raises CP the most. With no zeros this is the lowest-scoring motor.
+///
+/// A single zero score makes every gradient zero except the zeroed
+/// motor's. Two or more zeros make all gradients zero (no bottleneck).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CpSensitivity {
+    /// ∂CP/∂M in canonical order [M_P, M_N, M_C, M_M].
+    pub gradients: [f64; 4],
+
+    /// Motor with the largest gradient (ties resolved in canonical order).
+    /// None if all gradients are zero.
+    pub bottleneck: Option<MotorType>,
+}
+
+impl CpSensitivity {
+    /// Computes gradients from scores in canonical order.
+    pub fn from_scores(scores: [f64; 4]) -> Self {
+        let mut gradients = [0.0; 4];
+        for (i, gradient) in gradients.iter_mut().enumerate() {
+            *gradient = scores
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, s)| s)
+                .product();
+        }
+
+        let mut bottleneck = None;
+        let mut best = 0.0;
+        for (i, &gradient) in gradients.iter().enumerate() {
+            if gradient > best {
+                best = gradient;
+                bottleneck = MotorType::from_index(i);
+            }
+        }
+
+        Self { gradients, bottleneck }
+    }
+
+    /// Returns ∂CP/∂M for a given motor.
+    pub fn gradient(&self, motor: MotorType) -> f64 {
+        self.gradients[motor.index()]
+    }
 }
 
 impl Default for MotorScoreSet {
@@ -414,6 +579,10 @@ pub struct CraftPerformanceResult {
 
     /// Whether the CP value was clamped.
     pub was_clamped: bool,
+
+    /// Aggregation policy that produced `cp`.
+    #[serde(default)]
+    pub aggregation: Aggregation,
 }
 
 impl CraftPerformanceResult {
@@ -429,6 +598,7 @@ impl CraftPerformanceResult {
                 veto_sources: Vec::new(),
                 valid: true,
                 was_clamped,
+                aggregation: Aggregation::Product,
             },
             CpResult::Vetoed { value: _, cause: _ } => {
                 let sources = CraftPerformance::veto_sources(
@@ -448,6 +618,7 @@ impl CraftPerformanceResult {
                     veto_sources: sources,
                     valid: true,
                     was_clamped: false,
+                    aggregation: Aggregation::Product,
                 }
             }
             CpResult::Invalid { .. } => Self {
@@ -457,9 +628,29 @@ impl CraftPerformanceResult {
                 veto_sources: Vec::new(),
                 valid: false,
                 was_clamped: false,
+                aggregation: Aggregation::Product,
             },
         }
     }
+
+    /// Per-motor sensitivity of CP and the bottleneck motor.
+    ///
+    /// Pure arithmetic over `scores`; see [`CpSensitivity`].
+    pub fn sensitivity(&self) -> CpSensitivity {
+        self.scores.sensitivity()
+    }
+
+    /// Checks that the stored outcome follows from the stored scores.
+    ///
+    /// Recomputes CP from `scores` with the recorded `aggregation` and
+    /// requires the same `valid`/`vetoed` outcome and a `cp` within
+    /// `tolerance`. Intended for results loaded from storage.
+    pub fn verify_consistency(&self, tolerance: f64) -> bool {
+        let expected = CraftPerformance::compute_with(&self.scores, self.aggregation);
+        expected.valid == self.valid
+            && expected.vetoed == self.vetoed
+            && (expected.cp - self.cp).abs() <= tolerance
+    }
 }
 
 #[cfg(test)]
@@ -555,6 +746,25 @@ mod tests {
         assert_eq!(VETO_THRESHOLD, 1e-15);
     }
 
+    #[test]
+    fn test_is_cp_vetoed_boundary() {
+        let below = VETO_THRESHOLD * (1.0 - f64::EPSILON);
+        let above = VETO_THRESHOLD * (1.0 + f64::EPSILON);
+
+        assert!(is_cp_vetoed(0.0));
+        assert!(is_cp_vetoed(below));
+        assert!(!is_cp_vetoed(VETO_THRESHOLD));
+        assert!(!is_cp_vetoed(above));
+        assert!(!is_cp_vetoed(1.0));
+
+        // The DNA-level rule uses the same boundary
+        let root = VETO_THRESHOLD.sqrt().sqrt();
+        let at = crate::hierarchy::MotorScores::new(root, root, root, root * (1.0 + 1e-9));
+        let under = crate::hierarchy::MotorScores::new(root, root, root, root * (1.0 - 1e-9));
+        assert!(at.calculate_cp() > 0.0);
+        assert_eq!(under.calculate_cp(), 0.0);
+    }
+
     #[test]
     fn test_try_new_validation() {
         // Valid
@@ -567,6 +777,39 @@ mod tests {
         assert!(MotorScoreSet::try_new(0.5, f64::NAN, 0.5, 0.5).is_none());
     }
 
+    #[test]
+    fn test_sensitivity_gradients() {
+        let scores = MotorScoreSet::new(0.8, 0.7, 0.9, 0.6);
+        let result = CraftPerformanceResult::from_scores(scores);
+        let sens = result.sensitivity();
+
+        assert!((sens.gradient(MotorType::Praxis) - 0.7 * 0.9 * 0.6).abs() < 1e-12);
+        assert!((sens.gradient(MotorType::Nash) - 0.8 * 0.9 * 0.6).abs() < 1e-12);
+        assert!((sens.gradient(MotorType::Chaos) - 0.8 * 0.7 * 0.6).abs() < 1e-12);
+        assert!((sens.gradient(MotorType::Meristic) - 0.8 * 0.7 * 0.9).abs() < 1e-12);
+
+        // Bottleneck is the lowest-scoring motor
+        assert_eq!(sens.bottleneck, Some(MotorType::Meristic));
+    }
+
+    #[test]
+    fn test_sensitivity_single_zero() {
+        let sens = MotorScoreSet::new(0.8, 0.0, 0.9, 0.6).sensitivity();
+
+        assert_eq!(sens.gradient(MotorType::Praxis), 0.0);
+        assert_eq!(sens.gradient(MotorType::Chaos), 0.0);
+        assert_eq!(sens.gradient(MotorType::Meristic), 0.0);
+        assert!((sens.gradient(MotorType::Nash) - 0.8 * 0.9 * 0.6).abs() < 1e-12);
+        assert_eq!(sens.bottleneck, Some(MotorType::Nash));
+    }
+
+    #[test]
+    fn test_sensitivity_multiple_zeros() {
+        let sens = MotorScoreSet::new(0.0, 0.0, 0.9, 0.6).sensitivity();
+        assert_eq!(sens.gradients, [0.0; 4]);
+        assert_eq!(sens.bottleneck, None);
+    }
+
     #[test]
     fn test_was_clamped_in_result() {
         let scores = MotorScoreSet::new(0.8, 0.7, 0.9, 0.6);
@@ -576,4 +819,85 @@ mod tests {
         assert!(!result.vetoed);
         assert!(!result.was_clamped);
     }
+
+    #[test]
+    fn test_aggregation_modes_compared() {
+        let scores = MotorScoreSet::new(0.9, 0.9, 0.9, 0.1);
+
+        let product = CraftPerformance::compute(&scores);
+        let geometric = CraftPerformance::compute_with(&scores, Aggregation::GeometricMean);
+        let harmonic = CraftPerformance::compute_with(&scores, Aggregation::HarmonicMean);
+        let min = CraftPerformance::compute_with(&scores, Aggregation::Min);
+
+        assert_eq!(product.aggregation, Aggregation::Product);
+        assert!((product.cp - 0.0729).abs() < 1e-12);
+        assert!((min.cp - 0.1).abs() < 1e-12);
+        assert!(geometric.cp > product.cp);
+        assert!((geometric.cp - 0.0729f64.powf(0.25)).abs() < 1e-12);
+        assert!(harmonic.cp > product.cp && harmonic.cp < geometric.cp);
+        for r in [&product, &geometric, &harmonic, &min] {
+            assert!(r.valid && !r.vetoed);
+            assert!((0.0..=1.0).contains(&r.cp));
+        }
+    }
+
+    #[test]
+    fn test_aggregation_zero_vetoes_all_modes() {
+        let scores = MotorScoreSet::new(0.9, 0.0, 0.9, 0.9);
+        for mode in [
+            Aggregation::Product,
+            Aggregation::GeometricMean,
+            Aggregation::HarmonicMean,
+            Aggregation::Min,
+        ] {
+            assert_eq!(mode.aggregate(scores.as_array()), 0.0);
+            let result = CraftPerformance::compute_with(&scores, mode);
+            assert!(result.vetoed);
+            assert_eq!(result.cp, 0.0);
+            assert_eq!(result.veto_sources, vec!["Nash".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_compute_matches_from_scores() {
+        let scores = MotorScoreSet::new(0.8, 0.7, 0.9, 0.6);
+        let a = CraftPerformance::compute(&scores);
+        let b = CraftPerformanceResult::from_scores(scores);
+        assert_eq!(a.cp, b.cp);
+        assert_eq!(a.vetoed, b.vetoed);
+    }
+
+    #[test]
+    fn test_verify_consistency_roundtrip() {
+        let result = CraftPerformance::compute(&MotorScoreSet::new(0.8, 0.7, 0.9, 0.6));
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: CraftPerformanceResult = serde_json::from_str(&json).unwrap();
+        assert!(restored.verify_consistency(1e-12));
+
+        let vetoed = CraftPerformanceResult::from_scores(MotorScoreSet::new(0.9, 0.0, 0.9, 0.9));
+        assert!(vetoed.verify_consistency(1e-12));
+
+        let geometric = CraftPerformance::compute_with(
+            &MotorScoreSet::new(0.8, 0.7, 0.9, 0.6),
+            Aggregation::GeometricMean,
+        );
+        assert!(geometric.verify_consistency(1e-12));
+    }
+
+    #[test]
+    fn test_verify_consistency_detects_tampering() {
+        let mut result = CraftPerformance::compute(&MotorScoreSet::new(0.8, 0.7, 0.9, 0.6));
+        result.cp += 0.01;
+        assert!(!result.verify_consistency(1e-6));
+        // Within tolerance the drift is accepted
+        assert!(result.verify_consistency(0.02));
+
+        let mut result = CraftPerformanceResult::from_scores(MotorScoreSet::new(0.9, 0.0, 0.9, 0.9));
+        result.vetoed = false;
+        assert!(!result.verify_consistency(1.0));
+
+        let mut result = CraftPerformance::compute(&MotorScoreSet::new(0.8, 0.7, 0.9, 0.6));
+        result.cp = f64::NAN;
+        assert!(!result.verify_consistency(1.0));
+    }
 }
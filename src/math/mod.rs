@@ -10,14 +10,18 @@
 //!              arbitrary thresholds or heuristics.
 //! Layer: Community
 //! Dependencies: motors
-//! Affected Components: hierarchy/dna, selection
+//! Affected Components: hierarchy/dna, selection, sensory
 //!
 //! --------------------------
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
+//! 2026-10-16 - Carlos Eduardo Favini - Added stats (histogram / entropy)
+//! 2026-10-16 - Carlos Eduardo Favini - Added sampling (seeded shuffle / k-fold)
 //! --------------------------
 
 pub mod craft;
+pub mod sampling;
+pub mod stats;
 
 pub use craft::*;
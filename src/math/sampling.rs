@@ -0,0 +1,126 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Deterministic Sampling
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 1.2.0
+//! Description: Seeded shuffling and k-fold splits for validation
+//!              studies over observation sets. Draws come from
+//!              `DeterministicRng`, so the same seed gives the same
+//!              permutation and folds on every platform and run.
+//! Layer: Community
+//! Dependencies: replay (DeterministicRng)
+//! Affected Components: validation harnesses
+//!
+//! --------------------------
+//! ALGORITHM
+//! --------------------------
+//! Fisher-Yates shuffle; each index is drawn uniformly by rejection
+//! (no modulo bias). k-fold splits shuffle 0..n and cut the result into
+//! k contiguous folds whose sizes differ by at most one.
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation
+//! --------------------------
+
+use crate::replay::DeterministicRng;
+
+/// Uniform index in `0..bound` (`bound > 0`).
+fn uniform_below(rng: &mut DeterministicRng, bound: u64) -> u64 {
+    // Largest multiple of `bound` representable, minus one
+    let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+    loop {
+        let x = rng.next_u64();
+        if x <= zone {
+            return x % bound;
+        }
+    }
+}
+
+/// Shuffles `items` in place, reproducibly for a given `seed`.
+pub fn deterministic_shuffle<T>(items: &mut [T], seed: u64) {
+    let mut rng = DeterministicRng::from_seed(&seed.to_le_bytes());
+    for i in (1..items.len()).rev() {
+        let j = uniform_below(&mut rng, i as u64 + 1) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// Splits the indices `0..n` into `k` folds, reproducibly for `seed`.
+///
+/// Every index appears in exactly one fold. Fold sizes differ by at
+/// most one (the first `n % k` folds get the extra index); indices
+/// within a fold are sorted. With `k > n` some folds are empty, and
+/// `k == 0` gives no folds.
+pub fn k_fold_indices(n: usize, k: usize, seed: u64) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut indices: Vec<usize> = (0..n).collect();
+    deterministic_shuffle(&mut indices, seed);
+
+    let mut folds = Vec::with_capacity(k);
+    let mut rest = indices.as_slice();
+    for f in 0..k {
+        let size = n / k + usize::from(f < n % k);
+        let (head, tail) = rest.split_at(size);
+        let mut fold = head.to_vec();
+        fold.sort_unstable();
+        folds.push(fold);
+        rest = tail;
+    }
+    folds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle_is_reproducible_permutation() {
+        let original: Vec<u32> = (0..50).collect();
+
+        let mut a = original.clone();
+        let mut b = original.clone();
+        let mut c = original.clone();
+        deterministic_shuffle(&mut a, 42);
+        deterministic_shuffle(&mut b, 42);
+        deterministic_shuffle(&mut c, 43);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, original);
+
+        let mut sorted = a.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, original);
+
+        let mut empty: [u8; 0] = [];
+        deterministic_shuffle(&mut empty, 42);
+    }
+
+    #[test]
+    fn test_k_folds_partition_indices() {
+        let folds = k_fold_indices(23, 5, 7);
+        assert_eq!(folds, k_fold_indices(23, 5, 7));
+        assert_ne!(folds, k_fold_indices(23, 5, 8));
+
+        let sizes: Vec<usize> = folds.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![5, 5, 5, 4, 4]);
+
+        let mut seen = [0u32; 23];
+        for &i in folds.iter().flatten() {
+            seen[i] += 1;
+        }
+        assert!(seen.iter().all(|&count| count == 1));
+
+        assert!(k_fold_indices(10, 0, 7).is_empty());
+        let sparse = k_fold_indices(2, 4, 7);
+        assert_eq!(sparse.len(), 4);
+        assert_eq!(sparse.iter().map(Vec::len).sum::<usize>(), 2);
+    }
+}
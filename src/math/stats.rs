@@ -0,0 +1,149 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Histogram and Entropy Statistics
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 1.2.0
+//! Description: Equal-width histograms and Shannon entropy over bin
+//!              counts. Shared by the carrier (Level 0) and structure
+//!              (Level 2) stages so both discretize the same way.
+//! Layer: Community
+//! Dependencies: None (pure math)
+//! Affected Components: sensory/carrier, sensory/structure
+//!
+//! --------------------------
+//! MATHEMATICAL BASIS
+//! --------------------------
+//! Shannon Entropy: H = -Σ p(x) log_b p(x), with p(x) = count(x) / N
+//! Normalized: H_norm = H / log_b(K) where K = number of bins
+//!
+//! Empty bins contribute nothing (0 · log 0 = 0). The normalized value
+//! does not depend on the base.
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation (factored from carrier/structure)
+//! --------------------------
+
+use serde::{Deserialize, Serialize};
+
+/// Logarithm base for entropy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EntropyBase {
+    /// log₂ (bits / shannons)
+    #[default]
+    Bits,
+    /// ln (nats)
+    Nats,
+    /// log₁₀ (hartleys / bans)
+    Hartley,
+}
+
+impl EntropyBase {
+    /// Logarithm of `x` in this base.
+    pub fn log(&self, x: f64) -> f64 {
+        match self {
+            Self::Bits => x.log2(),
+            Self::Nats => x.ln(),
+            Self::Hartley => x.log10(),
+        }
+    }
+}
+
+/// Equal-width histogram of `values` over `[min, max]`.
+///
+/// Returns `bins` counts (at least one bin). The maximum falls in the
+/// last bin. If all values are equal they all land in the first bin;
+/// an empty input gives all-zero counts.
+pub fn histogram(values: &[f64], bins: usize) -> Vec<usize> {
+    let bins = bins.max(1);
+    let mut counts = vec![0usize; bins];
+    if values.is_empty() {
+        return counts;
+    }
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    if (max - min).abs() < f64::EPSILON {
+        counts[0] = values.len();
+        return counts;
+    }
+
+    let bin_width = (max - min) / bins as f64;
+    for &v in values {
+        let bin = ((v - min) / bin_width).floor() as usize;
+        counts[bin.min(bins - 1)] += 1;
+    }
+    counts
+}
+
+/// Shannon entropy of a distribution given as counts, in `base`.
+///
+/// Returns 0.0 for an empty or all-zero distribution.
+pub fn shannon_entropy(counts: &[usize], base: EntropyBase) -> f64 {
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return 0.0;
+    }
+
+    let n = total as f64;
+    let mut entropy = 0.0;
+    for &count in counts {
+        if count > 0 {
+            let p = count as f64 / n;
+            entropy -= p * base.log(p);
+        }
+    }
+    entropy
+}
+
+/// Shannon entropy divided by its maximum, `log(counts.len())`.
+///
+/// In [0, 1]: 0 for a single symbol, 1 for a uniform distribution over
+/// all bins. Returns 0.0 with fewer than two bins.
+pub fn normalized_entropy(counts: &[usize]) -> f64 {
+    if counts.len() < 2 {
+        return 0.0;
+    }
+
+    let base = EntropyBase::Bits;
+    let max_entropy = base.log(counts.len() as f64);
+    (shannon_entropy(counts, base) / max_entropy).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_bins() {
+        let counts = histogram(&[0.0, 0.1, 0.5, 0.9, 1.0], 4);
+        assert_eq!(counts, vec![2, 0, 1, 2]);
+        assert_eq!(histogram(&[3.0, 3.0, 3.0], 4), vec![3, 0, 0, 0]);
+        assert_eq!(histogram(&[], 3), vec![0, 0, 0]);
+        assert_eq!(histogram(&[1.0, 2.0], 0), vec![2]);
+    }
+
+    #[test]
+    fn test_uniform_distribution_max_entropy() {
+        let counts = [5usize; 8];
+        assert!((shannon_entropy(&counts, EntropyBase::Bits) - 3.0).abs() < 1e-12);
+        assert!((shannon_entropy(&counts, EntropyBase::Nats) - 8f64.ln()).abs() < 1e-12);
+        assert!((normalized_entropy(&counts) - 1.0).abs() < 1e-12);
+
+        let values: Vec<f64> = (0..256).map(f64::from).collect();
+        assert!((normalized_entropy(&histogram(&values, 256)) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_single_symbol_zero_entropy() {
+        let counts = [0, 0, 42, 0];
+        assert_eq!(shannon_entropy(&counts, EntropyBase::Bits), 0.0);
+        assert_eq!(normalized_entropy(&counts), 0.0);
+        assert_eq!(normalized_entropy(&histogram(&[7.0; 10], 16)), 0.0);
+        assert_eq!(shannon_entropy(&[], EntropyBase::Bits), 0.0);
+    }
+}
@@ -41,10 +41,18 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.5.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Replay trace via MaturationState::to_anomalies
+//! 2026-10-16 - Carlos Eduardo Favini - Per-metric ConvergencePolicy
+//! 2026-10-16 - Carlos Eduardo Favini - Proto-agency margin in RefinementMetrics
+//! 2026-10-16 - Carlos Eduardo Favini - MaturationConfig is serializable (CortexConfig)
 //! --------------------------
 
 use std::time::Instant;
 
+use serde::{Deserialize, Serialize};
+
+use crate::replay::Anomaly;
+
 // =============================================================================
 // MATURATION CONFIG
 // =============================================================================
@@ -72,7 +80,7 @@ use std::time::Instant;
 /// - NOT a learning rate or training parameter
 /// - NOT a memory configuration
 /// - NOT a persistent setting that affects future calls
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MaturationConfig {
     /// Maximum number of refinement iterations.
     ///
@@ -105,6 +113,11 @@ pub struct MaturationConfig {
     ///
     /// Default: 2 iterations
     pub min_iterations: usize,
+
+    /// How convergence is decided between consecutive passes.
+    ///
+    /// Default: `Combined` (weighted delta vs `convergence_threshold`)
+    pub convergence_policy: ConvergencePolicy,
 }
 
 impl Default for MaturationConfig {
@@ -114,6 +127,7 @@ impl Default for MaturationConfig {
             convergence_threshold: 0.01,
             iteration_timeout_ns: 0,
             min_iterations: 2,
+            convergence_policy: ConvergencePolicy::Combined,
         }
     }
 }
@@ -131,6 +145,7 @@ impl MaturationConfig {
             convergence_threshold: 0.0,
             iteration_timeout_ns: 0,
             min_iterations: 1,
+            convergence_policy: ConvergencePolicy::Combined,
         }
     }
 
@@ -141,6 +156,7 @@ impl MaturationConfig {
             convergence_threshold: 0.001,
             iteration_timeout_ns: 0,
             min_iterations: 3,
+            convergence_policy: ConvergencePolicy::Combined,
         }
     }
 
@@ -167,6 +183,102 @@ impl MaturationConfig {
         self.min_iterations = n.max(1);
         self
     }
+
+    /// Builder: sets the convergence policy.
+    pub fn with_convergence_policy(mut self, policy: ConvergencePolicy) -> Self {
+        self.convergence_policy = policy;
+        self
+    }
+
+    /// Whether two consecutive passes have converged under this config.
+    ///
+    /// Does not consider `min_iterations`; the caller checks that.
+    pub fn has_converged(&self, previous: &RefinementMetrics, current: &RefinementMetrics) -> bool {
+        match &self.convergence_policy {
+            ConvergencePolicy::Combined => {
+                previous.delta_from(current) <= self.convergence_threshold
+            }
+            ConvergencePolicy::PerMetric(thresholds) => thresholds.satisfied_by(previous, current),
+        }
+    }
+}
+
+/// How maturation decides that consecutive passes have converged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ConvergencePolicy {
+    /// Weighted delta (`RefinementMetrics::delta_from`) at most
+    /// `convergence_threshold`.
+    #[default]
+    Combined,
+
+    /// Every selected metric must individually stay within its own
+    /// threshold; `convergence_threshold` is ignored.
+    PerMetric(MetricThresholds),
+}
+
+/// Per-metric convergence thresholds.
+///
+/// `None` means the metric is not considered. A policy with nothing
+/// selected converges immediately (after `min_iterations`).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct MetricThresholds {
+    /// Maximum absolute entropy change.
+    pub entropy: Option<f64>,
+
+    /// Maximum absolute structure score change.
+    pub structure: Option<f64>,
+
+    /// Maximum absolute pattern strength change.
+    pub pattern: Option<f64>,
+
+    /// Require the proto-agency indicator to be unchanged.
+    pub proto_agency_stable: bool,
+}
+
+impl MetricThresholds {
+    /// Only the structure score must settle.
+    pub fn structure(threshold: f64) -> Self {
+        Self {
+            structure: Some(threshold),
+            ..Self::default()
+        }
+    }
+
+    /// Builder: entropy threshold.
+    pub fn with_entropy(mut self, threshold: f64) -> Self {
+        self.entropy = Some(threshold);
+        self
+    }
+
+    /// Builder: structure threshold.
+    pub fn with_structure(mut self, threshold: f64) -> Self {
+        self.structure = Some(threshold);
+        self
+    }
+
+    /// Builder: pattern threshold.
+    pub fn with_pattern(mut self, threshold: f64) -> Self {
+        self.pattern = Some(threshold);
+        self
+    }
+
+    /// Builder: require proto-agency to be stable.
+    pub fn with_proto_agency_stable(mut self) -> Self {
+        self.proto_agency_stable = true;
+        self
+    }
+
+    /// Whether every selected metric is within its threshold.
+    pub fn satisfied_by(&self, previous: &RefinementMetrics, current: &RefinementMetrics) -> bool {
+        let within = |threshold: Option<f64>, a: f64, b: f64| {
+            threshold.is_none_or(|t| (a - b).abs() <= t)
+        };
+
+        within(self.entropy, previous.entropy, current.entropy)
+            && within(self.structure, previous.structure, current.structure)
+            && within(self.pattern, previous.pattern, current.pattern)
+            && (!self.proto_agency_stable || previous.proto_agency == current.proto_agency)
+    }
 }
 
 // =============================================================================
@@ -279,6 +391,20 @@ impl MaturationState {
         }
         (threshold / self.final_delta.max(f64::EPSILON)).min(1.0)
     }
+
+    /// Encodes the maturation outcome as replay anomalies.
+    ///
+    /// Attach the result to a `ReplayEvent` so a recorded session captures
+    /// how maturation ended without persisting this state separately.
+    /// Timing is excluded: it is not reproducible under replay.
+    pub fn to_anomalies(&self) -> Vec<Anomaly> {
+        vec![Anomaly::MaturationTrace {
+            stop_reason: self.stop_reason.to_string(),
+            iterations: self.iterations_performed,
+            final_delta: self.final_delta,
+            converged: self.converged,
+        }]
+    }
 }
 
 impl Default for MaturationState {
@@ -355,6 +481,13 @@ pub struct RefinementMetrics {
 
     /// Proto-agency indicator after refinement.
     pub proto_agency: bool,
+
+    /// Proto-agency margin after refinement, in [-1, 1].
+    ///
+    /// Distance of the deciding condition from its threshold
+    /// (`ConditionMargins::overall`): non-negative when proto-agency
+    /// triggered, negative when not. None when unknown.
+    pub proto_agency_margin: Option<f64>,
 }
 
 impl RefinementMetrics {
@@ -365,17 +498,34 @@ impl RefinementMetrics {
             structure,
             pattern,
             proto_agency,
+            proto_agency_margin: None,
         }
     }
 
+    /// Builder: sets the proto-agency margin (clamped to [-1, 1]).
+    pub fn with_proto_agency_margin(mut self, margin: f64) -> Self {
+        self.proto_agency_margin = Some(margin.clamp(-1.0, 1.0));
+        self
+    }
+
     /// Calculates delta (change) from another metrics instance.
     ///
     /// Returns a value in [0.0, 1.0] representing how much changed.
+    /// The agency term is 1.0 on a flip and otherwise half the margin
+    /// change, so movement towards the boundary registers before the
+    /// boolean flips. Without a margin on both sides it is 0.0.
     pub fn delta_from(&self, other: &RefinementMetrics) -> f64 {
         let entropy_delta = (self.entropy - other.entropy).abs();
         let structure_delta = (self.structure - other.structure).abs();
         let pattern_delta = (self.pattern - other.pattern).abs();
-        let agency_delta = if self.proto_agency != other.proto_agency { 1.0 } else { 0.0 };
+        let agency_delta = if self.proto_agency != other.proto_agency {
+            1.0
+        } else {
+            match (self.proto_agency_margin, other.proto_agency_margin) {
+                (Some(a), Some(b)) => ((a - b).abs() / 2.0).min(1.0),
+                _ => 0.0,
+            }
+        };
 
         // Weighted average of deltas
         // Agency change is binary, so it has high weight
@@ -390,6 +540,7 @@ impl Default for RefinementMetrics {
             structure: 0.0,
             pattern: 0.0,
             proto_agency: false,
+            proto_agency_margin: None,
         }
     }
 }
@@ -528,6 +679,57 @@ mod tests {
         assert_eq!(state.stop_reason, StopReason::Converged);
     }
 
+    #[test]
+    fn test_maturation_trace_converged() {
+        let mut state = MaturationState::new();
+        state.record_iteration(0.5, 1000);
+        state.record_iteration(0.2, 900);
+        state.record_iteration(0.005, 800);
+        state.mark_converged();
+
+        assert_eq!(
+            state.to_anomalies(),
+            vec![Anomaly::MaturationTrace {
+                stop_reason: "Converged".to_string(),
+                iterations: 3,
+                final_delta: 0.005,
+                converged: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_maturation_trace_timeout() {
+        use crate::replay::{MotorType, ReplayContext, ReplayEvent};
+
+        let mut state = MaturationState::new();
+        state.record_iteration(0.4, 5_000_000);
+        state.mark_timeout();
+
+        let anomalies = state.to_anomalies();
+        assert!(matches!(
+            &anomalies[..],
+            [Anomaly::MaturationTrace { stop_reason, iterations: 1, converged: false, .. }]
+                if stop_reason == "Timeout"
+        ));
+
+        // Recorded sessions capture the trace
+        let mut ctx = ReplayContext::from_seed(b"maturation");
+        let mut event = ReplayEvent::new(
+            ctx.next_sequence(),
+            MotorType::CraftPerformance,
+            "in".to_string(),
+            "out".to_string(),
+            0.0,
+            true,
+        );
+        for anomaly in anomalies {
+            event.add_anomaly(anomaly);
+        }
+        ctx.record_event(event);
+        assert_eq!(ctx.anomaly_summary().get("MaturationTrace"), Some(&1));
+    }
+
     #[test]
     fn test_refinement_metrics_delta() {
         let m1 = RefinementMetrics::new(0.5, 0.3, 0.7, false);
@@ -548,6 +750,29 @@ mod tests {
         assert!(delta > 0.0);
     }
 
+    #[test]
+    fn test_refinement_metrics_delta_with_margin_change() {
+        let far = RefinementMetrics::new(0.5, 0.3, 0.7, false).with_proto_agency_margin(-0.4);
+        let near = RefinementMetrics::new(0.5, 0.3, 0.7, false).with_proto_agency_margin(-0.02);
+
+        // Same boolean, different margins: nonzero delta
+        let delta = far.delta_from(&near);
+        assert!((delta - 0.19 * 0.5 / 3.5).abs() < 1e-12);
+
+        // A flip still counts fully
+        let flipped = RefinementMetrics::new(0.5, 0.3, 0.7, true).with_proto_agency_margin(0.01);
+        assert!(near.delta_from(&flipped) > delta);
+        assert!((near.delta_from(&flipped) - 0.5 / 3.5).abs() < 1e-12);
+
+        // An unknown margin is not read as "on the boundary"
+        let unknown = RefinementMetrics::new(0.5, 0.3, 0.7, false);
+        assert_eq!(unknown.proto_agency_margin, None);
+        assert_eq!(unknown.delta_from(&near), 0.0);
+        let boundary = RefinementMetrics::new(0.5, 0.3, 0.7, false).with_proto_agency_margin(0.0);
+        assert_eq!(boundary.proto_agency_margin, Some(0.0));
+        assert!(boundary.delta_from(&near) > 0.0);
+    }
+
     #[test]
     fn test_refinement_metrics_delta_identical() {
         let m1 = RefinementMetrics::new(0.5, 0.3, 0.7, false);
@@ -575,6 +800,41 @@ mod tests {
         assert!(!engine.is_converged(0.10, 2));
     }
 
+    #[test]
+    fn test_per_metric_policy_waits_for_structure() {
+        // Structure still moving by 0.02 per pass, everything else settled
+        let passes = [
+            RefinementMetrics::new(0.5, 0.30, 0.7, false),
+            RefinementMetrics::new(0.5, 0.32, 0.7, false),
+            RefinementMetrics::new(0.5, 0.34, 0.7, false),
+        ];
+
+        let combined = MaturationConfig::new();
+        assert_eq!(combined.convergence_policy, ConvergencePolicy::Combined);
+        // Weighted delta 0.02 / 3.5 is below the default 0.01
+        assert!(combined.has_converged(&passes[0], &passes[1]));
+
+        let per_metric = MaturationConfig::new()
+            .with_convergence_policy(ConvergencePolicy::PerMetric(MetricThresholds::structure(0.005)));
+        assert!(passes.windows(2).all(|w| !per_metric.has_converged(&w[0], &w[1])));
+
+        // Converges once structure settles, regardless of other metrics
+        let settled = RefinementMetrics::new(0.9, 0.3405, 0.1, true);
+        assert!(per_metric.has_converged(&passes[2], &settled));
+        assert!(!combined.has_converged(&passes[2], &settled));
+    }
+
+    #[test]
+    fn test_metric_thresholds_agency() {
+        let a = RefinementMetrics::new(0.5, 0.3, 0.7, false);
+        let b = RefinementMetrics::new(0.5, 0.3, 0.7, true);
+        let policy = MetricThresholds::default().with_entropy(0.01).with_proto_agency_stable();
+
+        assert!(!policy.satisfied_by(&a, &b));
+        assert!(policy.satisfied_by(&a, &a));
+        assert!(MetricThresholds::default().satisfied_by(&a, &b));
+    }
+
     #[test]
     fn test_engine_max_iterations() {
         let config = MaturationConfig::new().with_max_iterations(5);
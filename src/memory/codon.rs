@@ -13,6 +13,7 @@
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use super::context::CanonicalContext;
+use crate::motors::MotorType;
 
 /// Origin marker — AO-18: Autorreferência Cognitiva
 ///
@@ -61,11 +62,50 @@ impl EvaluativeSignature {
         Self { m_p, m_n, m_c, m_m, cp, nash_applicable }
     }
     
+    /// Start a validated signature with named motor setters.
+    pub fn builder() -> EvaluativeSignatureBuilder {
+        EvaluativeSignatureBuilder::default()
+    }
+    
+    /// Craft Performance: the product M_P × M_N × M_C × M_M.
+    pub fn cp(&self) -> f64 {
+        self.cp
+    }
+    
     /// Check if any motor vetoed (score = 0).
     pub fn is_vetoed(&self) -> bool {
         self.cp == 0.0 || self.m_p == 0.0 || self.m_n == 0.0 || self.m_c == 0.0 || self.m_m == 0.0
     }
     
+    /// Pareto dominance over the four motor scores.
+    ///
+    /// True if this signature is ≥ `other` on all of M_P, M_N, M_C, M_M
+    /// and strictly > on at least one. Equal signatures do not dominate.
+    ///
+    /// This is an additional observation: learning still decides by
+    /// scalar CP (LEI-AF-11-02). It lets callers spot trade-offs that
+    /// the CP product hides.
+    pub fn dominates(&self, other: &Self) -> bool {
+        let pairs = [
+            (self.m_p, other.m_p),
+            (self.m_n, other.m_n),
+            (self.m_c, other.m_c),
+            (self.m_m, other.m_m),
+        ];
+        pairs.iter().all(|(a, b)| a >= b) && pairs.iter().any(|(a, b)| a > b)
+    }
+    
+    /// Check if neither signature Pareto-dominates the other.
+    ///
+    /// Equal signatures are NOT incomparable; they are equivalent.
+    pub fn is_pareto_incomparable(&self, other: &Self) -> bool {
+        let equal = self.m_p == other.m_p
+            && self.m_n == other.m_n
+            && self.m_c == other.m_c
+            && self.m_m == other.m_m;
+        !equal && !self.dominates(other) && !other.dominates(self)
+    }
+    
     /// Get CP as vector [cp_action] for single-action Codon.
     pub fn cp_vector(&self) -> Vec<f64> {
         vec![self.cp]
@@ -85,6 +125,101 @@ impl Default for EvaluativeSignature {
     }
 }
 
+/// Builder for `EvaluativeSignature` — named setters instead of four
+/// positional floats.
+///
+/// All four motor scores are required and must be finite and in [0, 1].
+/// `nash_applicable` defaults to false.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvaluativeSignatureBuilder {
+    m_p: Option<f64>,
+    m_n: Option<f64>,
+    m_c: Option<f64>,
+    m_m: Option<f64>,
+    nash_applicable: bool,
+}
+
+impl EvaluativeSignatureBuilder {
+    /// Praxeological motor score (M_P).
+    pub fn praxis(mut self, score: f64) -> Self {
+        self.m_p = Some(score);
+        self
+    }
+    
+    /// Nash motor score (M_N).
+    pub fn nash(mut self, score: f64) -> Self {
+        self.m_n = Some(score);
+        self
+    }
+    
+    /// Chaotic motor score (M_C).
+    pub fn chaos(mut self, score: f64) -> Self {
+        self.m_c = Some(score);
+        self
+    }
+    
+    /// Meristic motor score (M_M).
+    pub fn meristic(mut self, score: f64) -> Self {
+        self.m_m = Some(score);
+        self
+    }
+    
+    /// Whether Nash was applicable (≥2 players).
+    pub fn nash_applicable(mut self, applicable: bool) -> Self {
+        self.nash_applicable = applicable;
+        self
+    }
+    
+    /// Validate the scores and build the signature.
+    ///
+    /// Veto is not set here: it follows from a zero score (`is_vetoed`).
+    pub fn build(self) -> Result<EvaluativeSignature, SignatureError> {
+        let scores = [
+            (MotorType::Praxis, self.m_p),
+            (MotorType::Nash, self.m_n),
+            (MotorType::Chaos, self.m_c),
+            (MotorType::Meristic, self.m_m),
+        ];
+        let mut values = [0.0; 4];
+        for (slot, (motor, score)) in values.iter_mut().zip(scores) {
+            let value = score.ok_or(SignatureError::Missing(motor))?;
+            if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+                return Err(SignatureError::OutOfRange { motor, value });
+            }
+            *slot = value;
+        }
+        let [m_p, m_n, m_c, m_m] = values;
+        Ok(EvaluativeSignature::new(m_p, m_n, m_c, m_m, self.nash_applicable))
+    }
+}
+
+/// Rejected `EvaluativeSignatureBuilder` input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignatureError {
+    /// Motor score was never set.
+    Missing(MotorType),
+    /// Score outside [0, 1] or non-finite.
+    OutOfRange {
+        /// Offending motor.
+        motor: MotorType,
+        /// Rejected value.
+        value: f64,
+    },
+}
+
+impl std::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignatureError::Missing(motor) => write!(f, "{} score was not set", motor),
+            SignatureError::OutOfRange { motor, value } => {
+                write!(f, "{} score {} is outside [0, 1]", motor, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
 /// Activation Condition — Part of LEI-AF-12-01
 ///
 /// Specifies when this Codon is applicable.
@@ -249,6 +384,12 @@ impl CanonicalCodon {
             && self.cp() > other.cp()
     }
     
+    /// Check if this Codon Pareto-dominates another (see
+    /// [`EvaluativeSignature::dominates`]). Context is not considered.
+    pub fn pareto_dominates(&self, other: &Self) -> bool {
+        self.assinatura.dominates(&other.assinatura)
+    }
+    
     /// Generate unique fingerprint for this Codon.
     pub fn fingerprint(&self) -> [u8; 32] {
         let mut hasher = Sha256::new();
@@ -326,6 +467,39 @@ mod tests {
         assert!(!codon_high.dominates(&codon_other)); // Different context
     }
     
+    #[test]
+    fn test_signature_strict_dominance() {
+        let strong = EvaluativeSignature::new(0.9, 0.8, 0.7, 0.6, false);
+        let weak = EvaluativeSignature::new(0.9, 0.5, 0.7, 0.6, false);
+        
+        assert!(strong.dominates(&weak));
+        assert!(!weak.dominates(&strong));
+        assert!(!strong.is_pareto_incomparable(&weak));
+    }
+    
+    #[test]
+    fn test_signature_equality_no_dominance() {
+        let a = EvaluativeSignature::new(0.8, 0.8, 0.8, 0.8, false);
+        let b = EvaluativeSignature::new(0.8, 0.8, 0.8, 0.8, true);
+        
+        assert!(!a.dominates(&b));
+        assert!(!b.dominates(&a));
+        assert!(!a.is_pareto_incomparable(&b));
+    }
+    
+    #[test]
+    fn test_signature_incomparable_pair() {
+        // Strong on Praxis/Nash, weak on Chaos vs. the opposite
+        let a = EvaluativeSignature::new(0.95, 0.95, 0.3, 0.9, false);
+        let b = EvaluativeSignature::new(0.6, 0.6, 0.9, 0.9, false);
+        
+        assert!(!a.dominates(&b));
+        assert!(!b.dominates(&a));
+        assert!(a.is_pareto_incomparable(&b));
+        // Scalar CP still ranks one above the other
+        assert!(a.cp != b.cp);
+    }
+    
     #[test]
     fn test_origin_marker() {
         let mut prov = ReplayableProvenance::default();
@@ -349,4 +523,38 @@ mod tests {
         // Same parameters → same fingerprint
         assert_eq!(codon1.fingerprint(), codon2.fingerprint());
     }
+
+    #[test]
+    fn test_signature_builder() {
+        let sig = EvaluativeSignature::builder()
+            .praxis(0.9)
+            .nash(0.8)
+            .chaos(0.7)
+            .meristic(0.6)
+            .nash_applicable(true)
+            .build()
+            .unwrap();
+        
+        assert_eq!((sig.m_p, sig.m_n, sig.m_c, sig.m_m), (0.9, 0.8, 0.7, 0.6));
+        assert!(sig.nash_applicable);
+        assert_eq!(sig.cp(), 0.9 * 0.8 * 0.7 * 0.6);
+        assert_eq!(sig, EvaluativeSignature::new(0.9, 0.8, 0.7, 0.6, true));
+    }
+    
+    #[test]
+    fn test_signature_builder_rejects_invalid() {
+        let base = EvaluativeSignature::builder().praxis(0.9).nash(0.9).meristic(0.9);
+        
+        assert_eq!(
+            base.chaos(1.5).build(),
+            Err(SignatureError::OutOfRange { motor: MotorType::Chaos, value: 1.5 })
+        );
+        assert!(matches!(
+            base.chaos(f64::NAN).build(),
+            Err(SignatureError::OutOfRange { motor: MotorType::Chaos, .. })
+        ));
+        assert_eq!(base.build(), Err(SignatureError::Missing(MotorType::Chaos)));
+        // Zero is in range: it is a veto, not an error
+        assert!(base.chaos(0.0).build().unwrap().is_vetoed());
+    }
 }
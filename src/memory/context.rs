@@ -11,7 +11,11 @@
 //! # Version: 0.6.0 (MVP-6)
 
 use serde::{Deserialize, Serialize};
-use sha2::{Sha256, Digest};
+
+use crate::hashing::{DefaultHasher, Hasher};
+
+/// Total bits compared by `CanonicalContext::hamming_distance`.
+const CONTEXT_BITS: f64 = 512.0;
 
 /// Canonical Context — LEI-AF-12-02
 ///
@@ -39,17 +43,17 @@ impl CanonicalContext {
     /// * `problem_signature` - Bytes representing the problem class (e.g., data structure hash)
     /// * `initial_state` - Bytes representing initial conditions (e.g., input + MCI snapshot)
     pub fn new(problem_signature: &[u8], initial_state: &[u8]) -> Self {
-        let mut problem_hasher = Sha256::new();
-        problem_hasher.update(problem_signature);
-        let problem_class: [u8; 32] = problem_hasher.finalize().into();
-        
-        let mut conditions_hasher = Sha256::new();
-        conditions_hasher.update(initial_state);
-        let initial_conditions: [u8; 32] = conditions_hasher.finalize().into();
-        
+        Self::new_with::<DefaultHasher>(problem_signature, initial_state)
+    }
+
+    /// Create a context hashing with an explicit hasher.
+    ///
+    /// Contexts built with different hashers never compare equal, so one
+    /// MCI must use a single hasher throughout.
+    pub fn new_with<H: Hasher>(problem_signature: &[u8], initial_state: &[u8]) -> Self {
         Self {
-            problem_class,
-            initial_conditions,
+            problem_class: H::digest(problem_signature),
+            initial_conditions: H::digest(initial_state),
         }
     }
     
@@ -67,12 +71,37 @@ impl CanonicalContext {
         self.problem_class == other.problem_class
     }
     
+    /// Bitwise Hamming distance over problem class + initial conditions.
+    ///
+    /// Ranges over [0, 512]. Deterministic; 0 iff contexts are equal.
+    pub fn hamming_distance(&self, other: &Self) -> u32 {
+        self.problem_class.iter()
+            .zip(other.problem_class.iter())
+            .chain(self.initial_conditions.iter().zip(other.initial_conditions.iter()))
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+    
+    /// Similarity in [0,1] derived from Hamming distance (1 = identical).
+    pub fn similarity(&self, other: &Self) -> f64 {
+        1.0 - self.hamming_distance(other) as f64 / CONTEXT_BITS
+    }
+    
     /// Generate a combined fingerprint for indexing.
+    ///
+    /// SHA-256 over problem class then initial conditions. Equal contexts
+    /// always share a fingerprint; this is the key MCI orders and
+    /// indexes contexts by.
     pub fn fingerprint(&self) -> [u8; 32] {
-        let mut hasher = Sha256::new();
+        self.fingerprint_with::<DefaultHasher>()
+    }
+
+    /// Combined fingerprint with an explicit hasher.
+    pub fn fingerprint_with<H: Hasher>(&self) -> [u8; 32] {
+        let mut hasher = H::default();
         hasher.update(&self.problem_class);
         hasher.update(&self.initial_conditions);
-        hasher.finalize().into()
+        hasher.finalize()
     }
 }
 
@@ -85,6 +114,13 @@ impl Default for CanonicalContext {
     }
 }
 
+/// Short form for log lines: `ctx:` + first 8 hex characters of the fingerprint.
+impl std::fmt::Display for CanonicalContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ctx:{}", hex::encode(&self.fingerprint()[..4]))
+    }
+}
+
 impl Ord for CanonicalContext {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.fingerprint().cmp(&other.fingerprint())
@@ -127,6 +163,44 @@ mod tests {
         assert_eq!(ctx1.fingerprint(), ctx2.fingerprint());
     }
     
+    #[test]
+    fn test_fingerprint_distinguishes_contexts() {
+        let base = CanonicalContext::new(b"problem", b"state");
+        let other_state = CanonicalContext::new(b"problem", b"other");
+        let other_problem = CanonicalContext::new(b"other", b"state");
+        
+        assert_ne!(base.fingerprint(), other_state.fingerprint());
+        assert_ne!(base.fingerprint(), other_problem.fingerprint());
+        // Swapping the two halves is a different context
+        let swapped = CanonicalContext::from_hashes(base.initial_conditions, base.problem_class);
+        assert_ne!(base.fingerprint(), swapped.fingerprint());
+    }
+    
+    #[test]
+    fn test_display_short_fingerprint() {
+        let ctx = CanonicalContext::new(b"problem", b"state");
+        let same = CanonicalContext::new(b"problem", b"state");
+        let text = ctx.to_string();
+        
+        assert_eq!(text, same.to_string());
+        assert_eq!(text, format!("ctx:{}", &hex::encode(ctx.fingerprint())[..8]));
+        assert_ne!(text, CanonicalContext::new(b"problem", b"other").to_string());
+    }
+    
+    #[test]
+    fn test_hamming_distance() {
+        let a = CanonicalContext::from_hashes([0; 32], [0; 32]);
+        let mut pc = [0u8; 32];
+        pc[0] = 0b0000_0111;
+        let b = CanonicalContext::from_hashes(pc, [0; 32]);
+        
+        assert_eq!(a.hamming_distance(&a), 0);
+        assert_eq!(a.hamming_distance(&b), 3);
+        assert_eq!(b.hamming_distance(&a), 3);
+        assert!((a.similarity(&a) - 1.0).abs() < 1e-12);
+        assert!((a.similarity(&b) - (1.0 - 3.0 / 512.0)).abs() < 1e-12);
+    }
+    
     #[test]
     fn test_context_ordering() {
         let ctx1 = CanonicalContext::new(b"aaa", b"state");
@@ -136,4 +210,26 @@ mod tests {
         assert!(ctx1 != ctx2);
         assert!(ctx1 < ctx2 || ctx1 > ctx2);
     }
+
+    #[test]
+    fn test_default_hasher_reproduces_sha256() {
+        use sha2::{Digest, Sha256};
+
+        let ctx = CanonicalContext::new(b"problem", b"state");
+        let expected_class: [u8; 32] = Sha256::digest(b"problem").into();
+        assert_eq!(ctx.problem_class, expected_class);
+        assert_eq!(ctx, CanonicalContext::new_with::<crate::hashing::Sha256Hasher>(b"problem", b"state"));
+        assert_eq!(ctx.fingerprint(), ctx.fingerprint_with::<crate::hashing::Sha256Hasher>());
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_blake3_context() {
+        use crate::hashing::Blake3Hasher;
+
+        let ctx = CanonicalContext::new_with::<Blake3Hasher>(b"problem", b"state");
+        assert_eq!(ctx, CanonicalContext::new_with::<Blake3Hasher>(b"problem", b"state"));
+        assert_ne!(ctx, CanonicalContext::new(b"problem", b"state"));
+        assert_ne!(ctx.fingerprint_with::<Blake3Hasher>(), ctx.fingerprint());
+    }
 }
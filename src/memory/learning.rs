@@ -15,7 +15,7 @@
 
 use super::context::CanonicalContext;
 use super::codon::{CanonicalCodon, EvaluativeSignature, ActivationCondition, ReplayableProvenance, Origin};
-use super::mci::{MCI, MciError};
+use super::mci::{MCI, MciError, Incorporation};
 
 /// Learning trigger types — LEI-AF-11-01
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,37 +43,69 @@ pub struct LearningResult {
     pub new_cp: Option<f64>,
     /// Reason for rejection (if not learned)
     pub rejection_reason: Option<RejectionReason>,
+    /// Context of the Codon evicted to make room (bounded MCI only)
+    pub evicted: Option<CanonicalContext>,
 }
 
 /// Reasons for rejecting learning
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RejectionReason {
-    /// Codon was vetoed (CP = 0)
-    Vetoed,
-    /// CP did not improve strictly
-    NoImprovement,
+    /// Codon was vetoed (CP = 0) — LEI-AF-11-04
+    Vetoed {
+        /// CP of the rejected candidate
+        candidate_cp: f64,
+    },
+    /// The trigger's precondition does not hold for this candidate
+    TriggerNotSatisfied {
+        /// Trigger that was claimed
+        trigger: EpistemicTrigger,
+        /// Fingerprint of the candidate's context
+        context: [u8; 32],
+    },
+    /// Same form and signature already stored in this context
+    Duplicate {
+        /// Fingerprint of the stored Codon
+        existing: [u8; 32],
+    },
+    /// An existing Codon has equal or higher CP — LEI-AF-11-02
+    Dominated {
+        /// Fingerprint of the dominating Codon
+        existing: [u8; 32],
+        /// CP of the dominating Codon
+        existing_cp: f64,
+        /// CP of the rejected candidate
+        candidate_cp: f64,
+    },
     /// MCI capacity exceeded
     CapacityExceeded,
+    /// MCI refused the Codon for another reason
+    InvalidCodon(String),
     /// Replay verification failed
     ReplayFailed,
 }
 
+/// Default number of consecutive identical CPs that trigger stagnation.
+pub const DEFAULT_STAGNATION_THRESHOLD: usize = 3;
+
 /// Stagnation detector — LEI-AF-11-01
 ///
 /// Detects when CP is stagnating in the same context,
 /// which triggers epistemic exploration.
+///
+/// Counters are kept per context: observing a different CP for a
+/// context resets that context's counter to zero.
 #[derive(Debug, Clone, Default)]
 pub struct StagnationDetector {
     /// Last CP per context
     last_cp: std::collections::HashMap<[u8; 32], f64>,
     /// Stagnation count per context
-    stagnation_count: std::collections::HashMap<[u8; 32], u32>,
+    stagnation_count: std::collections::HashMap<[u8; 32], usize>,
     /// Threshold for triggering (consecutive stagnations)
-    threshold: u32,
+    threshold: usize,
 }
 
 impl StagnationDetector {
-    pub fn new(threshold: u32) -> Self {
+    pub fn new(threshold: usize) -> Self {
         Self {
             last_cp: std::collections::HashMap::new(),
             stagnation_count: std::collections::HashMap::new(),
@@ -81,6 +113,21 @@ impl StagnationDetector {
         }
     }
     
+    /// Get the trigger threshold.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+    
+    /// Change the trigger threshold (existing counters are kept).
+    pub fn set_threshold(&mut self, threshold: usize) {
+        self.threshold = threshold;
+    }
+    
+    /// Current stagnation count for a context (0 if never observed).
+    pub fn count(&self, context: &CanonicalContext) -> usize {
+        self.stagnation_count.get(&context.fingerprint()).copied().unwrap_or(0)
+    }
+    
     /// Record CP observation and check for stagnation.
     pub fn observe(&mut self, context: &CanonicalContext, current_cp: f64) -> bool {
         let key = context.fingerprint();
@@ -106,8 +153,15 @@ impl StagnationDetector {
         false
     }
     
-    /// Reset detector state.
-    pub fn reset(&mut self) {
+    /// Forget all state for one context.
+    pub fn reset(&mut self, context: &CanonicalContext) {
+        let key = context.fingerprint();
+        self.last_cp.remove(&key);
+        self.stagnation_count.remove(&key);
+    }
+    
+    /// Reset detector state for all contexts.
+    pub fn clear(&mut self) {
         self.last_cp.clear();
         self.stagnation_count.clear();
     }
@@ -128,7 +182,7 @@ pub struct LearningEngine {
 }
 
 impl LearningEngine {
-    pub fn new(stagnation_threshold: u32) -> Self {
+    pub fn new(stagnation_threshold: usize) -> Self {
         Self {
             stagnation: StagnationDetector::new(stagnation_threshold),
             replay_verify: true,
@@ -136,13 +190,23 @@ impl LearningEngine {
     }
     
     /// Create engine without replay verification (for testing).
-    pub fn without_replay_verify(stagnation_threshold: u32) -> Self {
+    pub fn without_replay_verify(stagnation_threshold: usize) -> Self {
         Self {
             stagnation: StagnationDetector::new(stagnation_threshold),
             replay_verify: false,
         }
     }
     
+    /// Get the stagnation detector.
+    pub fn stagnation(&self) -> &StagnationDetector {
+        &self.stagnation
+    }
+    
+    /// Get mutable access to the stagnation detector.
+    pub fn stagnation_mut(&mut self) -> &mut StagnationDetector {
+        &mut self.stagnation
+    }
+    
     /// Check if epistemic exploration should be triggered — LEI-AF-11-01
     pub fn check_trigger(&mut self, context: &CanonicalContext, current_cp: f64) -> Option<EpistemicTrigger> {
         if self.stagnation.observe(context, current_cp) {
@@ -153,40 +217,64 @@ impl LearningEngine {
     
     /// Attempt to learn (incorporate) a Codon — LEI-AF-11-02
     ///
-    /// # Criteria
+    /// # Criteria (checked in order)
     /// 1. Codon must not be vetoed (LEI-AF-11-04)
-    /// 2. CP_candidate > CP_baseline (LEI-AF-11-02)
-    /// 3. Replay verification passes (LEI-AF-11-03) — if enabled
+    /// 2. The trigger must hold: `NoveltyDetected` requires a context
+    ///    with no stored Codons
+    /// 3. Codon must not duplicate a stored one (same form and signature
+    ///    in the same context)
+    /// 4. CP_candidate > CP_baseline (LEI-AF-11-02)
+    /// 5. Replay verification passes (LEI-AF-11-03) — if enabled
+    ///
+    /// A rejected attempt carries the precise `RejectionReason`.
     pub fn try_learn(
         &mut self,
         mci: &mut MCI,
         candidate: CanonicalCodon,
         trigger: EpistemicTrigger,
     ) -> LearningResult {
-        let context = &candidate.condicao_uso.context;
-        let baseline_cp = mci.baseline_cp(context);
+        let context = candidate.condicao_uso.context.clone();
+        let best = mci.query_best(&context);
+        let baseline_cp = best.as_ref().map(|c| c.cp()).unwrap_or(0.0);
         let candidate_cp = candidate.cp();
+        let reject = |reason| LearningResult {
+            learned: false,
+            trigger,
+            baseline_cp,
+            new_cp: None,
+            rejection_reason: Some(reason),
+            evicted: None,
+        };
         
         // LEI-AF-11-04: Vetoed cannot incorporate
         if candidate.is_vetoed() {
-            return LearningResult {
-                learned: false,
+            return reject(RejectionReason::Vetoed { candidate_cp });
+        }
+        
+        let stored: Vec<CanonicalCodon> = mci.query(&context).codons.into_iter()
+            .filter(|c| c.condicao_uso.context == context)
+            .collect();
+        
+        if trigger == EpistemicTrigger::NoveltyDetected && !stored.is_empty() {
+            return reject(RejectionReason::TriggerNotSatisfied {
                 trigger,
-                baseline_cp,
-                new_cp: None,
-                rejection_reason: Some(RejectionReason::Vetoed),
-            };
+                context: context.fingerprint(),
+            });
+        }
+        
+        if let Some(existing) = stored.iter().find(|c| {
+            c.forma_fingerprint == candidate.forma_fingerprint && c.assinatura == candidate.assinatura
+        }) {
+            return reject(RejectionReason::Duplicate { existing: existing.fingerprint() });
         }
         
         // LEI-AF-11-02: Must improve strictly
-        if candidate_cp <= baseline_cp {
-            return LearningResult {
-                learned: false,
-                trigger,
-                baseline_cp,
-                new_cp: None,
-                rejection_reason: Some(RejectionReason::NoImprovement),
-            };
+        if let Some(existing) = best.as_ref().filter(|c| candidate_cp <= c.cp()) {
+            return reject(RejectionReason::Dominated {
+                existing: existing.fingerprint(),
+                existing_cp: existing.cp(),
+                candidate_cp,
+            });
         }
         
         // LEI-AF-11-03: Replay verification (if enabled)
@@ -197,35 +285,26 @@ impl LearningEngine {
         }
         
         // Attempt incorporation
-        match mci.try_incorporate(candidate) {
-            Ok(true) => LearningResult {
+        match mci.incorporate(candidate) {
+            Ok(Incorporation { incorporated: true, evicted }) => LearningResult {
                 learned: true,
                 trigger,
                 baseline_cp,
                 new_cp: Some(candidate_cp),
                 rejection_reason: None,
+                evicted,
             },
-            Ok(false) => LearningResult {
-                learned: false,
-                trigger,
-                baseline_cp,
-                new_cp: None,
-                rejection_reason: Some(RejectionReason::NoImprovement),
-            },
-            Err(MciError::CapacityExceeded) => LearningResult {
-                learned: false,
-                trigger,
-                baseline_cp,
-                new_cp: None,
-                rejection_reason: Some(RejectionReason::CapacityExceeded),
-            },
-            Err(_) => LearningResult {
-                learned: false,
-                trigger,
-                baseline_cp,
-                new_cp: None,
-                rejection_reason: Some(RejectionReason::Vetoed),
-            },
+            Ok(Incorporation { incorporated: false, .. }) => {
+                // MCI applies the same dominance rule within the exact context
+                let existing = mci.query_best(&context);
+                reject(RejectionReason::Dominated {
+                    existing: existing.as_ref().map(|c| c.fingerprint()).unwrap_or([0; 32]),
+                    existing_cp: existing.map(|c| c.cp()).unwrap_or(baseline_cp),
+                    candidate_cp,
+                })
+            }
+            Err(MciError::CapacityExceeded) => reject(RejectionReason::CapacityExceeded),
+            Err(e) => reject(RejectionReason::InvalidCodon(e.to_string())),
         }
     }
     
@@ -258,13 +337,13 @@ impl LearningEngine {
     
     /// Reset learning engine state.
     pub fn reset(&mut self) {
-        self.stagnation.reset();
+        self.stagnation.clear();
     }
 }
 
 impl Default for LearningEngine {
     fn default() -> Self {
-        Self::new(3) // Default: trigger after 3 stagnations
+        Self::new(DEFAULT_STAGNATION_THRESHOLD)
     }
 }
 
@@ -304,6 +383,56 @@ mod tests {
         assert!(!detector.observe(&ctx, 0.6));
     }
     
+    #[test]
+    fn test_stagnation_threshold_two_fires_earlier() {
+        let mut detector = StagnationDetector::new(2);
+        let ctx = CanonicalContext::new(b"problem", b"state");
+        
+        assert_eq!(detector.threshold(), 2);
+        assert!(!detector.observe(&ctx, 0.5));
+        assert!(!detector.observe(&ctx, 0.5));
+        // Fires on the second repeat, one step before threshold 3
+        assert!(detector.observe(&ctx, 0.5));
+    }
+    
+    #[test]
+    fn test_stagnation_cp_change_resets_count() {
+        let mut detector = StagnationDetector::new(2);
+        let ctx = CanonicalContext::new(b"problem", b"state");
+        
+        assert!(!detector.observe(&ctx, 0.5));
+        assert!(!detector.observe(&ctx, 0.5));
+        assert_eq!(detector.count(&ctx), 1);
+        
+        // Different CP → counter back to zero
+        assert!(!detector.observe(&ctx, 0.6));
+        assert_eq!(detector.count(&ctx), 0);
+        
+        // Following identical CP does not fire
+        assert!(!detector.observe(&ctx, 0.6));
+        assert_eq!(detector.count(&ctx), 1);
+    }
+    
+    #[test]
+    fn test_stagnation_reset_per_context() {
+        let mut detector = StagnationDetector::new(2);
+        let ctx_a = CanonicalContext::new(b"problem_a", b"state");
+        let ctx_b = CanonicalContext::new(b"problem_b", b"state");
+        
+        detector.observe(&ctx_a, 0.5);
+        detector.observe(&ctx_a, 0.5);
+        detector.observe(&ctx_b, 0.5);
+        detector.observe(&ctx_b, 0.5);
+        
+        detector.reset(&ctx_a);
+        assert_eq!(detector.count(&ctx_a), 0);
+        assert_eq!(detector.count(&ctx_b), 1);
+        
+        // ctx_a starts over (first observation again), ctx_b fires
+        assert!(!detector.observe(&ctx_a, 0.5));
+        assert!(detector.observe(&ctx_b, 0.5));
+    }
+    
     #[test]
     fn test_learning_engine_trigger() {
         let mut engine = LearningEngine::new(2);
@@ -347,7 +476,7 @@ mod tests {
         let result = engine.try_learn(&mut mci, codon, EpistemicTrigger::ExplicitRequest);
         
         assert!(!result.learned);
-        assert_eq!(result.rejection_reason, Some(RejectionReason::Vetoed));
+        assert_eq!(result.rejection_reason, Some(RejectionReason::Vetoed { candidate_cp: 0.0 }));
     }
     
     #[test]
@@ -360,11 +489,67 @@ mod tests {
         engine.try_learn(&mut mci, codon1, EpistemicTrigger::ExplicitRequest);
         
         // Lower CP Codon - rejected
+        let existing = mci.query_best(&CanonicalContext::new(b"problem", b"state")).unwrap();
         let codon2 = make_codon(0.7, b"problem", Origin::Internal);
+        let candidate_cp = codon2.cp();
         let result = engine.try_learn(&mut mci, codon2, EpistemicTrigger::MeristicProposal);
         
         assert!(!result.learned);
-        assert_eq!(result.rejection_reason, Some(RejectionReason::NoImprovement));
+        assert_eq!(result.rejection_reason, Some(RejectionReason::Dominated {
+            existing: existing.fingerprint(),
+            existing_cp: existing.cp(),
+            candidate_cp,
+        }));
+    }
+    
+    #[test]
+    fn test_try_learn_duplicate_rejected() {
+        let mut engine = LearningEngine::without_replay_verify(3);
+        let mut mci = MCI::unlimited();
+        
+        let codon = make_codon(0.9, b"problem", Origin::External);
+        let fingerprint = codon.fingerprint();
+        assert!(engine.try_learn(&mut mci, codon.clone(), EpistemicTrigger::ExplicitRequest).learned);
+        
+        let result = engine.try_learn(&mut mci, codon, EpistemicTrigger::ExplicitRequest);
+        assert!(!result.learned);
+        assert_eq!(result.rejection_reason, Some(RejectionReason::Duplicate { existing: fingerprint }));
+        assert_eq!(mci.total_codons(), 1);
+    }
+    
+    #[test]
+    fn test_try_learn_novelty_trigger_not_satisfied() {
+        let mut engine = LearningEngine::without_replay_verify(3);
+        let mut mci = MCI::unlimited();
+        
+        let codon1 = make_codon(0.8, b"problem", Origin::External);
+        assert!(engine.try_learn(&mut mci, codon1, EpistemicTrigger::NoveltyDetected).learned);
+        
+        // Context already known: not novel, even though CP improves
+        let codon2 = make_codon(0.95, b"problem", Origin::Internal);
+        let result = engine.try_learn(&mut mci, codon2, EpistemicTrigger::NoveltyDetected);
+        assert!(!result.learned);
+        assert_eq!(result.rejection_reason, Some(RejectionReason::TriggerNotSatisfied {
+            trigger: EpistemicTrigger::NoveltyDetected,
+            context: CanonicalContext::new(b"problem", b"state").fingerprint(),
+        }));
+    }
+    
+    #[test]
+    fn test_try_learn_reports_eviction() {
+        let mut engine = LearningEngine::without_replay_verify(3);
+        let mut mci = MCI::with_capacity(1);
+        
+        let codon1 = make_codon(0.8, b"problem_a", Origin::External);
+        let result1 = engine.try_learn(&mut mci, codon1, EpistemicTrigger::ExplicitRequest);
+        assert!(result1.learned);
+        assert_eq!(result1.evicted, None);
+        
+        let codon2 = make_codon(0.9, b"problem_b", Origin::External);
+        let result2 = engine.try_learn(&mut mci, codon2, EpistemicTrigger::NoveltyDetected);
+        assert!(result2.learned);
+        assert_eq!(result2.evicted, Some(CanonicalContext::new(b"problem_a", b"state")));
+        assert_eq!(mci.total_codons(), 1);
     }
     
     #[test]
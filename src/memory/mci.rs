@@ -16,6 +16,7 @@
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 use std::collections::BTreeMap;
+use std::sync::Mutex;
 
 use super::context::CanonicalContext;
 use super::codon::CanonicalCodon;
@@ -44,6 +45,98 @@ pub struct MciStats {
     pub discards_by_dominance: u64,
     /// Total rejections (vetoed)
     pub rejections_vetoed: u64,
+    /// Total evictions by capacity policy
+    pub evictions: u64,
+}
+
+/// Eviction policy for bounded MCI
+///
+/// Applied only when an incorporation would grow the store past its
+/// capacity. Both policies are deterministic under replay: recency is
+/// measured in logical access ticks, never wall clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EvictionPolicy {
+    /// Evict the Codon with the lowest CP across all contexts
+    #[default]
+    LowestCp,
+    /// Evict the Codon whose context was least recently queried or stored
+    LeastRecentlyQueried,
+}
+
+/// Outcome of an incorporation attempt on the MCI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Incorporation {
+    /// Whether the Codon was incorporated
+    pub incorporated: bool,
+    /// Context of the Codon evicted to make room (bounded MCI only)
+    pub evicted: Option<CanonicalContext>,
+}
+
+/// Logical access ticks per context, used by `LeastRecentlyQueried`.
+///
+/// Queries take `&self`, so ticks live behind a lock. The lock is only
+/// touched when the MCI is bounded and uses the LRU policy.
+#[derive(Debug, Default)]
+struct AccessTicks {
+    inner: Mutex<(u64, BTreeMap<[u8; 32], u64>)>,
+}
+
+impl AccessTicks {
+    fn touch(&self, key: [u8; 32]) {
+        let mut guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        guard.0 += 1;
+        let tick = guard.0;
+        guard.1.insert(key, tick);
+    }
+
+    fn forget(&self, key: &[u8; 32]) {
+        let mut guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        guard.1.remove(key);
+    }
+
+    fn get(&self, key: &[u8; 32]) -> u64 {
+        let guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        guard.1.get(key).copied().unwrap_or(0)
+    }
+
+    fn clear(&self) {
+        let mut guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = (0, BTreeMap::new());
+    }
+
+    fn snapshot(&self) -> (u64, Vec<([u8; 32], u64)>) {
+        let guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        (guard.0, guard.1.iter().map(|(k, v)| (*k, *v)).collect())
+    }
+
+    fn restore(tick: u64, entries: Vec<([u8; 32], u64)>) -> Self {
+        Self {
+            inner: Mutex::new((tick, entries.into_iter().collect())),
+        }
+    }
+}
+
+impl Clone for AccessTicks {
+    fn clone(&self) -> Self {
+        let guard = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        Self {
+            inner: Mutex::new(guard.clone()),
+        }
+    }
+}
+
+/// Whole-store snapshot used by `MCI::export`/`MCI::import`.
+///
+/// Codons are flattened in context order; the index key is recomputed
+/// from each Codon's context on import.
+#[derive(Serialize, Deserialize)]
+struct MciSnapshot {
+    capacity: Option<usize>,
+    eviction_policy: EvictionPolicy,
+    cycle_counter: u64,
+    access_tick: u64,
+    access: Vec<([u8; 32], u64)>,
+    codons: Vec<CanonicalCodon>,
 }
 
 /// Memória Cognitiva Interna — AF-12
@@ -67,6 +160,14 @@ pub struct MCI {
     /// Capacity limit (None = unlimited)
     capacity: Option<usize>,
     
+    /// Eviction policy applied when capacity is reached
+    #[serde(default)]
+    eviction_policy: EvictionPolicy,
+    
+    /// Access ticks per context (LRU policy only)
+    #[serde(skip)]
+    access: AccessTicks,
+    
     /// Statistics
     #[serde(skip)]
     stats: MciStats,
@@ -81,6 +182,8 @@ impl MCI {
         Self {
             codons: BTreeMap::new(),
             capacity,
+            eviction_policy: EvictionPolicy::default(),
+            access: AccessTicks::default(),
             stats: MciStats::default(),
             cycle_counter: 0,
         }
@@ -91,11 +194,27 @@ impl MCI {
         Self::new(None)
     }
     
-    /// Create a limited MCI.
+    /// Create a limited MCI (evicts lowest CP when full).
     pub fn with_capacity(max_codons: usize) -> Self {
         Self::new(Some(max_codons))
     }
     
+    /// Set the eviction policy used when capacity is reached.
+    pub fn with_eviction_policy(mut self, policy: EvictionPolicy) -> Self {
+        self.eviction_policy = policy;
+        self
+    }
+    
+    /// Get the eviction policy.
+    pub fn eviction_policy(&self) -> EvictionPolicy {
+        self.eviction_policy
+    }
+    
+    /// Get the capacity limit (None = unlimited).
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+    
     /// Get current cycle counter.
     pub fn cycle_counter(&self) -> u64 {
         self.cycle_counter
@@ -118,13 +237,23 @@ impl MCI {
     /// - `Ok(false)` if not incorporated (dominated or vetoed)
     /// - `Err` if capacity exceeded and cannot evict
     pub fn try_incorporate(&mut self, codon: CanonicalCodon) -> Result<bool, MciError> {
+        self.incorporate(codon).map(|r| r.incorporated)
+    }
+    
+    /// Attempt to incorporate a Codon, reporting any eviction.
+    ///
+    /// Same criteria as [`MCI::try_incorporate`]. When the MCI is bounded
+    /// and the Codon opens a new context at capacity, one Codon is evicted
+    /// according to the [`EvictionPolicy`] and its context is returned.
+    pub fn incorporate(&mut self, codon: CanonicalCodon) -> Result<Incorporation, MciError> {
         // LEI-AF-11-04: Vetoed Codons cannot be incorporated
         if !codon.can_incorporate() {
             self.stats.rejections_vetoed += 1;
-            return Ok(false);
+            return Ok(Incorporation { incorporated: false, evicted: None });
         }
         
         let context_key = codon.condicao_uso.context.fingerprint();
+        let mut replaced = 0;
         
         // Check for dominance
         if let Some(existing) = self.codons.get(&context_key) {
@@ -135,28 +264,32 @@ impl MCI {
             
             // LEI-AF-11-02: Must improve strictly
             if codon.cp() <= best_existing_cp {
-                return Ok(false);
+                return Ok(Incorporation { incorporated: false, evicted: None });
             }
             
             // Remove dominated Codons (LEI-AF-12-02)
-            let dominated_count = existing.iter()
+            replaced = existing.iter()
                 .filter(|c| codon.dominates(c))
                 .count();
-            self.stats.discards_by_dominance += dominated_count as u64;
+            self.stats.discards_by_dominance += replaced as u64;
         }
         
-        // Check capacity
+        // Check capacity (only if the store would actually grow)
+        let mut evicted = None;
         if let Some(cap) = self.capacity {
-            if self.total_codons() >= cap {
-                // Try to evict lowest CP across all contexts
-                if !self.evict_lowest() {
+            if replaced == 0 && self.total_codons() >= cap {
+                evicted = self.evict();
+                if evicted.is_none() {
                     return Err(MciError::CapacityExceeded);
                 }
             }
+            if self.eviction_policy == EvictionPolicy::LeastRecentlyQueried {
+                self.access.touch(context_key);
+            }
         }
         
         // Insert Codon
-        let codons = self.codons.entry(context_key).or_insert_with(Vec::new);
+        let codons = self.codons.entry(context_key).or_default();
         
         // Remove dominated Codons before inserting
         codons.retain(|c| !codon.dominates(c));
@@ -165,7 +298,7 @@ impl MCI {
         self.stats.incorporations += 1;
         self.update_stats();
         
-        Ok(true)
+        Ok(Incorporation { incorporated: true, evicted })
     }
     
     /// Query MCI for Codons similar to given context — LEI-AF-12-04
@@ -176,6 +309,9 @@ impl MCI {
         
         // Exact match first
         if let Some(codons) = self.codons.get(&context_key) {
+            if self.capacity.is_some() && self.eviction_policy == EvictionPolicy::LeastRecentlyQueried {
+                self.access.touch(context_key);
+            }
             return MciQueryResult {
                 codons: codons.clone(),
                 found: true,
@@ -197,6 +333,33 @@ impl MCI {
         }
     }
     
+    /// Find the k stored contexts most similar to the given one.
+    ///
+    /// Similarity is `CanonicalContext::similarity` (Hamming over the
+    /// context hashes). Results are sorted by similarity descending, ties
+    /// broken by context order, so the ranking is deterministic.
+    ///
+    /// This does NOT return Codons — it only surfaces candidate contexts
+    /// which can then be passed to `query`/`query_best`.
+    pub fn query_nearest(&self, context: &CanonicalContext, k: usize) -> Vec<(CanonicalContext, f64)> {
+        let mut ranked: Vec<(CanonicalContext, f64)> = self.codons.values()
+            .filter_map(|v| v.first())
+            .map(|c| {
+                let stored = c.condicao_uso.context.clone();
+                let similarity = stored.similarity(context);
+                (stored, similarity)
+            })
+            .collect();
+        
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        ranked.truncate(k);
+        ranked
+    }
+    
     /// Query MCI for best Codon in context (highest CP).
     pub fn query_best(&self, context: &CanonicalContext) -> Option<CanonicalCodon> {
         let result = self.query(context);
@@ -251,40 +414,115 @@ impl MCI {
         hasher.finalize().into()
     }
     
+    /// Serialize the whole store to JSON (backup / cross-process handoff).
+    ///
+    /// Includes every Codon with its context, origin and signature, the
+    /// capacity, eviction policy, cycle counter and LRU access ticks.
+    /// Statistics are not exported. Output is deterministic: equal stores
+    /// export identical JSON.
+    pub fn export(&self) -> Result<String, MciError> {
+        let (access_tick, access) = self.access.snapshot();
+        let snapshot = MciSnapshot {
+            capacity: self.capacity,
+            eviction_policy: self.eviction_policy,
+            cycle_counter: self.cycle_counter,
+            access_tick,
+            access,
+            codons: self.codons.values().flatten().cloned().collect(),
+        };
+        serde_json::to_string(&snapshot).map_err(|e| MciError::Serialization(e.to_string()))
+    }
+    
+    /// Restore a store written by [`MCI::export`].
+    ///
+    /// Statistics restart from zero apart from codon/context totals.
+    ///
+    /// # Errors
+    /// `Serialization` for malformed JSON, `CapacityExceeded` if the
+    /// snapshot holds more Codons than its declared capacity.
+    pub fn import(json: &str) -> Result<MCI, MciError> {
+        let snapshot: MciSnapshot =
+            serde_json::from_str(json).map_err(|e| MciError::Serialization(e.to_string()))?;
+        
+        if let Some(cap) = snapshot.capacity {
+            if snapshot.codons.len() > cap {
+                return Err(MciError::CapacityExceeded);
+            }
+        }
+        
+        let mut codons: BTreeMap<[u8; 32], Vec<CanonicalCodon>> = BTreeMap::new();
+        for codon in snapshot.codons {
+            let key = codon.condicao_uso.context.fingerprint();
+            codons.entry(key).or_default().push(codon);
+        }
+        
+        let mut mci = Self {
+            codons,
+            capacity: snapshot.capacity,
+            eviction_policy: snapshot.eviction_policy,
+            access: AccessTicks::restore(snapshot.access_tick, snapshot.access),
+            stats: MciStats::default(),
+            cycle_counter: snapshot.cycle_counter,
+        };
+        mci.update_stats();
+        Ok(mci)
+    }
+    
     /// Clear all Codons (for testing/reset).
     pub fn clear(&mut self) {
         self.codons.clear();
+        self.access.clear();
         self.stats = MciStats::default();
     }
     
-    /// Evict lowest CP Codon across all contexts.
-    fn evict_lowest(&mut self) -> bool {
-        let mut lowest_cp = f64::MAX;
-        let mut lowest_key: Option<[u8; 32]> = None;
-        let mut lowest_idx: Option<usize> = None;
-        
-        for (key, codons) in &self.codons {
-            for (idx, codon) in codons.iter().enumerate() {
-                if codon.cp() < lowest_cp {
-                    lowest_cp = codon.cp();
-                    lowest_key = Some(*key);
-                    lowest_idx = Some(idx);
+    /// Evict one Codon according to the eviction policy.
+    ///
+    /// Returns the context of the evicted Codon, or None if empty.
+    /// Ties are broken by context order (BTreeMap), so eviction is
+    /// deterministic under replay.
+    fn evict(&mut self) -> Option<CanonicalContext> {
+        let mut victim: Option<([u8; 32], usize)> = None;
+        
+        match self.eviction_policy {
+            EvictionPolicy::LowestCp => {
+                let mut lowest_cp = f64::MAX;
+                for (key, codons) in &self.codons {
+                    for (idx, codon) in codons.iter().enumerate() {
+                        if codon.cp() < lowest_cp {
+                            lowest_cp = codon.cp();
+                            victim = Some((*key, idx));
+                        }
+                    }
                 }
             }
-        }
-        
-        if let (Some(key), Some(idx)) = (lowest_key, lowest_idx) {
-            if let Some(codons) = self.codons.get_mut(&key) {
-                codons.remove(idx);
-                if codons.is_empty() {
-                    self.codons.remove(&key);
+            EvictionPolicy::LeastRecentlyQueried => {
+                let mut oldest_tick = u64::MAX;
+                for (key, codons) in &self.codons {
+                    let tick = self.access.get(key);
+                    if tick < oldest_tick {
+                        // Within a context, drop the lowest CP Codon
+                        let idx = codons.iter()
+                            .enumerate()
+                            .min_by(|a, b| a.1.cp().partial_cmp(&b.1.cp()).unwrap_or(std::cmp::Ordering::Equal))
+                            .map(|(i, _)| i);
+                        if let Some(idx) = idx {
+                            oldest_tick = tick;
+                            victim = Some((*key, idx));
+                        }
+                    }
                 }
-                self.stats.discards_by_dominance += 1;
-                return true;
             }
         }
         
-        false
+        let (key, idx) = victim?;
+        let codons = self.codons.get_mut(&key)?;
+        let removed = codons.remove(idx);
+        if codons.is_empty() {
+            self.codons.remove(&key);
+            self.access.forget(&key);
+        }
+        self.stats.evictions += 1;
+        Some(removed.condicao_uso.context)
     }
     
     fn update_stats(&mut self) {
@@ -306,6 +544,8 @@ pub enum MciError {
     CapacityExceeded,
     /// Invalid Codon
     InvalidCodon(String),
+    /// Whole-store export/import failed
+    Serialization(String),
 }
 
 impl std::fmt::Display for MciError {
@@ -313,6 +553,7 @@ impl std::fmt::Display for MciError {
         match self {
             MciError::CapacityExceeded => write!(f, "MCI capacity exceeded"),
             MciError::InvalidCodon(msg) => write!(f, "Invalid Codon: {}", msg),
+            MciError::Serialization(msg) => write!(f, "MCI serialization failed: {}", msg),
         }
     }
 }
@@ -408,6 +649,41 @@ mod tests {
         assert_eq!(result.codons.len(), 1);
     }
     
+    #[test]
+    fn test_query_nearest() {
+        let mut mci = MCI::unlimited();
+        
+        let target = CanonicalContext::from_hashes([0; 32], [0; 32]);
+        let mut near = [0u8; 32];
+        near[0] = 0b0000_0001; // 1 bit away
+        let mut mid = [0u8; 32];
+        mid[0] = 0b0000_1111; // 4 bits away
+        let far = [0xFFu8; 32]; // 256 bits away
+        
+        for (pc, cp_root) in [(far, 0.9), (mid, 0.8), (near, 0.7)] {
+            let ctx = CanonicalContext::from_hashes(pc, [0; 32]);
+            let codon = CanonicalCodon::new(
+                b"action".to_vec(),
+                ReplayableProvenance::default(),
+                EvaluativeSignature::new(cp_root, cp_root, cp_root, cp_root, false),
+                ActivationCondition::new(ctx),
+            );
+            mci.try_incorporate(codon).unwrap();
+        }
+        
+        let nearest = mci.query_nearest(&target, 2);
+        assert_eq!(nearest.len(), 2);
+        assert_eq!(nearest[0].0, CanonicalContext::from_hashes(near, [0; 32]));
+        assert_eq!(nearest[1].0, CanonicalContext::from_hashes(mid, [0; 32]));
+        assert!(nearest[0].1 > nearest[1].1);
+        
+        // Target itself is not stored → no exact hit
+        assert!(!mci.query(&target).found);
+        
+        // k larger than store returns everything
+        assert_eq!(mci.query_nearest(&target, 10).len(), 3);
+    }
+    
     #[test]
     fn test_baseline_cp() {
         let mut mci = MCI::unlimited();
@@ -458,6 +734,63 @@ mod tests {
         assert!(mci.total_codons() <= 2);
     }
     
+    #[test]
+    fn test_eviction_lowest_cp() {
+        let mut mci = MCI::with_capacity(2);
+        
+        mci.try_incorporate(make_codon(0.9, b"problem_1", Origin::External)).unwrap();
+        mci.try_incorporate(make_codon(0.7, b"problem_2", Origin::External)).unwrap();
+        
+        // problem_2 has the lowest CP → evicted
+        let result = mci.incorporate(make_codon(0.8, b"problem_3", Origin::External)).unwrap();
+        assert!(result.incorporated);
+        assert_eq!(result.evicted, Some(CanonicalContext::new(b"problem_2", b"state")));
+        assert_eq!(mci.total_codons(), 2);
+        assert_eq!(mci.stats().evictions, 1);
+    }
+    
+    #[test]
+    fn test_eviction_least_recently_queried() {
+        let mut mci = MCI::with_capacity(2)
+            .with_eviction_policy(EvictionPolicy::LeastRecentlyQueried);
+        
+        mci.try_incorporate(make_codon(0.7, b"problem_1", Origin::External)).unwrap();
+        mci.try_incorporate(make_codon(0.9, b"problem_2", Origin::External)).unwrap();
+        
+        // Touch problem_1 so problem_2 becomes least recently queried
+        assert!(mci.query(&CanonicalContext::new(b"problem_1", b"state")).found);
+        
+        let result = mci.incorporate(make_codon(0.8, b"problem_3", Origin::External)).unwrap();
+        assert!(result.incorporated);
+        assert_eq!(result.evicted, Some(CanonicalContext::new(b"problem_2", b"state")));
+        assert_eq!(mci.total_codons(), 2);
+        assert!(mci.query(&CanonicalContext::new(b"problem_1", b"state")).found);
+    }
+    
+    #[test]
+    fn test_no_eviction_when_replacing_in_same_context() {
+        let mut mci = MCI::with_capacity(2);
+        
+        mci.try_incorporate(make_codon(0.7, b"problem_1", Origin::External)).unwrap();
+        mci.try_incorporate(make_codon(0.8, b"problem_2", Origin::External)).unwrap();
+        
+        // Dominates the existing Codon in problem_1 → no growth, no eviction
+        let result = mci.incorporate(make_codon(0.9, b"problem_1", Origin::External)).unwrap();
+        assert!(result.incorporated);
+        assert_eq!(result.evicted, None);
+        assert_eq!(mci.total_codons(), 2);
+    }
+    
+    #[test]
+    fn test_unlimited_never_evicts() {
+        let mut mci = MCI::unlimited();
+        for i in 0..10u8 {
+            let result = mci.incorporate(make_codon(0.9, &[i], Origin::External)).unwrap();
+            assert_eq!(result.evicted, None);
+        }
+        assert_eq!(mci.total_codons(), 10);
+    }
+    
     #[test]
     fn test_state_fingerprint_determinism() {
         let mut mci1 = MCI::unlimited();
@@ -479,4 +812,55 @@ mod tests {
         assert_eq!(mci.next_cycle(), 2);
         assert_eq!(mci.cycle_counter(), 2);
     }
+    
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut mci = MCI::with_capacity(8).with_eviction_policy(EvictionPolicy::LeastRecentlyQueried);
+        mci.next_cycle();
+        mci.try_incorporate(make_codon(0.9, b"problem_a", Origin::External)).unwrap();
+        mci.try_incorporate(make_codon(0.8, b"problem_b", Origin::Internal)).unwrap();
+        mci.try_incorporate(make_codon(0.95, b"problem_c", Origin::Recombined)).unwrap();
+        
+        let json = mci.export().unwrap();
+        let restored = MCI::import(&json).unwrap();
+        
+        assert_eq!(restored.capacity(), Some(8));
+        assert_eq!(restored.eviction_policy(), EvictionPolicy::LeastRecentlyQueried);
+        assert_eq!(restored.cycle_counter(), 1);
+        assert_eq!(restored.total_codons(), 3);
+        assert_eq!(restored.state_fingerprint(), mci.state_fingerprint());
+        
+        for problem in [&b"problem_a"[..], b"problem_b", b"problem_c"] {
+            let ctx = CanonicalContext::new(problem, b"state");
+            let before = mci.query_best(&ctx).unwrap();
+            let after = restored.query_best(&ctx).unwrap();
+            assert_eq!(before.fingerprint(), after.fingerprint());
+            assert_eq!(before.evidencia.origin, after.evidencia.origin);
+            assert_eq!(before.cp().to_bits(), after.cp().to_bits());
+        }
+    }
+    
+    #[test]
+    fn test_export_is_deterministic() {
+        let mut mci = MCI::with_capacity(4);
+        for i in 0..3u8 {
+            mci.try_incorporate(make_codon(0.7 + f64::from(i) * 0.05, &[i], Origin::External)).unwrap();
+        }
+        
+        let first = mci.export().unwrap();
+        let second = MCI::import(&first).unwrap().export().unwrap();
+        assert_eq!(first, second);
+    }
+    
+    #[test]
+    fn test_import_rejects_malformed_and_over_capacity() {
+        assert!(matches!(MCI::import("{not json"), Err(MciError::Serialization(_))));
+        
+        let mut mci = MCI::unlimited();
+        mci.try_incorporate(make_codon(0.9, b"a", Origin::External)).unwrap();
+        mci.try_incorporate(make_codon(0.9, b"b", Origin::External)).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&mci.export().unwrap()).unwrap();
+        value["capacity"] = serde_json::json!(1);
+        assert_eq!(MCI::import(&value.to_string()).unwrap_err(), MciError::CapacityExceeded);
+    }
 }
@@ -34,6 +34,7 @@
 //! - **CanonicalCodon**: Atomic knowledge unit with 4 fields (LEI-AF-12-01)
 //! - **MCI**: Memory store indexed by context (AF-12)
 //! - **LearningEngine**: Incorporation orchestrator (AF-11)
+//! - **SharedMci**: Lock-protected MCI handle for concurrent learners
 //! - **Origin**: Autorreferência marker (AO-18)
 //!
 //! # Layer: Community
@@ -43,6 +44,7 @@ mod context;
 mod codon;
 mod mci;
 mod learning;
+mod shared;
 
 // Re-exports
 pub use context::CanonicalContext;
@@ -50,17 +52,21 @@ pub use codon::{
     CanonicalCodon,
     Origin,
     EvaluativeSignature,
+    EvaluativeSignatureBuilder,
+    SignatureError,
     ActivationCondition,
     ReplayableProvenance,
 };
-pub use mci::{MCI, MciQueryResult, MciStats, MciError};
+pub use mci::{MCI, MciQueryResult, MciStats, MciError, EvictionPolicy, Incorporation};
 pub use learning::{
     LearningEngine,
     LearningResult,
     EpistemicTrigger,
     RejectionReason,
     StagnationDetector,
+    DEFAULT_STAGNATION_THRESHOLD,
 };
+pub use shared::SharedMci;
 
 #[cfg(test)]
 mod tests {
@@ -181,6 +187,7 @@ mod tests {
         assert_send_sync::<Origin>();
         assert_send_sync::<EvaluativeSignature>();
         assert_send_sync::<MCI>();
+        assert_send_sync::<SharedMci>();
         // LearningEngine uses HashMap which is Send+Sync
     }
 }
@@ -0,0 +1,193 @@
+//! Shared MCI — concurrent access wrapper for AF-12
+//!
+//! # Purpose
+//! `MCI` is `Send + Sync` but not internally synchronized. `SharedMci`
+//! wraps it in `Arc<RwLock<_>>` so several learners can share one store:
+//! queries take the read lock, incorporations take the write lock.
+//!
+//! # Lock Ordering
+//! Every method acquires exactly one MCI lock and releases it before
+//! returning; no guard escapes. The `LearningEngine` is never stored
+//! here — callers pass it in. When an engine is itself shared (e.g.
+//! `Mutex<LearningEngine>`), always lock the engine FIRST and the MCI
+//! second (engine → MCI). Never lock an engine from code that runs while
+//! an MCI lock is held.
+//!
+//! # Layer: Community
+//! # Version: 0.7.0
+
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use super::context::CanonicalContext;
+use super::codon::CanonicalCodon;
+use super::mci::{MCI, MciError, MciQueryResult};
+use super::learning::{LearningEngine, LearningResult, EpistemicTrigger};
+
+/// Thread-safe handle to a shared MCI.
+///
+/// Cloning the handle shares the same store.
+#[derive(Debug, Clone, Default)]
+pub struct SharedMci {
+    inner: Arc<RwLock<MCI>>,
+}
+
+impl SharedMci {
+    /// Wrap an existing MCI.
+    pub fn new(mci: MCI) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(mci)),
+        }
+    }
+
+    // A panic in another learner must not make the store unusable;
+    // MCI updates are applied in a single step, so the data stays valid.
+    fn read(&self) -> RwLockReadGuard<'_, MCI> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, MCI> {
+        self.inner.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Query Codons for a context (read lock) — LEI-AF-12-04
+    pub fn query(&self, context: &CanonicalContext) -> MciQueryResult {
+        self.read().query(context)
+    }
+
+    /// Best Codon for a context (read lock).
+    pub fn query_best(&self, context: &CanonicalContext) -> Option<CanonicalCodon> {
+        self.read().query_best(context)
+    }
+
+    /// Baseline CP for a context (read lock).
+    pub fn baseline_cp(&self, context: &CanonicalContext) -> f64 {
+        self.read().baseline_cp(context)
+    }
+
+    /// Total number of stored Codons (read lock).
+    pub fn total_codons(&self) -> usize {
+        self.read().total_codons()
+    }
+
+    /// Number of unique contexts (read lock).
+    pub fn unique_contexts(&self) -> usize {
+        self.read().unique_contexts()
+    }
+
+    /// Fingerprint of the current state (read lock) — LEI-AF-12-05
+    pub fn state_fingerprint(&self) -> [u8; 32] {
+        self.read().state_fingerprint()
+    }
+
+    /// Attempt to learn a Codon (write lock) — LEI-AF-11-02
+    ///
+    /// The baseline check and incorporation run under one write lock,
+    /// so no other learner can interleave between them.
+    pub fn try_learn(
+        &self,
+        engine: &mut LearningEngine,
+        candidate: CanonicalCodon,
+        trigger: EpistemicTrigger,
+    ) -> LearningResult {
+        engine.try_learn(&mut self.write(), candidate, trigger)
+    }
+
+    /// Incorporate a Codon directly (write lock).
+    pub fn try_incorporate(&self, codon: CanonicalCodon) -> Result<bool, MciError> {
+        self.write().try_incorporate(codon)
+    }
+
+    /// Copy of the current store (read lock).
+    pub fn snapshot(&self) -> MCI {
+        self.read().clone()
+    }
+}
+
+impl From<MCI> for SharedMci {
+    fn from(mci: MCI) -> Self {
+        Self::new(mci)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::codon::{EvaluativeSignature, Origin};
+    use std::thread;
+
+    #[test]
+    fn test_concurrent_learning_loses_nothing() {
+        const THREADS: u8 = 8;
+        const PER_THREAD: u8 = 25;
+        let shared = SharedMci::new(MCI::unlimited());
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    let mut engine = LearningEngine::without_replay_verify(3);
+                    for i in 0..PER_THREAD {
+                        let ctx = CanonicalContext::new(&[t, i], b"state");
+                        let codon = engine.create_candidate(
+                            vec![t, i],
+                            ctx,
+                            EvaluativeSignature::new(0.9, 0.9, 0.9, 0.9, false),
+                            [t; 16], [i; 32], [0; 32], u64::from(i),
+                            Origin::External,
+                        );
+                        let result = shared.try_learn(&mut engine, codon, EpistemicTrigger::NoveltyDetected);
+                        assert!(result.learned);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let expected = usize::from(THREADS) * usize::from(PER_THREAD);
+        assert_eq!(shared.total_codons(), expected);
+        assert_eq!(shared.unique_contexts(), expected);
+        for t in 0..THREADS {
+            for i in 0..PER_THREAD {
+                let best = shared.query_best(&CanonicalContext::new(&[t, i], b"state")).unwrap();
+                assert_eq!(best.forma_bytes, vec![t, i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concurrent_improvements_keep_best() {
+        let shared = SharedMci::new(MCI::unlimited());
+        let ctx = CanonicalContext::new(b"contested", b"state");
+
+        let handles: Vec<_> = (1..=10u8)
+            .map(|k| {
+                let shared = shared.clone();
+                let ctx = ctx.clone();
+                thread::spawn(move || {
+                    let mut engine = LearningEngine::without_replay_verify(3);
+                    let root = 0.5 + f64::from(k) * 0.04;
+                    let codon = engine.create_candidate(
+                        vec![k],
+                        ctx,
+                        EvaluativeSignature::new(root, root, root, root, false),
+                        [k; 16], [0; 32], [0; 32], u64::from(k),
+                        Origin::External,
+                    );
+                    shared.try_learn(&mut engine, codon, EpistemicTrigger::ExplicitRequest);
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Whatever the interleaving, only the best Codon survives
+        assert_eq!(shared.total_codons(), 1);
+        assert_eq!(shared.query_best(&ctx).unwrap().forma_bytes, vec![10]);
+        assert_eq!(shared.snapshot().state_fingerprint(), shared.state_fingerprint());
+    }
+}
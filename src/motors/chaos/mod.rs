@@ -27,12 +27,40 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Canonical implementation with dual trajectories
 //! 2025-01-02 - Carlos Eduardo Favini - Renamed to trajectory_divergence (v0.2.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Added was_clamped signaling (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Degenerate perturbation detection
+//! 2026-10-16 - Carlos Eduardo Favini - Selectable divergence method (log-separation slope)
+//! 2026-10-16 - Carlos Eduardo Favini - resample_trajectory preprocessing helper
+//! 2026-10-16 - Carlos Eduardo Favini - MAX_RESAMPLED_STATES bound on resampling
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
+use crate::compat::prelude::*;
+
 use super::CognitiveMotor;
 
+/// Mean separation at or below which the perturbation is considered absent.
+///
+/// A small multiple of machine epsilon: trajectories closer than this are
+/// numerically identical and the divergence rate carries no information.
+pub const DEGENERATE_SEPARATION: f64 = 16.0 * f64::EPSILON;
+
+/// Maximum number of states `resample_trajectory` will produce.
+pub const MAX_RESAMPLED_STATES: usize = 1 << 20;
+
+/// Algorithm used to estimate the trajectory divergence rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DivergenceMethod {
+    /// Mean of per-step log ratios: λ = (1/T) × Σ ln(d_i / d_{i-1}).
+    #[default]
+    LocalRatio,
+    /// Least-squares slope of ln(d_i) against time (Rosenstein-style).
+    ///
+    /// Uses every point of the separation curve instead of effectively
+    /// only its endpoints, so it is less sensitive to noise.
+    LogSeparationSlope,
+}
+
 /// Chaotic Motor (M_C).
 ///
 /// Evaluates structural sensitivity and stability using chaos theory concepts.
@@ -84,6 +112,10 @@ pub struct ChaosInput {
     /// Error tolerance for predictability horizon.
     /// If None, derived as 10 * delta_0.
     pub epsilon_tolerance: Option<f64>,
+
+    /// Divergence rate estimator.
+    #[serde(default)]
+    pub divergence_method: DivergenceMethod,
 }
 
 /// Validation error for Chaos input.
@@ -103,10 +135,16 @@ pub enum ChaosValidationError {
     InvalidDt,
     /// State dimension is zero.
     ZeroDimensionState { time_step: usize },
+    /// Trajectory to resample is empty.
+    EmptyTrajectory,
+    /// A state's dimension differs from the first state's.
+    InconsistentDimension { time_step: usize, expected: usize, found: usize },
+    /// Resampling would produce more than `MAX_RESAMPLED_STATES` states.
+    ResampledTooLong { requested: usize, max: usize },
 }
 
-impl std::fmt::Display for ChaosValidationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ChaosValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::EmptyReferenceTrajectory => {
                 write!(f, "Reference trajectory is empty")
@@ -125,11 +163,18 @@ impl std::fmt::Display for ChaosValidationError {
             Self::ZeroDimensionState { time_step } => {
                 write!(f, "Zero dimension state at t={}", time_step)
             }
+            Self::EmptyTrajectory => write!(f, "Trajectory is empty"),
+            Self::InconsistentDimension { time_step, expected, found } => {
+                write!(f, "Inconsistent dimension at t={}: expected={}, found={}", time_step, expected, found)
+            }
+            Self::ResampledTooLong { requested, max } => {
+                write!(f, "Resampling would produce {} states (max {})", requested, max)
+            }
         }
     }
 }
 
-impl std::error::Error for ChaosValidationError {}
+impl core::error::Error for ChaosValidationError {}
 
 /// Output from the Chaotic Motor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -174,6 +219,18 @@ pub struct ChaosOutput {
 
     /// Original score before clamping (if was_clamped is true).
     pub unclamped_score: Option<f64>,
+
+    /// Whether the trajectories never separated beyond `DEGENERATE_SEPARATION`.
+    ///
+    /// When true, zero separations were replaced by `delta_0` and the
+    /// divergence rate is unreliable: it reflects the substitution, not
+    /// the dynamics. The score remains finite and in [0, 1].
+    #[serde(default)]
+    pub degenerate_perturbation: bool,
+
+    /// Estimator used for `trajectory_divergence_rate`.
+    #[serde(default)]
+    pub divergence_method: DivergenceMethod,
 }
 
 impl ChaosOutput {
@@ -191,6 +248,8 @@ impl ChaosOutput {
             score: 0.0,
             was_clamped: false,
             unclamped_score: None,
+            degenerate_perturbation: false,
+            divergence_method: DivergenceMethod::default(),
         }
     }
 }
@@ -276,23 +335,38 @@ impl ChaosMotor {
     /// nearby trajectories separate, without the full mathematical rigor of
     /// Lyapunov exponent calculation (which requires infinite-time limits and
     /// renormalization).
+    ///
+    /// With `DivergenceMethod::LogSeparationSlope` the rate is instead the
+    /// least-squares slope of ln(d_i) over t_i = i × dt.
+    ///
+    /// Also returns whether the mean raw separation stayed at or below
+    /// `DEGENERATE_SEPARATION` (no effective perturbation).
     fn estimate_trajectory_divergence(
         reference: &[Vec<f64>],
         perturbed: &[Vec<f64>],
         delta_0: f64,
         dt: f64,
-    ) -> (f64, Vec<f64>) {
+        method: DivergenceMethod,
+    ) -> (f64, Vec<f64>, bool) {
         let n = reference.len();
-        if n < 2 {
-            return (0.0, vec![delta_0]);
-        }
 
         // Calculate divergence at each time step
         let mut divergences = Vec::with_capacity(n);
+        let mut raw_sum = 0.0;
         for (ref_state, pert_state) in reference.iter().zip(perturbed.iter()) {
             let d = Self::euclidean_distance(ref_state, pert_state);
+            raw_sum += d;
             divergences.push(if d > 0.0 { d } else { delta_0 });
         }
+        let degenerate = raw_sum / n as f64 <= DEGENERATE_SEPARATION;
+
+        if n < 2 {
+            return (0.0, vec![delta_0], degenerate);
+        }
+
+        if method == DivergenceMethod::LogSeparationSlope {
+            return (Self::log_separation_slope(&divergences, dt), divergences, degenerate);
+        }
 
         // Calculate local divergence rates and average
         let mut lambda_sum = 0.0;
@@ -320,7 +394,28 @@ impl ChaosMotor {
             0.0
         };
 
-        (lambda, divergences)
+        (lambda, divergences, degenerate)
+    }
+
+    /// Least-squares slope of ln(d_i) against t_i = i × dt.
+    ///
+    /// # Formula
+    /// λ = Σ (t_i - t̄)(y_i - ȳ) / Σ (t_i - t̄)², with y_i = ln(d_i)
+    fn log_separation_slope(divergences: &[f64], dt: f64) -> f64 {
+        let n = divergences.len() as f64;
+        let t_mean = (n - 1.0) * dt / 2.0;
+        let y_mean = divergences.iter().map(|d| d.ln()).sum::<f64>() / n;
+
+        let mut cov = 0.0;
+        let mut var = 0.0;
+        for (i, d) in divergences.iter().enumerate() {
+            let dt_i = i as f64 * dt - t_mean;
+            cov += dt_i * (d.ln() - y_mean);
+            var += dt_i * dt_i;
+        }
+
+        let slope = cov / var;
+        if slope.is_finite() { slope } else { 0.0 }
     }
 
     /// Calculates predictability horizon.
@@ -427,12 +522,14 @@ impl CognitiveMotor for ChaosMotor {
         let epsilon = input.epsilon_tolerance.unwrap_or(10.0 * input.delta_0);
 
         // Calculate trajectory divergence rate
-        let (trajectory_divergence_rate, divergence_history) = Self::estimate_trajectory_divergence(
-            &input.reference_trajectory,
-            &input.perturbed_trajectory,
-            input.delta_0,
-            input.dt,
-        );
+        let (trajectory_divergence_rate, divergence_history, degenerate_perturbation) =
+            Self::estimate_trajectory_divergence(
+                &input.reference_trajectory,
+                &input.perturbed_trajectory,
+                input.delta_0,
+                input.dt,
+                input.divergence_method,
+            );
 
         // Calculate predictability horizon
         let predictability_horizon = Self::calculate_predictability_horizon(
@@ -471,10 +568,77 @@ impl CognitiveMotor for ChaosMotor {
             score: final_score,
             was_clamped: needs_clamping,
             unclamped_score: if needs_clamping { Some(raw_score) } else { None },
+            degenerate_perturbation,
+            divergence_method: input.divergence_method,
         }
     }
 }
 
+/// Resamples a trajectory sampled every `from_dt` onto a grid of `to_dt`.
+///
+/// Linear interpolation per dimension. The output starts at t = 0 and
+/// covers the original span `(len - 1) × from_dt`; a trailing partial
+/// step is dropped. Use it to align reference and perturbed trajectories
+/// recorded at different rates before building a `ChaosInput`.
+///
+/// Fails with `ResampledTooLong` instead of allocating when the output
+/// would exceed `MAX_RESAMPLED_STATES` states.
+pub fn resample_trajectory(
+    traj: &[Vec<f64>],
+    from_dt: f64,
+    to_dt: f64,
+) -> Result<Vec<Vec<f64>>, ChaosValidationError> {
+    if !(from_dt > 0.0 && from_dt.is_finite() && to_dt > 0.0 && to_dt.is_finite()) {
+        return Err(ChaosValidationError::InvalidDt);
+    }
+    if traj.is_empty() {
+        return Err(ChaosValidationError::EmptyTrajectory);
+    }
+    let dim = traj[0].len();
+    if dim == 0 {
+        return Err(ChaosValidationError::ZeroDimensionState { time_step: 0 });
+    }
+    for (t, state) in traj.iter().enumerate() {
+        if state.len() != dim {
+            return Err(ChaosValidationError::InconsistentDimension {
+                time_step: t,
+                expected: dim,
+                found: state.len(),
+            });
+        }
+    }
+
+    let last = traj.len() - 1;
+    let span = last as f64 * from_dt;
+    // Tolerance so a span that is an exact multiple of to_dt keeps its endpoint
+    // Saturating cast: an extreme ratio is reported, never allocated
+    let steps = (span / to_dt + 1e-9) as usize;
+    if steps >= MAX_RESAMPLED_STATES {
+        return Err(ChaosValidationError::ResampledTooLong {
+            requested: steps.saturating_add(1),
+            max: MAX_RESAMPLED_STATES,
+        });
+    }
+
+    let resampled = (0..=steps)
+        .map(|k| {
+            let position = k as f64 * to_dt / from_dt;
+            let i = (position as usize).min(last);
+            if i == last {
+                return traj[last].clone();
+            }
+            let frac = position - i as f64;
+            traj[i]
+                .iter()
+                .zip(&traj[i + 1])
+                .map(|(a, b)| a + (b - a) * frac)
+                .collect()
+        })
+        .collect();
+
+    Ok(resampled)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,6 +665,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -517,6 +682,7 @@ mod tests {
             delta_0: 0.0, // Invalid
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -538,6 +704,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -562,6 +729,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -588,6 +756,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -613,6 +782,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: Some(1.0),
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output1 = motor.evaluate(&input);
@@ -637,6 +807,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -657,6 +828,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -676,6 +848,7 @@ mod tests {
             delta_0: 0.1,
             dt: 1.0,
             epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
         };
 
         let output = motor.evaluate(&input);
@@ -684,4 +857,143 @@ mod tests {
         assert!(output.score >= 0.0);
         assert!(output.score <= 1.0);
     }
+
+    #[test]
+    fn test_identical_trajectories_flag_degenerate() {
+        let motor = ChaosMotor::new();
+
+        let trajectory = vec![vec![0.0, 1.0], vec![0.5, 1.5], vec![1.0, 3.0], vec![2.0, 5.0]];
+        let input = ChaosInput {
+            reference_trajectory: trajectory.clone(),
+            perturbed_trajectory: trajectory,
+            delta_0: 0.1,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+
+        let output = motor.evaluate(&input);
+
+        assert!(output.valid);
+        assert!(output.degenerate_perturbation);
+        assert!(output.score.is_finite());
+        assert!((0.0..=1.0).contains(&output.score));
+        assert!(output.trajectory_divergence_rate.is_finite());
+    }
+
+    #[test]
+    fn test_real_perturbation_not_degenerate() {
+        let motor = ChaosMotor::new();
+        let (reference, perturbed) = exponential_divergence(5, 0.2, 0.1, 1.0);
+
+        let output = motor.evaluate(&ChaosInput {
+            reference_trajectory: reference,
+            perturbed_trajectory: perturbed,
+            delta_0: 0.1,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        });
+
+        assert!(output.valid);
+        assert!(!output.degenerate_perturbation);
+    }
+
+    #[test]
+    fn test_slope_method_recovers_lambda_better() {
+        let motor = ChaosMotor::new();
+        let (lambda, delta_0, dt) = (0.3, 0.01, 1.0);
+        // Exponential separation with alternating ±0.5 log-noise
+        let reference: Vec<Vec<f64>> = (0..20).map(|i| vec![i as f64]).collect();
+        let perturbed: Vec<Vec<f64>> = (0..20)
+            .map(|i| {
+                let noise = if i % 2 == 0 { 0.5 } else { -0.5 };
+                vec![i as f64 + delta_0 * (lambda * i as f64 * dt + noise).exp()]
+            })
+            .collect();
+
+        let evaluate = |method| {
+            motor.evaluate(&ChaosInput {
+                reference_trajectory: reference.clone(),
+                perturbed_trajectory: perturbed.clone(),
+                delta_0,
+                dt,
+                epsilon_tolerance: None,
+                divergence_method: method,
+            })
+        };
+        let local = evaluate(DivergenceMethod::LocalRatio);
+        let slope = evaluate(DivergenceMethod::LogSeparationSlope);
+
+        assert_eq!(local.divergence_method, DivergenceMethod::LocalRatio);
+        assert_eq!(slope.divergence_method, DivergenceMethod::LogSeparationSlope);
+        let local_error = (local.trajectory_divergence_rate - lambda).abs();
+        let slope_error = (slope.trajectory_divergence_rate - lambda).abs();
+        assert!(slope_error < local_error);
+        assert!(slope_error < 0.01);
+    }
+
+    #[test]
+    fn test_slope_method_exact_on_clean_exponential() {
+        let motor = ChaosMotor::new();
+        let (reference, perturbed) = exponential_divergence(10, 0.2, 0.01, 0.5);
+
+        let output = motor.evaluate(&ChaosInput {
+            reference_trajectory: reference,
+            perturbed_trajectory: perturbed,
+            delta_0: 0.01,
+            dt: 0.5,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LogSeparationSlope,
+        });
+
+        assert!(output.valid);
+        assert!((output.trajectory_divergence_rate - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_resample_linear_trajectory() {
+        // x(t) = 1 + 2t, y(t) = -t, sampled every 0.5 over [0, 3]
+        let line = |t: f64| vec![1.0 + 2.0 * t, -t];
+        let traj: Vec<Vec<f64>> = (0..7).map(|i| line(i as f64 * 0.5)).collect();
+
+        let fine = resample_trajectory(&traj, 0.5, 0.2).unwrap();
+        assert_eq!(fine.len(), 16);
+        for (k, state) in fine.iter().enumerate() {
+            let expected = line(k as f64 * 0.2);
+            for (a, b) in state.iter().zip(&expected) {
+                assert!((a - b).abs() < 1e-12);
+            }
+        }
+
+        let coarse = resample_trajectory(&traj, 0.5, 1.5).unwrap();
+        assert_eq!(coarse, vec![line(0.0), line(1.5), line(3.0)]);
+
+        assert_eq!(resample_trajectory(&traj, 0.5, 0.5).unwrap(), traj);
+    }
+
+    #[test]
+    fn test_resample_rejects_invalid_input() {
+        let traj = vec![vec![0.0], vec![1.0]];
+        assert_eq!(resample_trajectory(&traj, 0.0, 0.1), Err(ChaosValidationError::InvalidDt));
+        assert_eq!(resample_trajectory(&traj, 0.1, -1.0), Err(ChaosValidationError::InvalidDt));
+        assert_eq!(resample_trajectory(&traj, f64::NAN, 0.1), Err(ChaosValidationError::InvalidDt));
+        assert_eq!(resample_trajectory(&[], 0.1, 0.1), Err(ChaosValidationError::EmptyTrajectory));
+        assert_eq!(
+            resample_trajectory(&[vec![0.0, 1.0], vec![2.0]], 0.1, 0.1),
+            Err(ChaosValidationError::InconsistentDimension { time_step: 1, expected: 2, found: 1 })
+        );
+        assert_eq!(resample_trajectory(&[vec![3.0]], 1.0, 0.1).unwrap(), vec![vec![3.0]]);
+
+        assert_eq!(
+            resample_trajectory(&traj, 0.1, 1e-300),
+            Err(ChaosValidationError::ResampledTooLong { requested: usize::MAX, max: MAX_RESAMPLED_STATES })
+        );
+        let at_limit = 0.1 / (MAX_RESAMPLED_STATES - 1) as f64;
+        assert_eq!(resample_trajectory(&traj, 0.1, at_limit).unwrap().len(), MAX_RESAMPLED_STATES);
+        assert!(matches!(
+            resample_trajectory(&traj, 0.1, 0.1 / MAX_RESAMPLED_STATES as f64),
+            Err(ChaosValidationError::ResampledTooLong { requested, .. }) if requested == MAX_RESAMPLED_STATES + 1
+        ));
+    }
 }
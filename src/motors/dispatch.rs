@@ -0,0 +1,372 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Motor Dispatch
+//! Description: Single call site to evaluate any of the four motors.
+//!              Routes each input variant to the motor's own `evaluate`
+//!              with no behavioral change and normalizes the result.
+//! Layer: Community
+//! Dependencies: motors
+//! Affected Components: orchestrators (cognitive cycle, GDO emulator)
+//! --------------------------
+
+use serde::{Deserialize, Serialize};
+
+use crate::compat::prelude::*;
+
+use crate::motors::MotorType;
+
+use super::{
+    ChaosInput, ChaosMotor, CognitiveMotor, MeristicInput, MeristicMotor, MotorOutput, NashInput,
+    NashMotor, PraxisInput, PraxisMotor,
+};
+
+/// Input for any of the four motors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MotorInput {
+    /// Input for the Praxeological motor (M_P).
+    Praxis(PraxisInput),
+    /// Input for the Nash motor (M_N).
+    Nash(NashInput),
+    /// Input for the Chaotic motor (M_C).
+    Chaos(ChaosInput),
+    /// Input for the Meristic motor (M_M).
+    Meristic(MeristicInput),
+}
+
+impl MotorInput {
+    /// Returns which motor this input is for.
+    pub fn motor_type(&self) -> MotorType {
+        match self {
+            Self::Praxis(_) => MotorType::Praxis,
+            Self::Nash(_) => MotorType::Nash,
+            Self::Chaos(_) => MotorType::Chaos,
+            Self::Meristic(_) => MotorType::Meristic,
+        }
+    }
+}
+
+/// Normalized result of a dispatched motor evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MotorScore {
+    /// Motor that produced the score.
+    pub motor: MotorType,
+    /// The motor's score [0.0, 1.0].
+    pub score: f64,
+    /// Whether the input was valid.
+    pub valid: bool,
+    /// Whether the score was clamped to [0, 1].
+    pub was_clamped: bool,
+}
+
+/// Evaluates one motor, returning its full output.
+///
+/// Calls `motor.evaluate(input)` unchanged. Orchestrators that need the
+/// whole output (not just a `MotorScore`) go through here so every
+/// motor evaluation shares one call site.
+///
+/// # Tracing
+/// With the `tracing` feature each call runs inside a `motor_evaluation`
+/// debug span recording `motor`, `score` and `was_clamped`.
+pub fn evaluate_motor<M>(motor_type: MotorType, motor: &M, input: &M::Input) -> M::Output
+where
+    M: CognitiveMotor,
+    M::Output: MotorOutput,
+{
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "motor_evaluation",
+        motor = motor_type.name(),
+        score = tracing::field::Empty,
+        was_clamped = tracing::field::Empty,
+    )
+    .entered();
+    #[cfg(not(feature = "tracing"))]
+    let _ = motor_type;
+
+    let output = motor.evaluate(input);
+
+    #[cfg(feature = "tracing")]
+    {
+        span.record("score", output.score());
+        span.record("was_clamped", output.was_clamped());
+    }
+
+    output
+}
+
+/// Evaluates whichever motor the input is for.
+///
+/// # Determinism
+/// Delegates to the motor's `evaluate`; same input, same score.
+///
+/// # Tracing
+/// Goes through `evaluate_motor`, so each call emits one
+/// `motor_evaluation` span with the `tracing` feature.
+pub fn evaluate_any(input: MotorInput) -> MotorScore {
+    fn score<M>(motor_type: MotorType, motor: M, input: &M::Input) -> MotorScore
+    where
+        M: CognitiveMotor,
+        M::Output: MotorOutput,
+    {
+        let out = evaluate_motor(motor_type, &motor, input);
+        MotorScore {
+            motor: motor_type,
+            score: out.score(),
+            valid: out.is_valid(),
+            was_clamped: out.was_clamped(),
+        }
+    }
+
+    match input {
+        MotorInput::Praxis(i) => score(MotorType::Praxis, PraxisMotor::new(), &i),
+        MotorInput::Nash(i) => score(MotorType::Nash, NashMotor::new(), &i),
+        MotorInput::Chaos(i) => score(MotorType::Chaos, ChaosMotor::new(), &i),
+        MotorInput::Meristic(i) => score(MotorType::Meristic, MeristicMotor::new(), &i),
+    }
+}
+
+/// Evaluates a batch of inputs, one score per input in input order.
+///
+/// With the `rayon` feature the batch is evaluated in parallel; each
+/// evaluation is pure, so the result is identical to
+/// `evaluate_batch_sequential` element for element.
+pub fn evaluate_batch(inputs: Vec<MotorInput>) -> Vec<MotorScore> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        inputs.into_par_iter().map(evaluate_any).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        evaluate_batch_sequential(inputs)
+    }
+}
+
+/// Evaluates a batch of inputs one after another, in input order.
+pub fn evaluate_batch_sequential(inputs: Vec<MotorInput>) -> Vec<MotorScore> {
+    inputs.into_iter().map(evaluate_any).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::motors::DivergenceMethod;
+
+    #[test]
+    fn test_dispatch_praxis() {
+        let input = PraxisInput {
+            proposed: vec!["a".into(), "b".into()],
+            necessary: vec!["a".into(), "b".into()],
+            context_vector: vec![1.0, 0.0],
+            history_centroid: vec![1.0, 0.0],
+        };
+        let direct = PraxisMotor::new().evaluate(&input);
+        let score = evaluate_any(MotorInput::Praxis(input));
+        assert_eq!(score.motor, MotorType::Praxis);
+        assert!(score.valid);
+        assert_eq!(score.score, direct.score);
+    }
+
+    #[test]
+    fn test_dispatch_nash() {
+        let input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![100, 0], vec![100, 0]],
+            scale: 100,
+        };
+        let direct = NashMotor::new().evaluate(&input);
+        let score = evaluate_any(MotorInput::Nash(input));
+        assert_eq!(score.motor, MotorType::Nash);
+        assert!(score.valid);
+        // (0,0) is a pure equilibrium of the coordination game
+        assert!((score.score - 1.0).abs() < 1e-12);
+        assert_eq!(score.score, direct.score);
+    }
+
+    #[test]
+    fn test_dispatch_chaos() {
+        let input = ChaosInput {
+            reference_trajectory: vec![vec![0.0], vec![0.0], vec![0.0]],
+            perturbed_trajectory: vec![vec![0.0], vec![0.0], vec![0.0]],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+        let direct = ChaosMotor::new().evaluate(&input);
+        let score = evaluate_any(MotorInput::Chaos(input));
+        assert_eq!(score.motor, MotorType::Chaos);
+        assert_eq!(score.valid, direct.valid);
+        assert_eq!(score.score, direct.score);
+    }
+
+    #[test]
+    fn test_dispatch_meristic() {
+        let input = MeristicInput {
+            current_embedding: vec![0.5, 0.5, 0.5],
+            historical_embeddings: vec![],
+            domain_characteristics: None,
+            exploration_depth: 3,
+            novelty_weight: 0.5,
+        };
+        let direct = MeristicMotor::new().evaluate(&input);
+        let score = evaluate_any(MotorInput::Meristic(input));
+        assert_eq!(score.motor, MotorType::Meristic);
+        assert!(score.valid);
+        assert_eq!(score.score, direct.score);
+    }
+
+    #[test]
+    fn test_batch_matches_sequential() {
+        let mut inputs = Vec::new();
+        for k in 0..32u32 {
+            let x = f64::from(k) / 32.0;
+            inputs.push(match k % 4 {
+                0 => MotorInput::Praxis(PraxisInput {
+                    proposed: vec!["a".into(), format!("p{}", k)],
+                    necessary: vec!["a".into()],
+                    context_vector: vec![x, 1.0 - x],
+                    history_centroid: vec![0.5, 0.5],
+                }),
+                1 => MotorInput::Nash(NashInput {
+                    num_players: 2,
+                    action_sizes: vec![2, 2],
+                    payoffs: vec![vec![3, 0, 5, 1], vec![3, 5, 0, 1]],
+                    strategies: vec![vec![u64::from(k), 100 - u64::from(k)], vec![50, 50]],
+                    scale: 100,
+                }),
+                2 => MotorInput::Chaos(ChaosInput {
+                    reference_trajectory: vec![vec![0.1], vec![0.2 + x], vec![0.3]],
+                    perturbed_trajectory: vec![vec![0.11], vec![0.25 + x], vec![0.4]],
+                    delta_0: 0.01,
+                    dt: 1.0,
+                    epsilon_tolerance: None,
+                    divergence_method: DivergenceMethod::LocalRatio,
+                }),
+                _ => MotorInput::Meristic(MeristicInput {
+                    current_embedding: vec![x, 0.5, 1.0 - x],
+                    historical_embeddings: vec![vec![0.5, 0.5, 0.5]],
+                    domain_characteristics: None,
+                    exploration_depth: 3,
+                    novelty_weight: 0.5,
+                }),
+            });
+        }
+
+        let parallel = evaluate_batch(inputs.clone());
+        let sequential = evaluate_batch_sequential(inputs.clone());
+        assert_eq!(parallel.len(), inputs.len());
+        for ((p, s), input) in parallel.iter().zip(&sequential).zip(&inputs) {
+            assert_eq!(p.motor, input.motor_type());
+            assert_eq!(p.score.to_bits(), s.score.to_bits());
+            assert_eq!(p, s);
+        }
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    mod spans {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Each span's name and the fields recorded on it.
+        type SpanLog = Vec<(&'static str, Vec<String>)>;
+
+        #[derive(Clone, Default)]
+        pub struct Recorder(pub Arc<Mutex<SpanLog>>);
+
+        struct FieldNames<'a>(&'a mut Vec<String>);
+
+        impl Visit for FieldNames<'_> {
+            fn record_debug(&mut self, field: &Field, _value: &dyn core::fmt::Debug) {
+                self.0.push(field.name().to_string());
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.0.lock().unwrap();
+                let mut fields = Vec::new();
+                span.record(&mut FieldNames(&mut fields));
+                spans.push((span.metadata().name(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, id: &Id, values: &Record<'_>) {
+                let mut spans = self.0.lock().unwrap();
+                let index = id.into_u64() as usize - 1;
+                values.record(&mut FieldNames(&mut spans[index].1));
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        /// Runs `f` and returns the fields of every `motor_evaluation` span.
+        pub fn motor_spans(f: impl FnOnce()) -> Vec<Vec<String>> {
+            let recorder = Recorder::default();
+            tracing::subscriber::with_default(recorder.clone(), f);
+            let spans = recorder.0.lock().unwrap();
+            spans
+                .iter()
+                .filter(|(name, _)| *name == "motor_evaluation")
+                .map(|(_, fields)| fields.clone())
+                .collect()
+        }
+
+        pub fn assert_complete(spans: &[Vec<String>]) {
+            for fields in spans {
+                for field in ["motor", "score", "was_clamped"] {
+                    assert!(fields.iter().any(|f| f == field), "missing {field}");
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    fn test_span_per_motor_evaluation() {
+        let inputs = vec![
+            MotorInput::Praxis(PraxisInput {
+                proposed: vec!["a".into()],
+                necessary: vec!["a".into()],
+                context_vector: vec![1.0],
+                history_centroid: vec![1.0],
+            }),
+            MotorInput::Meristic(MeristicInput {
+                current_embedding: vec![0.5, 0.5],
+                historical_embeddings: vec![],
+                domain_characteristics: None,
+                exploration_depth: 2,
+                novelty_weight: 0.5,
+            }),
+        ];
+
+        let spans = spans::motor_spans(|| {
+            evaluate_batch_sequential(inputs);
+        });
+        assert_eq!(spans.len(), 2);
+        spans::assert_complete(&spans);
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    fn test_cognitive_cycle_emits_motor_spans() {
+        use crate::cognitive::{CognitiveCycle, MotorContext};
+
+        let cycle = CognitiveCycle::new();
+        let spans = spans::motor_spans(|| {
+            cycle.run(&MotorContext::default());
+        });
+        // Nash is not applicable to the default context
+        assert_eq!(spans.len(), 3);
+        spans::assert_complete(&spans);
+    }
+}
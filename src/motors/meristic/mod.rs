@@ -19,10 +19,13 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - Added was_clamped signaling (v0.2.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Added novelty_weight validation (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - unadjusted_novelty_weight (original input when adjusted)
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+
+use crate::compat::prelude::*;
+use alloc::collections::BTreeMap;
 
 use super::CognitiveMotor;
 
@@ -47,8 +50,17 @@ use super::CognitiveMotor;
 /// - Coherence potential
 /// - Proposals (as metadata, not executable)
 /// - Continuous score [0.0, 1.0]
+///
+/// # Diversity
+/// Proposals closer than `min_proposal_distance` (L2 over the delta
+/// embedding) to one already emitted this cycle are suppressed. This
+/// only shapes the generated set; it never touches incorporation.
 #[derive(Debug, Clone)]
-pub struct MeristicMotor;
+pub struct MeristicMotor {
+    /// Minimum pairwise L2 distance between emitted proposals.
+    /// 0.0 disables filtering.
+    min_proposal_distance: f64,
+}
 
 /// Input for the Meristic Motor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,8 +96,8 @@ pub enum MeristicValidationError {
     InvalidNoveltyWeight { value: String },
 }
 
-impl std::fmt::Display for MeristicValidationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for MeristicValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::EmptyCurrentEmbedding => {
                 write!(f, "Current embedding is empty")
@@ -107,7 +119,7 @@ impl std::fmt::Display for MeristicValidationError {
     }
 }
 
-impl std::error::Error for MeristicValidationError {}
+impl core::error::Error for MeristicValidationError {}
 
 /// A non-binding proposal generated by the Meristic motor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -152,6 +164,9 @@ pub struct MeristicOutput {
     /// Non-binding proposals (appendix).
     pub proposals: Vec<MeristicProposal>,
 
+    /// Proposals dropped for being too close to an emitted one.
+    pub suppressed_proposals: usize,
+
     /// Final motor score: M_M = blend of novelty and coherence.
     pub score: f64,
 
@@ -166,6 +181,10 @@ pub struct MeristicOutput {
 
     /// Actual novelty_weight used (after adjustment).
     pub effective_novelty_weight: f64,
+
+    /// Input novelty_weight before adjustment (if novelty_weight_adjusted is true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unadjusted_novelty_weight: Option<f64>,
 }
 
 impl MeristicOutput {
@@ -178,11 +197,13 @@ impl MeristicOutput {
             coherence_potential: 0.0,
             exploration_coverage: 0.0,
             proposals: Vec::new(),
+            suppressed_proposals: 0,
             score: 0.0,
             was_clamped: false,
             unclamped_score: None,
             novelty_weight_adjusted: false,
             effective_novelty_weight: 0.0,
+            unadjusted_novelty_weight: None,
         }
     }
 }
@@ -190,7 +211,60 @@ impl MeristicOutput {
 impl MeristicMotor {
     /// Creates a new Meristic Motor.
     pub fn new() -> Self {
-        Self
+        Self {
+            min_proposal_distance: 0.0,
+        }
+    }
+
+    /// Sets the minimum pairwise distance between emitted proposals.
+    ///
+    /// Negative or non-finite values disable filtering.
+    pub fn with_min_proposal_distance(mut self, distance: f64) -> Self {
+        self.min_proposal_distance = if distance.is_finite() { distance.max(0.0) } else { 0.0 };
+        self
+    }
+
+    /// Returns the minimum pairwise proposal distance.
+    pub fn min_proposal_distance(&self) -> f64 {
+        self.min_proposal_distance
+    }
+
+    /// Deterministic distance between two proposals (L2 over delta embeddings).
+    ///
+    /// Embeddings of different length are compared as if the shorter one
+    /// were zero-padded.
+    pub fn proposal_distance(a: &MeristicProposal, b: &MeristicProposal) -> f64 {
+        let len = a.delta_embedding.len().max(b.delta_embedding.len());
+        (0..len)
+            .map(|i| {
+                let x = a.delta_embedding.get(i).copied().unwrap_or(0.0);
+                let y = b.delta_embedding.get(i).copied().unwrap_or(0.0);
+                (x - y).powi(2)
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Keeps proposals in generation order, dropping any closer than the
+    /// minimum distance to one already kept. Returns (kept, suppressed).
+    fn enforce_diversity(&self, proposals: Vec<MeristicProposal>) -> (Vec<MeristicProposal>, usize) {
+        if self.min_proposal_distance <= 0.0 {
+            return (proposals, 0);
+        }
+
+        let total = proposals.len();
+        let mut kept: Vec<MeristicProposal> = Vec::with_capacity(total);
+        for proposal in proposals {
+            let distinct = kept
+                .iter()
+                .all(|k| Self::proposal_distance(k, &proposal) >= self.min_proposal_distance);
+            if distinct {
+                kept.push(proposal);
+            }
+        }
+
+        let suppressed = total - kept.len();
+        (kept, suppressed)
     }
 
     /// Validates input constraints.
@@ -336,7 +410,7 @@ impl MeristicMotor {
             // Simple perturbation strategy: emphasize different dimensions
             for (j, d) in delta.iter_mut().enumerate() {
                 // Oscillating pattern based on proposal index
-                let phase = ((i + j) as f64) * std::f64::consts::PI / (dim as f64);
+                let phase = ((i + j) as f64) * core::f64::consts::PI / (dim as f64);
                 *d = phase.sin() * 0.1;
             }
 
@@ -410,13 +484,13 @@ impl CognitiveMotor for MeristicMotor {
             input.current_embedding.len(),
         );
 
-        // Generate proposals
-        let proposals = Self::generate_proposals(
+        // Generate proposals, then drop near-duplicates
+        let (proposals, suppressed_proposals) = self.enforce_diversity(Self::generate_proposals(
             &input.current_embedding,
             novelty_score,
             coherence_potential,
             input.exploration_depth,
-        );
+        ));
 
         // Calculate raw score
         let raw_score = Self::calculate_score(
@@ -441,11 +515,13 @@ impl CognitiveMotor for MeristicMotor {
             coherence_potential,
             exploration_coverage,
             proposals,
+            suppressed_proposals,
             score: final_score,
             was_clamped: needs_clamping,
             unclamped_score: if needs_clamping { Some(raw_score) } else { None },
             novelty_weight_adjusted: weight_needs_adjustment,
             effective_novelty_weight: effective_weight,
+            unadjusted_novelty_weight: weight_needs_adjustment.then_some(raw_weight),
         }
     }
 }
@@ -521,6 +597,7 @@ mod tests {
         assert!(output.valid);
         assert!(output.novelty_weight_adjusted);
         assert!((output.effective_novelty_weight - 1.0).abs() < 1e-10);
+        assert_eq!(output.unadjusted_novelty_weight, Some(1.5));
     }
 
     #[test]
@@ -605,6 +682,50 @@ mod tests {
         assert_eq!(output.proposals.len(), 5);
     }
 
+    fn diversity_input() -> MeristicInput {
+        MeristicInput {
+            current_embedding: vec![1.0, 2.0],
+            historical_embeddings: vec![],
+            domain_characteristics: None,
+            exploration_depth: 5,
+            novelty_weight: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_low_diversity_threshold_keeps_proposals() {
+        let output = MeristicMotor::new().evaluate(&diversity_input());
+        assert_eq!(output.proposals.len(), 5);
+        assert_eq!(output.suppressed_proposals, 0);
+
+        // Proposal 4 repeats proposal 0 exactly in 2 dimensions
+        let output = MeristicMotor::new()
+            .with_min_proposal_distance(1e-9)
+            .evaluate(&diversity_input());
+        assert_eq!(output.proposals.len(), 4);
+        assert_eq!(output.suppressed_proposals, 1);
+    }
+
+    #[test]
+    fn test_high_diversity_threshold_collapses_proposals() {
+        let motor = MeristicMotor::new().with_min_proposal_distance(0.15);
+        let output = motor.evaluate(&diversity_input());
+
+        assert!(output.proposals.len() < 4, "got {}", output.proposals.len());
+        assert_eq!(output.proposals.len() + output.suppressed_proposals, 5);
+        for (i, a) in output.proposals.iter().enumerate() {
+            for b in &output.proposals[i + 1..] {
+                assert!(MeristicMotor::proposal_distance(a, b) >= 0.15);
+            }
+        }
+
+        let output = MeristicMotor::new()
+            .with_min_proposal_distance(10.0)
+            .evaluate(&diversity_input());
+        assert_eq!(output.proposals.len(), 1);
+        assert_eq!(output.suppressed_proposals, 4);
+    }
+
     #[test]
     fn test_motor_determinism() {
         let motor = MeristicMotor::new();
@@ -18,18 +18,30 @@
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - Added Meristic motor
+//! 2026-10-16 - Carlos Eduardo Favini - Builds without `std` (core + alloc + libm)
+//! 2026-10-16 - Carlos Eduardo Favini - ClampReport across the four motors
 //! --------------------------
 
 pub mod praxis;
 pub mod nash;
 pub mod chaos;
 pub mod meristic;
+pub mod dispatch;
+pub mod motor_type;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compat::prelude::*;
 
 // Re-exports
 pub use praxis::{PraxisMotor, PraxisInput, PraxisOutput};
-pub use nash::{NashMotor, NashInput, NashOutput};
-pub use chaos::{ChaosMotor, ChaosInput, ChaosOutput};
+pub use nash::{NashMotor, NashInput, NashOutput, NashInputF64, NashOutputF64};
+pub use chaos::{ChaosMotor, ChaosInput, ChaosOutput, DivergenceMethod, MAX_RESAMPLED_STATES, resample_trajectory};
 pub use meristic::{MeristicMotor, MeristicInput, MeristicOutput, MeristicProposal};
+pub use dispatch::{
+    MotorInput, MotorScore, evaluate_any, evaluate_batch, evaluate_batch_sequential, evaluate_motor,
+};
+pub use motor_type::MotorType;
 
 /// Trait that all cognitive motors must implement.
 ///
@@ -52,24 +64,157 @@ pub trait CognitiveMotor {
     /// # Determinism
     /// Same input MUST produce same output. Always.
     fn evaluate(&self, input: &Self::Input) -> Self::Output;
+
+    /// Evaluates and lifts the in-band validity flag into a `Result`.
+    ///
+    /// Calls `evaluate` unchanged; an invalid output becomes
+    /// `Err(MotorError::InvalidInput)` with the motor's error message.
+    fn try_evaluate(&self, input: &Self::Input) -> Result<Self::Output, MotorError>
+    where
+        Self::Output: MotorOutput,
+    {
+        let output = self.evaluate(input);
+        if output.is_valid() {
+            Ok(output)
+        } else {
+            let message = output.error_message().unwrap_or("invalid input").to_string();
+            Err(MotorError::InvalidInput(message))
+        }
+    }
 }
 
+/// Common view over motor outputs.
+pub trait MotorOutput {
+    /// Whether the input was valid.
+    fn is_valid(&self) -> bool;
+
+    /// Validation error message, if invalid.
+    fn error_message(&self) -> Option<&str>;
+
+    /// The motor score [0.0, 1.0].
+    fn score(&self) -> f64;
+
+    /// Whether the score was clamped to [0, 1].
+    fn was_clamped(&self) -> bool;
+}
+
+impl MotorOutput for PraxisOutput {
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    fn error_message(&self) -> Option<&str> {
+        None
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+
+    fn was_clamped(&self) -> bool {
+        self.was_clamped
+    }
+}
+
+impl MotorOutput for NashOutput {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn error_message(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+
+    fn was_clamped(&self) -> bool {
+        self.was_clamped
+    }
+}
+
+impl MotorOutput for ChaosOutput {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn error_message(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+
+    fn was_clamped(&self) -> bool {
+        self.was_clamped
+    }
+}
+
+impl MotorOutput for MeristicOutput {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    fn error_message(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    fn score(&self) -> f64 {
+        self.score
+    }
+
+    fn was_clamped(&self) -> bool {
+        self.was_clamped
+    }
+}
+
+/// Error returned by `CognitiveMotor::try_evaluate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MotorError {
+    /// The motor rejected its input.
+    InvalidInput(String),
+}
+
+impl core::fmt::Display for MotorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MotorError::InvalidInput(msg) => write!(f, "Invalid motor input: {}", msg),
+        }
+    }
+}
+
+impl core::error::Error for MotorError {}
+
 /// Motor evaluation result with score and metadata.
-#[derive(Debug, Clone, Copy)]
+///
+/// Uniform cross-motor result. The `from_*` constructors keep each motor's
+/// diagnostics (clamping, validation error) instead of flattening them.
+#[derive(Debug, Clone, PartialEq)]
 pub struct MotorResult {
     /// The motor's score [0.0, 1.0].
     pub score: f64,
 
     /// Whether the evaluation completed successfully.
     pub valid: bool,
+
+    /// Whether the score was clamped to [0, 1].
+    pub was_clamped: bool,
+
+    /// Why the evaluation is invalid (if known).
+    pub reason: Option<String>,
 }
 
 impl MotorResult {
     /// Creates a valid result with the given score.
     pub fn valid(score: f64) -> Self {
+        let clamped = score.clamp(0.0, 1.0);
         Self {
-            score: score.clamp(0.0, 1.0),
+            score: clamped,
             valid: true,
+            was_clamped: clamped != score,
+            reason: None,
         }
     }
 
@@ -78,6 +223,290 @@ impl MotorResult {
         Self {
             score: 0.0,
             valid: false,
+            was_clamped: false,
+            reason: None,
+        }
+    }
+
+    /// Creates an invalid result (score = 0) with a reason.
+    pub fn invalid_with_reason(reason: impl Into<String>) -> Self {
+        Self {
+            reason: Some(reason.into()),
+            ..Self::invalid()
+        }
+    }
+
+    /// Maps a Praxis output (always valid; no validation error).
+    pub fn from_praxis(output: &PraxisOutput) -> Self {
+        Self {
+            score: output.score,
+            valid: true,
+            was_clamped: output.was_clamped,
+            reason: None,
+        }
+    }
+
+    /// Maps a Nash output, preserving clamping and validation error.
+    pub fn from_nash(output: &NashOutput) -> Self {
+        Self {
+            score: output.score,
+            valid: output.valid,
+            was_clamped: output.was_clamped,
+            reason: output.validation_error.clone(),
+        }
+    }
+
+    /// Maps a Chaos output, preserving clamping and validation error.
+    pub fn from_chaos(output: &ChaosOutput) -> Self {
+        Self {
+            score: output.score,
+            valid: output.valid,
+            was_clamped: output.was_clamped,
+            reason: output.validation_error.clone(),
+        }
+    }
+
+    /// Maps a Meristic output, preserving clamping and validation error.
+    pub fn from_meristic(output: &MeristicOutput) -> Self {
+        Self {
+            score: output.score,
+            valid: output.valid,
+            was_clamped: output.was_clamped,
+            reason: output.validation_error.clone(),
+        }
+    }
+}
+
+/// Clamping of one motor's score.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClampEntry {
+    /// Whether the score was clamped to [0, 1].
+    pub was_clamped: bool,
+
+    /// Score before clamping (equals `final_score` if not clamped).
+    pub original: f64,
+
+    /// Score the motor reported.
+    pub final_score: f64,
+}
+
+impl ClampEntry {
+    /// Entry from a motor's reported score and clamp diagnostics.
+    pub fn new(final_score: f64, was_clamped: bool, unclamped_score: Option<f64>) -> Self {
+        Self {
+            was_clamped,
+            original: unclamped_score.unwrap_or(final_score),
+            final_score,
+        }
+    }
+
+    /// Entry for a motor that was not clamped.
+    pub fn unclamped(score: f64) -> Self {
+        Self::new(score, false, None)
+    }
+}
+
+/// Numerical interventions (clamping) across one pass of the four motors.
+///
+/// Entries are in canonical order [Praxis, Nash, Chaos, Meristic]; index
+/// with `MotorType::index` or use `entry`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClampReport {
+    /// Per-motor clamp entries.
+    pub entries: [ClampEntry; 4],
+}
+
+impl ClampReport {
+    /// Collects clamp diagnostics from the four motor outputs.
+    ///
+    /// `nash` is `None` when Nash was not applicable; it is then recorded
+    /// as the unclamped neutral score 1.0.
+    pub fn from_outputs(
+        praxis: &PraxisOutput,
+        nash: Option<&NashOutput>,
+        chaos: &ChaosOutput,
+        meristic: &MeristicOutput,
+    ) -> Self {
+        Self {
+            entries: [
+                ClampEntry::new(praxis.score, praxis.was_clamped, praxis.unclamped_score),
+                nash.map_or(ClampEntry::unclamped(1.0), |n| {
+                    ClampEntry::new(n.score, n.was_clamped, n.unclamped_score)
+                }),
+                ClampEntry::new(chaos.score, chaos.was_clamped, chaos.unclamped_score),
+                ClampEntry::new(meristic.score, meristic.was_clamped, meristic.unclamped_score),
+            ],
         }
     }
+
+    /// Entry for one motor.
+    pub fn entry(&self, motor: MotorType) -> &ClampEntry {
+        &self.entries[motor.index()]
+    }
+
+    /// True if any motor's score was clamped.
+    pub fn any_clamped(&self) -> bool {
+        self.entries.iter().any(|e| e.was_clamped)
+    }
+
+    /// Motors whose score was clamped, in canonical order.
+    pub fn clamped_motors(&self) -> Vec<MotorType> {
+        MotorType::all()
+            .into_iter()
+            .filter(|m| self.entry(*m).was_clamped)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nash_output(valid: bool, error: Option<&str>, was_clamped: bool) -> NashOutput {
+        NashOutput {
+            valid,
+            validation_error: error.map(String::from),
+            epsilon_vector: Vec::new(),
+            eta_equilibrium: 1.0,
+            score: if valid { 1.0 } else { 0.0 },
+            was_clamped,
+            unclamped_score: if was_clamped { Some(1.2) } else { None },
+            degenerate_players: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_legacy_constructors() {
+        let r = MotorResult::valid(1.5);
+        assert_eq!(r.score, 1.0);
+        assert!(r.valid);
+        assert!(r.was_clamped);
+
+        let r = MotorResult::invalid();
+        assert!(!r.valid);
+        assert_eq!(r.reason, None);
+    }
+
+    #[test]
+    fn test_from_nash_preserves_clamping() {
+        let r = MotorResult::from_nash(&nash_output(true, None, true));
+        assert!(r.valid);
+        assert!(r.was_clamped);
+        assert_eq!(r.score, 1.0);
+    }
+
+    #[test]
+    fn test_from_nash_preserves_reason() {
+        let r = MotorResult::from_nash(&nash_output(false, Some("Empty payoff matrix"), false));
+        assert!(!r.valid);
+        assert_eq!(r.reason.as_deref(), Some("Empty payoff matrix"));
+    }
+
+    #[test]
+    fn test_try_evaluate_nash() {
+        let motor = NashMotor::new();
+        let invalid = NashInput {
+            num_players: 2,
+            action_sizes: vec![],
+            payoffs: vec![],
+            strategies: vec![],
+            scale: 100,
+        };
+        assert!(matches!(motor.try_evaluate(&invalid), Err(MotorError::InvalidInput(_))));
+
+        let valid = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+        assert!(motor.try_evaluate(&valid).is_ok());
+    }
+
+    #[test]
+    fn test_try_evaluate_chaos() {
+        let motor = ChaosMotor::new();
+        let invalid = ChaosInput {
+            reference_trajectory: vec![],
+            perturbed_trajectory: vec![],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+        assert!(motor.try_evaluate(&invalid).is_err());
+
+        let valid = ChaosInput {
+            reference_trajectory: vec![vec![0.1], vec![0.15], vec![0.12]],
+            perturbed_trajectory: vec![vec![0.11], vec![0.16], vec![0.13]],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+        assert!(motor.try_evaluate(&valid).is_ok());
+    }
+
+    #[test]
+    fn test_from_chaos_invalid_input() {
+        let motor = ChaosMotor::new();
+        let input = ChaosInput {
+            reference_trajectory: vec![],
+            perturbed_trajectory: vec![],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        };
+        let r = MotorResult::from_chaos(&motor.evaluate(&input));
+        assert!(!r.valid);
+        assert!(r.reason.is_some());
+    }
+
+    fn empty_chaos() -> ChaosOutput {
+        ChaosMotor::new().evaluate(&ChaosInput {
+            reference_trajectory: vec![],
+            perturbed_trajectory: vec![],
+            delta_0: 0.01,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        })
+    }
+
+    #[test]
+    fn test_clamp_report_only_chaos() {
+        let praxis = PraxisMotor::new().evaluate(&PraxisInput {
+            proposed: vec!["a".into()],
+            necessary: vec!["a".into()],
+            context_vector: vec![1.0],
+            history_centroid: vec![1.0],
+        });
+        let nash = nash_output(true, None, false);
+        let mut chaos = empty_chaos();
+        chaos.score = 1.0;
+        chaos.was_clamped = true;
+        chaos.unclamped_score = Some(1.3);
+        let meristic = MeristicMotor::new().evaluate(&MeristicInput {
+            current_embedding: vec![0.5],
+            historical_embeddings: vec![],
+            domain_characteristics: None,
+            exploration_depth: 1,
+            novelty_weight: 0.5,
+        });
+        assert!(!praxis.was_clamped && !meristic.was_clamped);
+
+        let report = ClampReport::from_outputs(&praxis, Some(&nash), &chaos, &meristic);
+        assert!(report.any_clamped());
+        assert_eq!(report.clamped_motors(), vec![MotorType::Chaos]);
+        let entry = report.entry(MotorType::Chaos);
+        assert_eq!((entry.original, entry.final_score), (1.3, 1.0));
+        assert_eq!(report.entry(MotorType::Praxis).original, praxis.score);
+
+        let report = ClampReport::from_outputs(&praxis, None, &empty_chaos(), &meristic);
+        assert!(!report.any_clamped());
+        assert!(report.clamped_motors().is_empty());
+        assert_eq!(report.entry(MotorType::Nash).final_score, 1.0);
+    }
 }
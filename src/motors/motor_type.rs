@@ -0,0 +1,125 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Motor Type
+//! Description: Canonical identifier for the four cognitive motors.
+//!              Single source of truth for motor order, index and name.
+//!              Re-exported by `competition`; `replay::MotorType` converts
+//!              to and from it.
+//! Layer: Community
+//! Dependencies: serde
+//! Affected Components: competition, replay, math/craft, motors/dispatch
+//! --------------------------
+
+use serde::{Deserialize, Serialize};
+
+/// Identifier for the four cognitive motors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MotorType {
+    /// Praxeological motor (M_P)
+    Praxis,
+    /// Nash equilibrium motor (M_N)
+    Nash,
+    /// Chaos/stability motor (M_C)
+    Chaos,
+    /// Meristic generation motor (M_M)
+    Meristic,
+}
+
+impl MotorType {
+    /// Returns all motor types in canonical order.
+    pub fn all() -> [MotorType; 4] {
+        [Self::Praxis, Self::Nash, Self::Chaos, Self::Meristic]
+    }
+
+    /// Returns the index of this motor (0-3).
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Praxis => 0,
+            Self::Nash => 1,
+            Self::Chaos => 2,
+            Self::Meristic => 3,
+        }
+    }
+
+    /// Returns the motor type from an index.
+    pub fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Self::Praxis),
+            1 => Some(Self::Nash),
+            2 => Some(Self::Chaos),
+            3 => Some(Self::Meristic),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the motor.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Praxis => "Praxis",
+            Self::Nash => "Nash",
+            Self::Chaos => "Chaos",
+            Self::Meristic => "Meristic",
+        }
+    }
+}
+
+impl PartialOrd for MotorType {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MotorType {
+    /// Canonical order, by `index()`: Praxis < Nash < Chaos < Meristic.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+impl core::fmt::Display for MotorType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_roundtrip() {
+        for motor in MotorType::all() {
+            assert_eq!(MotorType::from_index(motor.index()), Some(motor));
+        }
+        assert_eq!(MotorType::from_index(4), None);
+    }
+
+    #[test]
+    fn test_ord_is_canonical() {
+        let mut motors = vec![
+            MotorType::Meristic,
+            MotorType::Nash,
+            MotorType::Chaos,
+            MotorType::Praxis,
+        ];
+        motors.sort();
+        assert_eq!(motors, MotorType::all().to_vec());
+
+        let map: std::collections::BTreeMap<MotorType, usize> = MotorType::all()
+            .iter()
+            .rev()
+            .map(|&m| (m, m.index()))
+            .collect();
+        let keys: Vec<MotorType> = map.keys().copied().collect();
+        assert_eq!(keys, MotorType::all().to_vec());
+        assert_eq!(map[&MotorType::Chaos], 2);
+    }
+
+    #[test]
+    fn test_display_matches_name() {
+        for motor in MotorType::all() {
+            assert_eq!(motor.to_string(), motor.name());
+        }
+    }
+}
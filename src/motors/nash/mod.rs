@@ -20,6 +20,7 @@
 //! - MAX_PLAYERS = 10
 //! - MAX_ACTIONS = 100
 //! - MAX_PAYOFF_MAGNITUDE = 10^12
+//! - MAX_PURE_PROFILES = 2^20 (pure equilibrium enumeration only)
 //!
 //! --------------------------
 //! CHANGE LOG
@@ -28,10 +29,16 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Added dimensional validation
 //! 2025-01-02 - Carlos Eduardo Favini - Added was_clamped signaling (v0.2.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Added overflow protection (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Pure-strategy equilibrium enumeration
+//! 2026-10-16 - Carlos Eduardo Favini - Real-valued payoffs via fixed-point NashInputF64
+//! 2026-10-16 - Carlos Eduardo Favini - Observational best-response step
+//! 2026-10-16 - Carlos Eduardo Favini - degenerate_players (single-action) warning
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
+use crate::compat::prelude::*;
+
 use super::CognitiveMotor;
 
 /// Maximum number of players to prevent overflow.
@@ -43,6 +50,9 @@ pub const MAX_ACTIONS_PER_PLAYER: usize = 100;
 /// Maximum payoff magnitude to prevent overflow.
 pub const MAX_PAYOFF_MAGNITUDE: i64 = 1_000_000_000_000; // 10^12
 
+/// Maximum joint profiles enumerated by `find_pure_equilibria`.
+pub const MAX_PURE_PROFILES: usize = 1 << 20;
+
 /// Nash Motor (M_N).
 ///
 /// Evaluates collective equilibrium of actions using Nash equilibrium
@@ -90,6 +100,84 @@ pub struct NashInput {
     pub scale: u64,
 }
 
+impl NashInput {
+    /// Players with a single action (`action_sizes[i] == 1`).
+    ///
+    /// Such a player cannot deviate: its strategy is fixed and its
+    /// epsilon is always 0. Valid, but usually passed by mistake.
+    pub fn degenerate_players(&self) -> Vec<usize> {
+        self.action_sizes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &size)| size == 1)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Input for the Nash Motor with real-valued payoffs.
+///
+/// Payoffs are converted to fixed-point `i64` by multiplying with
+/// `payoff_scale` and rounding; the scaled values must stay within
+/// `MAX_PAYOFF_MAGNITUDE`. Strategies use the same integer
+/// representation as `NashInput`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NashInputF64 {
+    /// Number of players/agents in the game.
+    pub num_players: usize,
+
+    /// Number of actions available to each player.
+    pub action_sizes: Vec<usize>,
+
+    /// Real-valued payoff matrices for each player (flattened).
+    pub payoffs: Vec<Vec<f64>>,
+
+    /// Current strategy profile (scaled integers, sum = scale).
+    pub strategies: Vec<Vec<u64>>,
+
+    /// Scale factor for integer probability representation.
+    pub scale: u64,
+
+    /// Fixed-point factor: payoff units per 1.0. Must be finite and > 0.
+    pub payoff_scale: f64,
+}
+
+impl NashInputF64 {
+    /// Converts to the fixed-point `NashInput` evaluated by the motor.
+    ///
+    /// Only the payoff conversion is checked here; dimensions and limits
+    /// are validated by the motor as for any `NashInput`.
+    pub fn to_fixed_point(&self) -> Result<NashInput, NashValidationError> {
+        if !self.payoff_scale.is_finite() || self.payoff_scale <= 0.0 {
+            return Err(NashValidationError::InvalidPayoffScale);
+        }
+
+        let mut payoffs = Vec::with_capacity(self.payoffs.len());
+        for (player, player_payoffs) in self.payoffs.iter().enumerate() {
+            let mut scaled = Vec::with_capacity(player_payoffs.len());
+            for (profile, &payoff) in player_payoffs.iter().enumerate() {
+                if !payoff.is_finite() {
+                    return Err(NashValidationError::NonFinitePayoff { player, profile });
+                }
+                let fixed = (payoff * self.payoff_scale).round();
+                if !fixed.is_finite() || fixed.abs() > MAX_PAYOFF_MAGNITUDE as f64 {
+                    return Err(NashValidationError::ScaledPayoffOverflow { player, profile });
+                }
+                scaled.push(fixed as i64);
+            }
+            payoffs.push(scaled);
+        }
+
+        Ok(NashInput {
+            num_players: self.num_players,
+            action_sizes: self.action_sizes.clone(),
+            payoffs,
+            strategies: self.strategies.clone(),
+            scale: self.scale,
+        })
+    }
+}
+
 /// Validation error for Nash input.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NashValidationError {
@@ -115,10 +203,18 @@ pub enum NashValidationError {
     StrategyDimensionMismatch { player: usize, expected: usize, got: usize },
     /// Each action_size must be > 0.
     ZeroActionSize { player: usize },
+    /// Joint profile space too large to enumerate.
+    ProfileSpaceTooLarge { max: usize },
+    /// payoff_scale must be finite and > 0.
+    InvalidPayoffScale,
+    /// Real-valued payoff is NaN or infinite.
+    NonFinitePayoff { player: usize, profile: usize },
+    /// Real-valued payoff exceeds MAX_PAYOFF_MAGNITUDE once scaled.
+    ScaledPayoffOverflow { player: usize, profile: usize },
 }
 
-impl std::fmt::Display for NashValidationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for NashValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::ZeroScale => write!(f, "Scale must be > 0"),
             Self::ZeroPlayers => write!(f, "Number of players must be > 0"),
@@ -149,11 +245,21 @@ impl std::fmt::Display for NashValidationError {
             Self::ZeroActionSize { player } => {
                 write!(f, "action_sizes[{}] must be > 0", player)
             }
+            Self::ProfileSpaceTooLarge { max } => {
+                write!(f, "Joint profile space exceeds {} profiles", max)
+            }
+            Self::InvalidPayoffScale => write!(f, "payoff_scale must be finite and > 0"),
+            Self::NonFinitePayoff { player, profile } => {
+                write!(f, "Payoff at [{}][{}] is not finite", player, profile)
+            }
+            Self::ScaledPayoffOverflow { player, profile } => {
+                write!(f, "Scaled payoff at [{}][{}] exceeds ±{}", player, profile, MAX_PAYOFF_MAGNITUDE)
+            }
         }
     }
 }
 
-impl std::error::Error for NashValidationError {}
+impl core::error::Error for NashValidationError {}
 
 /// Output from the Nash Motor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -181,6 +287,26 @@ pub struct NashOutput {
 
     /// Original score before clamping (if was_clamped is true).
     pub unclamped_score: Option<f64>,
+
+    /// Players with a single action, who cannot deviate.
+    /// Informative only; does not affect the score. Omitted from the
+    /// serialization when empty, so output hashes of other games are
+    /// unchanged.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub degenerate_players: Vec<usize>,
+}
+
+/// Output from the Nash Motor for a real-valued game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NashOutputF64 {
+    /// Output of the fixed-point evaluation (payoff units × payoff_scale).
+    pub fixed: NashOutput,
+
+    /// Epsilon vector scaled back to the original payoff units.
+    pub epsilon_vector: Vec<f64>,
+
+    /// Fixed-point factor used for the conversion.
+    pub payoff_scale: f64,
 }
 
 impl NashOutput {
@@ -194,6 +320,7 @@ impl NashOutput {
             score: 0.0,
             was_clamped: false,
             unclamped_score: None,
+            degenerate_players: Vec::new(),
         }
     }
 
@@ -214,6 +341,7 @@ impl NashOutput {
             score: final_score,
             was_clamped: needs_clamping,
             unclamped_score: if needs_clamping { Some(eta_equilibrium) } else { None },
+            degenerate_players: Vec::new(),
         }
     }
 }
@@ -337,6 +465,14 @@ impl NashMotor {
         profile
     }
 
+    /// Converts a joint action profile to its strategy profile index.
+    fn profile_to_index(action_sizes: &[usize], profile: &[usize]) -> usize {
+        profile
+            .iter()
+            .zip(action_sizes.iter())
+            .fold(0, |index, (&action, &size)| index * size + action)
+    }
+
     /// Calculates total number of joint action profiles.
     fn total_profiles(action_sizes: &[usize]) -> usize {
         action_sizes.iter().product()
@@ -463,6 +599,134 @@ impl NashMotor {
         eps
     }
 
+    /// Enumerates all pure-strategy Nash equilibria of the game.
+    ///
+    /// A joint action profile is an equilibrium when no player can strictly
+    /// improve their payoff by deviating unilaterally. Profiles are returned
+    /// in index order. The current `strategies` are validated but ignored.
+    ///
+    /// # Errors
+    /// Returns the validation error for invalid input, or
+    /// `ProfileSpaceTooLarge` if more than `MAX_PURE_PROFILES` profiles
+    /// would need to be enumerated.
+    pub fn find_pure_equilibria(input: &NashInput) -> Result<Vec<Vec<usize>>, NashValidationError> {
+        let total = input
+            .action_sizes
+            .iter()
+            .try_fold(1usize, |acc, &size| acc.checked_mul(size))
+            .filter(|&total| total <= MAX_PURE_PROFILES)
+            .ok_or(NashValidationError::ProfileSpaceTooLarge { max: MAX_PURE_PROFILES })?;
+
+        Self::validate(input)?;
+
+        let sizes = &input.action_sizes;
+        let mut equilibria = Vec::new();
+
+        for idx in 0..total {
+            let mut profile = Self::index_to_profile(sizes, idx);
+
+            let is_equilibrium = (0..input.num_players).all(|player| {
+                let current = input.payoffs[player][idx];
+                let played = profile[player];
+
+                let stable = (0..sizes[player]).filter(|&a| a != played).all(|a| {
+                    profile[player] = a;
+                    let deviation = input.payoffs[player][Self::profile_to_index(sizes, &profile)];
+                    deviation <= current
+                });
+
+                profile[player] = played;
+                stable
+            });
+
+            if is_equilibrium {
+                equilibria.push(profile);
+            }
+        }
+
+        Ok(equilibria)
+    }
+
+    /// Projects one round of best-response dynamics (observation only).
+    ///
+    /// Every player simultaneously finds its best pure response to the
+    /// current profile (lowest action index on ties) and moves half of
+    /// the weight of every other action onto it, rounding the moved
+    /// amount up so the step always reaches a pure strategy eventually.
+    /// Weights stay in the integer `scale` representation and each
+    /// player's total weight is preserved. A player whose played actions
+    /// are all best responses keeps its strategy, so equilibria are fixed
+    /// points.
+    ///
+    /// This describes where the dynamics would move the strategies; it
+    /// is not a recommendation and the motor acts on nothing.
+    ///
+    /// # Errors
+    /// Returns the validation error for invalid input (same overflow
+    /// guards as `evaluate`).
+    pub fn best_response_step(input: &NashInput) -> Result<Vec<Vec<u64>>, NashValidationError> {
+        Self::validate(input)?;
+
+        let strategies: Vec<&[u64]> = input.strategies.iter().map(|s| s.as_slice()).collect();
+        let sizes = &input.action_sizes;
+        let mut next = input.strategies.clone();
+
+        for (player, next_strategy) in next.iter_mut().enumerate() {
+            let payoffs = &input.payoffs[player];
+            let utilities: Vec<i64> = (0..sizes[player])
+                .map(|a| {
+                    Self::expected_utility_pure_action(player, a, payoffs, sizes, &strategies, input.scale)
+                })
+                .collect();
+
+            // Already a best response if every played action is optimal
+            let best = utilities.iter().copied().max().unwrap_or(i64::MIN);
+            let settled = next_strategy
+                .iter()
+                .zip(&utilities)
+                .all(|(&weight, &u)| weight == 0 || u == best);
+            if settled {
+                continue;
+            }
+            let best_action = utilities.iter().position(|&u| u == best).unwrap_or(0);
+
+            let mut moved = 0u64;
+            for (action, weight) in next_strategy.iter_mut().enumerate() {
+                if action != best_action {
+                    let shift = *weight - *weight / 2;
+                    *weight -= shift;
+                    moved = moved.saturating_add(shift);
+                }
+            }
+            next_strategy[best_action] = next_strategy[best_action].saturating_add(moved);
+        }
+
+        Ok(next)
+    }
+
+    /// Evaluates a real-valued game through its fixed-point equivalent.
+    ///
+    /// Runs the same overflow-checked computation as `evaluate`, then
+    /// divides the epsilon vector by `payoff_scale`. η_eq is invariant
+    /// under payoff scaling, so the score needs no conversion.
+    pub fn evaluate_f64(&self, input: &NashInputF64) -> NashOutputF64 {
+        let fixed = match input.to_fixed_point() {
+            Ok(fixed_input) => self.evaluate(&fixed_input),
+            Err(e) => NashOutput::invalid(e),
+        };
+        let epsilon_vector = fixed
+            .epsilon_vector
+            .iter()
+            .map(|&eps| eps as f64 / input.payoff_scale)
+            .collect();
+
+        NashOutputF64 {
+            fixed,
+            epsilon_vector,
+            payoff_scale: input.payoff_scale,
+        }
+    }
+
     /// Calculates equilibrium quality from epsilon vector.
     /// η_eq = 1 / (1 + d̄)
     fn calculate_eta_equilibrium(epsilon: &[u64], u_max: u64) -> f64 {
@@ -522,7 +786,9 @@ impl CognitiveMotor for NashMotor {
         // Calculate equilibrium quality
         let eta_equilibrium = Self::calculate_eta_equilibrium(&epsilon_vector, u_max);
 
-        NashOutput::valid(epsilon_vector, eta_equilibrium)
+        let mut output = NashOutput::valid(epsilon_vector, eta_equilibrium);
+        output.degenerate_players = input.degenerate_players();
+        output
     }
 }
 
@@ -530,6 +796,99 @@ impl CognitiveMotor for NashMotor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_single_action_player_listed_as_degenerate() {
+        let motor = NashMotor::new();
+        // Player 1 has one action; the others choose between two or three.
+        let input = NashInput {
+            num_players: 3,
+            action_sizes: vec![2, 1, 3],
+            payoffs: vec![
+                vec![1, 0, 0, 1, 2, 0],
+                vec![0; 6],
+                vec![1, 1, 0, 0, 2, 2],
+            ],
+            strategies: vec![vec![50, 50], vec![100], vec![30, 30, 40]],
+            scale: 100,
+        };
+
+        let output = motor.evaluate(&input);
+        assert!(output.valid);
+        assert_eq!(output.degenerate_players, vec![1]);
+        assert_eq!(output.epsilon_vector[1], 0);
+
+        let square = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+        assert!(motor.evaluate(&square).degenerate_players.is_empty());
+    }
+
+    #[test]
+    fn test_f64_game_matches_hand_scaled() {
+        let motor = NashMotor::new();
+        let float_input = NashInputF64 {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![3.25, 0.0, 5.5, 1.125], vec![3.25, 5.5, 0.0, 1.125]],
+            strategies: vec![vec![30, 70], vec![60, 40]],
+            scale: 100,
+            payoff_scale: 1000.0,
+        };
+        let int_input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![3250, 0, 5500, 1125], vec![3250, 5500, 0, 1125]],
+            strategies: vec![vec![30, 70], vec![60, 40]],
+            scale: 100,
+        };
+
+        let float_out = motor.evaluate_f64(&float_input);
+        let int_out = motor.evaluate(&int_input);
+
+        assert!(float_out.fixed.valid);
+        assert_eq!(float_out.fixed.epsilon_vector, int_out.epsilon_vector);
+        assert_eq!(float_out.fixed.score, int_out.score);
+        assert!(int_out.epsilon_vector.iter().any(|&e| e > 0));
+        for (f, &i) in float_out.epsilon_vector.iter().zip(&int_out.epsilon_vector) {
+            assert!((f - i as f64 / 1000.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_f64_rejects_non_finite_and_overflow() {
+        let motor = NashMotor::new();
+        let mut input = NashInputF64 {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1.0, 0.0, 0.0, 1.0], vec![1.0, 0.0, f64::NAN, 1.0]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+            payoff_scale: 1000.0,
+        };
+        assert_eq!(
+            input.to_fixed_point().unwrap_err(),
+            NashValidationError::NonFinitePayoff { player: 1, profile: 2 }
+        );
+        assert!(!motor.evaluate_f64(&input).fixed.valid);
+
+        input.payoffs[1][2] = f64::INFINITY;
+        assert!(input.to_fixed_point().is_err());
+
+        input.payoffs[1][2] = 1e10; // 1e13 once scaled
+        assert_eq!(
+            input.to_fixed_point().unwrap_err(),
+            NashValidationError::ScaledPayoffOverflow { player: 1, profile: 2 }
+        );
+
+        input.payoffs[1][2] = 0.0;
+        input.payoff_scale = 0.0;
+        assert_eq!(input.to_fixed_point().unwrap_err(), NashValidationError::InvalidPayoffScale);
+    }
+
     #[test]
     fn test_validation_zero_scale() {
         let motor = NashMotor::new();
@@ -692,4 +1051,118 @@ mod tests {
         assert!(output.score >= 0.0);
         assert!(output.score <= 1.0);
     }
+
+    #[test]
+    fn test_pure_equilibria_coordination() {
+        let input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+
+        let equilibria = NashMotor::find_pure_equilibria(&input).unwrap();
+        assert_eq!(equilibria, vec![vec![0, 0], vec![1, 1]]);
+    }
+
+    #[test]
+    fn test_pure_equilibria_prisoners_dilemma() {
+        let input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![-1, -3, 0, -2], vec![-1, 0, -3, -2]],
+            strategies: vec![vec![100, 0], vec![100, 0]],
+            scale: 100,
+        };
+
+        // (Defect, Defect) is the unique pure equilibrium
+        let equilibria = NashMotor::find_pure_equilibria(&input).unwrap();
+        assert_eq!(equilibria, vec![vec![1, 1]]);
+    }
+
+    #[test]
+    fn test_pure_equilibria_none_in_matching_pennies() {
+        let input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, -1, -1, 1], vec![-1, 1, 1, -1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+
+        assert!(NashMotor::find_pure_equilibria(&input).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pure_equilibria_profile_space_guard() {
+        // 100^10 profiles overflows usize; must short-circuit before validation
+        let input = NashInput {
+            num_players: MAX_PLAYERS,
+            action_sizes: vec![MAX_ACTIONS_PER_PLAYER; MAX_PLAYERS],
+            payoffs: vec![],
+            strategies: vec![],
+            scale: 100,
+        };
+
+        assert_eq!(
+            NashMotor::find_pure_equilibria(&input),
+            Err(NashValidationError::ProfileSpaceTooLarge { max: MAX_PURE_PROFILES })
+        );
+
+        let invalid = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+        assert!(NashMotor::find_pure_equilibria(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_best_response_step_prisoners_dilemma() {
+        let mut input = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            // Payoffs x100 so expected utilities are exact integers
+            payoffs: vec![vec![-100, -300, 0, -200], vec![-100, 0, -300, -200]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+
+        // Defect is the best response: weight moves from C to D
+        let step = NashMotor::best_response_step(&input).unwrap();
+        assert_eq!(step, vec![vec![25, 75], vec![25, 75]]);
+
+        // Repeated steps reach all-Defect, which is a fixed point
+        for _ in 0..10 {
+            input.strategies = NashMotor::best_response_step(&input).unwrap();
+        }
+        assert_eq!(input.strategies, vec![vec![0, 100], vec![0, 100]]);
+        assert_eq!(NashMotor::best_response_step(&input).unwrap(), input.strategies);
+
+        // Observation only: the input is not modified and the score improves
+        let before = NashMotor::new().evaluate(&NashInput {
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            ..input.clone()
+        });
+        let after = NashMotor::new().evaluate(&NashInput { strategies: step, ..input });
+        assert!(after.score > before.score);
+    }
+
+    #[test]
+    fn test_best_response_step_validates() {
+        let invalid = NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![MAX_PAYOFF_MAGNITUDE + 1, 0, 0, 0], vec![0; 4]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 100,
+        };
+        assert!(matches!(
+            NashMotor::best_response_step(&invalid),
+            Err(NashValidationError::PayoffTooLarge { .. })
+        ));
+    }
 }
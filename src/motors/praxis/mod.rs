@@ -22,6 +22,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::compat::prelude::*;
+
 use super::CognitiveMotor;
 
 /// Praxeological Motor (M_P).
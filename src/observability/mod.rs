@@ -33,11 +33,14 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Trackers keep history in BoundedHistory (O(1) eviction)
+//! 2026-10-16 - Carlos Eduardo Favini - ProgressTracker::is_stuck_windowed
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
 use crate::competition::MotorCompetition;
+use crate::core_types::BoundedHistory;
 use crate::sensory::state::{PerceptualState, StateTransition};
 
 /// Health indicators for the cognitive system.
@@ -121,6 +124,10 @@ pub struct ProgressTracker {
 
     /// Number of level regressions
     pub level_regressions: usize,
+
+    /// Whether each recent transition advanced a level (oldest first)
+    #[serde(default)]
+    pub recent_advances: BoundedHistory<bool, 100>,
 }
 
 impl Default for ProgressTracker {
@@ -132,6 +139,7 @@ impl Default for ProgressTracker {
             total_processing_time_ns: 0,
             level_advances: 0,
             level_regressions: 0,
+            recent_advances: BoundedHistory::new(),
         }
     }
 }
@@ -146,6 +154,7 @@ impl ProgressTracker {
     pub fn record_transition(&mut self, transition: &StateTransition) {
         let from_level = transition.from.level();
         let to_level = transition.to.level();
+        self.recent_advances.push(to_level > from_level);
 
         if to_level > from_level {
             self.level_advances += 1;
@@ -176,6 +185,27 @@ impl ProgressTracker {
     pub fn is_stuck(&self, threshold: usize) -> bool {
         self.cycles_without_progress >= threshold
     }
+
+    /// Returns true if fewer than `min_advances` of the last `window`
+    /// transitions advanced a level.
+    ///
+    /// Unlike `is_stuck`, a single lateral step does not reset anything,
+    /// so occasional noise between advances is tolerated. The window is
+    /// capped at the retained history (100 transitions); with fewer
+    /// transitions recorded than the window, the system is not flagged.
+    pub fn is_stuck_windowed(&self, window: usize, min_advances: usize) -> bool {
+        let window = window.min(self.recent_advances.capacity());
+        if window == 0 || self.recent_advances.len() < window {
+            return false;
+        }
+        let advances = self
+            .recent_advances
+            .iter()
+            .skip(self.recent_advances.len() - window)
+            .filter(|&&advanced| advanced)
+            .count();
+        advances < min_advances
+    }
 }
 
 /// Divergence tracking between cognitive components.
@@ -185,7 +215,7 @@ pub struct DivergenceTracker {
     pub motor_divergence: f64,
 
     /// Historical divergence values
-    pub divergence_history: Vec<f64>,
+    pub divergence_history: BoundedHistory<f64, 100>,
 
     /// Average divergence over history
     pub average_divergence: f64,
@@ -198,7 +228,7 @@ impl Default for DivergenceTracker {
     fn default() -> Self {
         Self {
             motor_divergence: 0.0,
-            divergence_history: Vec::new(),
+            divergence_history: BoundedHistory::new(),
             average_divergence: 0.0,
             max_divergence: 0.0,
         }
@@ -216,13 +246,9 @@ impl DivergenceTracker {
         // Divergence is inverse of consensus
         self.motor_divergence = 1.0 - competition.consensus_score;
 
+        // Keeps only the last 100 entries
         self.divergence_history.push(self.motor_divergence);
 
-        // Keep only last 100 entries
-        if self.divergence_history.len() > 100 {
-            self.divergence_history.remove(0);
-        }
-
         // Update statistics
         if self.motor_divergence > self.max_divergence {
             self.max_divergence = self.motor_divergence;
@@ -260,7 +286,7 @@ impl DivergenceTracker {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OscillationDetector {
     /// Recent state sequence
-    pub recent_states: Vec<String>,
+    pub recent_states: BoundedHistory<String, 20>,
 
     /// Detected oscillation pattern (if any)
     pub oscillation_pattern: Option<Vec<String>>,
@@ -272,7 +298,7 @@ pub struct OscillationDetector {
 impl Default for OscillationDetector {
     fn default() -> Self {
         Self {
-            recent_states: Vec::new(),
+            recent_states: BoundedHistory::new(),
             oscillation_pattern: None,
             oscillation_frequency: 0.0,
         }
@@ -288,13 +314,9 @@ impl OscillationDetector {
     /// Records a new state.
     pub fn record_state(&mut self, state: &PerceptualState) {
         let state_name = state.name().to_string();
+        // Keeps only the last 20 states
         self.recent_states.push(state_name);
 
-        // Keep only last 20 states
-        if self.recent_states.len() > 20 {
-            self.recent_states.remove(0);
-        }
-
         self.detect_oscillation();
     }
 
@@ -309,15 +331,16 @@ impl OscillationDetector {
         // Count state changes
         let changes = self
             .recent_states
-            .windows(2)
-            .filter(|w| w[0] != w[1])
+            .iter()
+            .zip(self.recent_states.iter().skip(1))
+            .filter(|(a, b)| a != b)
             .count();
 
         self.oscillation_frequency = changes as f64 / (self.recent_states.len() - 1) as f64;
 
         // Detect simple A-B-A-B pattern
         if self.recent_states.len() >= 4 {
-            let last = &self.recent_states[self.recent_states.len() - 4..];
+            let last: Vec<&String> = self.recent_states.iter().skip(self.recent_states.len() - 4).collect();
             if last[0] == last[2] && last[1] == last[3] && last[0] != last[1] {
                 self.oscillation_pattern = Some(vec![last[0].clone(), last[1].clone()]);
             } else {
@@ -482,6 +505,36 @@ mod tests {
         assert!(tracker.is_stuck(5));
     }
 
+    #[test]
+    fn test_progress_windowed_stuck_detection() {
+        let pattern = PerceptualState::PerceivingPattern;
+        let structure = PerceptualState::PerceivingStructure;
+
+        // Advances once every four cycles, lateral steps in between
+        let mut slow = ProgressTracker::new();
+        for i in 0..19u64 {
+            let t = if i % 4 == 3 {
+                StateTransition::new(pattern.clone(), structure.clone(), i * 1000, i)
+            } else {
+                StateTransition::new(pattern.clone(), pattern.clone(), i * 1000, i)
+            };
+            slow.record_transition(&t);
+        }
+        assert!(slow.is_stuck(3)); // the consecutive count trips on the lateral run
+        assert!(!slow.is_stuck_windowed(12, 2));
+
+        let mut stuck = ProgressTracker::new();
+        for i in 0..20u64 {
+            let t = StateTransition::new(pattern.clone(), pattern.clone(), i * 1000, i);
+            stuck.record_transition(&t);
+        }
+        assert!(stuck.is_stuck_windowed(12, 2));
+
+        // Not enough history to judge
+        assert!(!ProgressTracker::new().is_stuck_windowed(12, 2));
+        assert!(!stuck.is_stuck_windowed(0, 1));
+    }
+
     #[test]
     fn test_divergence_tracker() {
         let mut tracker = DivergenceTracker::new();
@@ -507,6 +560,41 @@ mod tests {
         assert!(detector.oscillation_pattern.is_some());
     }
 
+    #[test]
+    fn test_trackers_keep_bounded_history() {
+        let mut tracker = DivergenceTracker::new();
+        let agree = MotorCompetition::from_scores([0.5, 0.5, 0.5, 0.5]);
+        let disagree = MotorCompetition::from_scores([0.0, 0.0, 1.0, 1.0]);
+        for _ in 0..150 {
+            tracker.update_from_competition(&agree);
+        }
+        for _ in 0..5 {
+            tracker.update_from_competition(&disagree);
+        }
+
+        // Last 100 entries: 95 agreeing, 5 disagreeing
+        assert_eq!(tracker.divergence_history.len(), 100);
+        let high = 1.0 - disagree.consensus_score;
+        let low = 1.0 - agree.consensus_score;
+        assert!((tracker.average_divergence - (0.95 * low + 0.05 * high)).abs() < 1e-9);
+        assert_eq!(tracker.trend(), 1);
+
+        let json = serde_json::to_value(&tracker).unwrap();
+        assert_eq!(json["divergence_history"].as_array().unwrap().len(), 100);
+
+        let mut detector = OscillationDetector::new();
+        for i in 0..30 {
+            detector.record_state(if i % 2 == 0 {
+                &PerceptualState::PerceivingPattern
+            } else {
+                &PerceptualState::PerceivingStructure
+            });
+        }
+        assert_eq!(detector.recent_states.len(), 20);
+        assert!((detector.oscillation_frequency - 1.0).abs() < 1e-12);
+        assert!(detector.is_oscillating());
+    }
+
     #[test]
     fn test_cognitive_observability_status() {
         let obs = CognitiveObservability::new();
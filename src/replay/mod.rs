@@ -50,13 +50,121 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v0.3.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Per-event RNG state and RngMismatch
+//! 2026-10-16 - Carlos Eduardo Favini - Counted clamp anomalies (compact_anomalies)
+//! 2026-10-16 - Carlos Eduardo Favini - Replayable for the four motor outputs
+//! 2026-10-16 - Carlos Eduardo Favini - MaturationTrace anomaly
+//! 2026-10-16 - Carlos Eduardo Favini - Session schema_version and ReplayError
+//! 2026-10-16 - Carlos Eduardo Favini - ReplayContext checkpoint / rollback
+//! 2026-10-16 - Carlos Eduardo Favini - ReplayComparison::report
+//! 2026-10-16 - Carlos Eduardo Favini - DeterministicRng handed out by ReplayContext
+//! 2026-10-16 - Carlos Eduardo Favini - SignedSession tamper-evidence envelope
+//! 2026-10-16 - Carlos Eduardo Favini - Anomaly density percentiles
+//! 2026-10-16 - Carlos Eduardo Favini - ReplayContext::events / last_event accessors
+//! 2026-10-16 - Carlos Eduardo Favini - CompareOptions and JSON comparison report
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::core_types::{ActionId, DnaId};
+use crate::core_types::{ActionId, DnaId, NeuronId, SynapseId};
+use crate::motors::{ChaosOutput, MeristicOutput, NashOutput, PraxisOutput};
+
+mod rng;
+mod signing;
+
+pub use rng::DeterministicRng;
+pub use signing::SignedSession;
+
+/// Current `ReplaySession` JSON schema version.
+/// Bump whenever a field is added, removed, renamed or changes meaning.
+pub const REPLAY_SESSION_SCHEMA_VERSION: u32 = 1;
+
+fn default_schema_version() -> u32 {
+    // Sessions written before versioning share the version 1 layout
+    1
+}
+
+/// Error from replay session (de)serialization, checkpoint rollback or
+/// signature verification.
+#[derive(Debug)]
+pub enum ReplayError {
+    /// The blob is not valid JSON for the session schema
+    Serialization(serde_json::Error),
+    /// The blob carries a schema version this build does not understand
+    SchemaMismatch {
+        /// Version this build reads
+        expected: u32,
+        /// Version found in the blob
+        got: u32,
+    },
+    /// The input contains no session at all
+    EmptySession,
+    /// The checkpoint belongs to another session or is ahead of the context
+    InvalidCheckpoint {
+        /// Session the checkpoint was taken from
+        session_id: String,
+        /// Events recorded at the checkpoint
+        checkpoint_events: usize,
+        /// Events currently recorded
+        current_events: usize,
+    },
+    /// A signed session no longer matches the hash it was signed over
+    PayloadHashMismatch {
+        /// Session whose content changed
+        session_id: String,
+    },
+    /// The signature over the payload hash was rejected
+    InvalidSignature {
+        /// Signer named in the envelope
+        signer: String,
+    },
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::Serialization(e) => write!(f, "Invalid replay session JSON: {}", e),
+            ReplayError::SchemaMismatch { expected, got } => {
+                write!(f, "Replay schema version mismatch: expected {}, got {}", expected, got)
+            }
+            ReplayError::EmptySession => write!(f, "Empty replay session input"),
+            ReplayError::InvalidCheckpoint { session_id, checkpoint_events, current_events } => {
+                write!(
+                    f,
+                    "Cannot roll back to checkpoint of {} at {} events (context has {})",
+                    session_id, checkpoint_events, current_events
+                )
+            }
+            ReplayError::PayloadHashMismatch { session_id } => {
+                write!(f, "Session {} was modified after signing", session_id)
+            }
+            ReplayError::InvalidSignature { signer } => {
+                write!(f, "Signature by {} does not verify", signer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReplayError::Serialization(e) => Some(e),
+            ReplayError::SchemaMismatch { .. }
+            | ReplayError::EmptySession
+            | ReplayError::InvalidCheckpoint { .. }
+            | ReplayError::PayloadHashMismatch { .. }
+            | ReplayError::InvalidSignature { .. } => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ReplayError {
+    fn from(e: serde_json::Error) -> Self {
+        ReplayError::Serialization(e)
+    }
+}
 
 /// Anomaly types that can be captured during execution.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -71,6 +179,20 @@ pub enum Anomaly {
         clamped: f64,
     },
 
+    /// The same clamp repeated consecutively, collapsed into one entry.
+    ///
+    /// Produced by [`ReplayEvent::compact_anomalies`].
+    ValueClampedRepeated {
+        /// Name of the clamped field.
+        field: String,
+        /// Original value before clamping.
+        original: f64,
+        /// Final value after clamping.
+        clamped: f64,
+        /// Number of identical clamps represented (≥ 2).
+        count: u64,
+    },
+
     /// An input was adjusted (e.g., novelty_weight).
     InputAdjusted {
         /// Name of the adjusted field.
@@ -98,10 +220,84 @@ pub enum Anomaly {
         /// Where overflow was prevented.
         location: String,
     },
+
+    /// Outcome of a perceptual maturation cycle.
+    ///
+    /// Produced by `MaturationState::to_anomalies`.
+    MaturationTrace {
+        /// Why maturation stopped (`StopReason` display form).
+        stop_reason: String,
+        /// Number of refinement iterations performed.
+        iterations: usize,
+        /// Delta of the last iteration.
+        final_delta: f64,
+        /// Whether convergence was reached.
+        converged: bool,
+    },
+}
+
+impl Anomaly {
+    /// Number of occurrences this entry represents.
+    pub fn occurrences(&self) -> u64 {
+        match self {
+            Anomaly::ValueClampedRepeated { count, .. } => *count,
+            _ => 1,
+        }
+    }
+
+    /// Field, original, clamped value and count of a (possibly repeated) clamp.
+    fn clamp_parts(&self) -> Option<(&str, f64, f64, u64)> {
+        match self {
+            Anomaly::ValueClamped { field, original, clamped } => {
+                Some((field, *original, *clamped, 1))
+            }
+            Anomaly::ValueClampedRepeated { field, original, clamped, count } => {
+                Some((field, *original, *clamped, *count))
+            }
+            _ => None,
+        }
+    }
+
+    /// Merges two clamps of the same field and values into a counted entry.
+    fn merge_clamp(&self, next: &Anomaly) -> Option<Anomaly> {
+        let (field, original, clamped, a) = self.clamp_parts()?;
+        let (next_field, next_original, next_clamped, b) = next.clamp_parts()?;
+
+        if field != next_field || original != next_original || clamped != next_clamped {
+            return None;
+        }
+
+        Some(Anomaly::ValueClampedRepeated {
+            field: field.to_string(),
+            original,
+            clamped,
+            count: a + b,
+        })
+    }
+
+    /// Summary key under which this anomaly is counted.
+    ///
+    /// Repeated clamps are counted as `ValueClamped`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Anomaly::ValueClamped { .. } | Anomaly::ValueClampedRepeated { .. } => "ValueClamped",
+            Anomaly::InputAdjusted { .. } => "InputAdjusted",
+            Anomaly::ValidationWarning { .. } => "ValidationWarning",
+            Anomaly::NumericEdgeCase { .. } => "NumericEdgeCase",
+            Anomaly::OverflowProtection { .. } => "OverflowProtection",
+            Anomaly::MaturationTrace { .. } => "MaturationTrace",
+        }
+    }
 }
 
 /// Motor type identifier for replay events.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// The four motors mirror the canonical [`crate::motors::MotorType`];
+/// `CraftPerformance` marks the CP calculation and has no motor
+/// equivalent. Use `From`/`TryFrom` to convert between the two.
+///
+/// Ordered like the canonical motors, with `CraftPerformance` last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum MotorType {
     /// Praxeological Motor (M_P).
     Praxis,
@@ -115,16 +311,48 @@ pub enum MotorType {
     CraftPerformance,
 }
 
-impl std::fmt::Display for MotorType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl MotorType {
+    /// Returns the canonical motor, or None for `CraftPerformance`.
+    pub fn as_motor(&self) -> Option<crate::motors::MotorType> {
         match self {
-            Self::Praxis => write!(f, "Praxis"),
-            Self::Nash => write!(f, "Nash"),
-            Self::Chaos => write!(f, "Chaos"),
-            Self::Meristic => write!(f, "Meristic"),
-            Self::CraftPerformance => write!(f, "CraftPerformance"),
+            Self::Praxis => Some(crate::motors::MotorType::Praxis),
+            Self::Nash => Some(crate::motors::MotorType::Nash),
+            Self::Chaos => Some(crate::motors::MotorType::Chaos),
+            Self::Meristic => Some(crate::motors::MotorType::Meristic),
+            Self::CraftPerformance => None,
         }
     }
+
+    /// Returns the name (canonical motor name, or "CraftPerformance").
+    pub fn name(&self) -> &'static str {
+        self.as_motor().map_or("CraftPerformance", |m| m.name())
+    }
+}
+
+impl From<crate::motors::MotorType> for MotorType {
+    fn from(motor: crate::motors::MotorType) -> Self {
+        match motor {
+            crate::motors::MotorType::Praxis => Self::Praxis,
+            crate::motors::MotorType::Nash => Self::Nash,
+            crate::motors::MotorType::Chaos => Self::Chaos,
+            crate::motors::MotorType::Meristic => Self::Meristic,
+        }
+    }
+}
+
+impl TryFrom<MotorType> for crate::motors::MotorType {
+    type Error = MotorType;
+
+    /// Fails (returning the input) for `CraftPerformance`.
+    fn try_from(motor: MotorType) -> Result<Self, Self::Error> {
+        motor.as_motor().ok_or(motor)
+    }
+}
+
+impl std::fmt::Display for MotorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
 }
 
 /// A single evaluation event captured during replay.
@@ -156,6 +384,13 @@ pub struct ReplayEvent {
 
     /// Additional metadata.
     pub metadata: BTreeMap<String, String>,
+
+    /// Seed/counter state that governed this evaluation, if any.
+    ///
+    /// Opaque bytes recorded by the caller. `None` for evaluations that
+    /// use no randomness and for events recorded before this field existed.
+    #[serde(default)]
+    pub rng_state: Option<Vec<u8>>,
 }
 
 impl ReplayEvent {
@@ -183,9 +418,15 @@ impl ReplayEvent {
             anomalies: Vec::new(),
             valid,
             metadata: BTreeMap::new(),
+            rng_state: None,
         }
     }
 
+    /// Records the RNG state that governed this evaluation.
+    pub fn set_rng_state(&mut self, state: &[u8]) {
+        self.rng_state = Some(state.to_vec());
+    }
+
     /// Adds an anomaly to this event.
     pub fn add_anomaly(&mut self, anomaly: Anomaly) {
         self.anomalies.push(anomaly);
@@ -202,9 +443,47 @@ impl ReplayEvent {
     }
 
     /// Returns the count of anomalies.
+    ///
+    /// Counted entries contribute their full count, so the total is
+    /// unchanged by [`compact_anomalies`](Self::compact_anomalies).
     pub fn anomaly_count(&self) -> usize {
-        self.anomalies.len()
+        self.anomalies.iter().map(|a| a.occurrences() as usize).sum()
     }
+
+    /// Collapses runs of consecutive identical clamps into counted entries.
+    ///
+    /// Distinct anomalies and their relative order are preserved.
+    pub fn compact_anomalies(&mut self) {
+        let mut compacted: Vec<Anomaly> = Vec::with_capacity(self.anomalies.len());
+
+        for anomaly in self.anomalies.drain(..) {
+            if let Some(last) = compacted.last_mut() {
+                if let Some(merged) = last.merge_clamp(&anomaly) {
+                    *last = merged;
+                    continue;
+                }
+            }
+            compacted.push(anomaly);
+        }
+
+        self.anomalies = compacted;
+    }
+}
+
+/// Position of a `ReplayContext`, for speculative branches.
+///
+/// Taken with `ReplayContext::checkpoint` and restored with
+/// `ReplayContext::rollback`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayCheckpoint {
+    /// Session the checkpoint was taken from.
+    pub session_id: String,
+
+    /// Sequence counter at the checkpoint.
+    pub sequence: u64,
+
+    /// Number of recorded events at the checkpoint.
+    pub event_count: usize,
 }
 
 /// Deterministic execution context for replay.
@@ -306,18 +585,43 @@ impl ReplayContext {
         DnaId::new_deterministic(&seed)
     }
 
+    /// Generates a deterministic SynapseId for this context.
+    pub fn deterministic_synapse_id(&mut self) -> SynapseId {
+        let seq = self.next_sequence();
+        let mut seed = self.seed.clone();
+        seed.extend_from_slice(&seq.to_le_bytes());
+        seed.extend_from_slice(b"-synapse");
+        SynapseId::new_deterministic(&seed)
+    }
+
+    /// Generates a deterministic NeuronId for this context.
+    pub fn deterministic_neuron_id(&mut self) -> NeuronId {
+        let seq = self.next_sequence();
+        let mut seed = self.seed.clone();
+        seed.extend_from_slice(&seq.to_le_bytes());
+        seed.extend_from_slice(b"-neuron");
+        NeuronId::new_deterministic(&seed)
+    }
+
+    /// Hands out a deterministic RNG for a stochastic evaluation.
+    ///
+    /// Seeded from the session seed and the next sequence number, so the
+    /// N-th generator of two same-seed contexts produces the same stream.
+    /// Store `rng.initial_state()` in the event via `set_rng_state`.
+    pub fn rng(&mut self) -> DeterministicRng {
+        let seq = self.next_sequence();
+        let mut seed = self.seed.clone();
+        seed.extend_from_slice(&seq.to_le_bytes());
+        seed.extend_from_slice(b"-rng");
+        DeterministicRng::from_seed(&seed)
+    }
+
     /// Records an event in the session.
     pub fn record_event(&mut self, event: ReplayEvent) {
         // Update anomaly counts
         for anomaly in &event.anomalies {
-            let key = match anomaly {
-                Anomaly::ValueClamped { .. } => "ValueClamped",
-                Anomaly::InputAdjusted { .. } => "InputAdjusted",
-                Anomaly::ValidationWarning { .. } => "ValidationWarning",
-                Anomaly::NumericEdgeCase { .. } => "NumericEdgeCase",
-                Anomaly::OverflowProtection { .. } => "OverflowProtection",
-            };
-            *self.anomaly_counts.entry(key.to_string()).or_insert(0) += 1;
+            *self.anomaly_counts.entry(anomaly.kind().to_string()).or_insert(0) +=
+                anomaly.occurrences();
         }
 
         self.events.push(event);
@@ -335,6 +639,7 @@ impl ReplayContext {
     /// Exports the current session for later replay.
     pub fn export_session(&self) -> ReplaySession {
         ReplaySession {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
             session_id: self.session_id.clone(),
             seed: self.seed.clone(),
             events: self.events.clone(),
@@ -349,10 +654,60 @@ impl ReplayContext {
         self.events.len()
     }
 
+    /// Recorded events in order, without cloning the session.
+    pub fn events(&self) -> &[ReplayEvent] {
+        &self.events
+    }
+
+    /// The most recently recorded event.
+    pub fn last_event(&self) -> Option<&ReplayEvent> {
+        self.events.last()
+    }
+
     /// Returns the anomaly summary.
     pub fn anomaly_summary(&self) -> &BTreeMap<String, u64> {
         &self.anomaly_counts
     }
+
+    /// Captures the current sequence counter and event count.
+    pub fn checkpoint(&self) -> ReplayCheckpoint {
+        ReplayCheckpoint {
+            session_id: self.session_id.clone(),
+            sequence: self.sequence_counter,
+            event_count: self.events.len(),
+        }
+    }
+
+    /// Discards everything recorded since `checkpoint`.
+    ///
+    /// Truncates the events, restores the sequence counter and recomputes
+    /// the anomaly summary from the remaining events, so the context is
+    /// exactly as it was when the checkpoint was taken. Committing a
+    /// speculative branch is simply not rolling back.
+    ///
+    /// # Errors
+    /// `InvalidCheckpoint` if the checkpoint comes from another session
+    /// or records more events than the context holds (e.g. an older
+    /// checkpoint was already restored). The context is left unchanged.
+    pub fn rollback(&mut self, checkpoint: ReplayCheckpoint) -> Result<(), ReplayError> {
+        if checkpoint.session_id != self.session_id || checkpoint.event_count > self.events.len() {
+            return Err(ReplayError::InvalidCheckpoint {
+                session_id: checkpoint.session_id,
+                checkpoint_events: checkpoint.event_count,
+                current_events: self.events.len(),
+            });
+        }
+
+        self.events.truncate(checkpoint.event_count);
+        self.sequence_counter = checkpoint.sequence;
+
+        self.anomaly_counts.clear();
+        for anomaly in self.events.iter().flat_map(|e| &e.anomalies) {
+            *self.anomaly_counts.entry(anomaly.kind().to_string()).or_insert(0) +=
+                anomaly.occurrences();
+        }
+        Ok(())
+    }
 }
 
 impl Default for ReplayContext {
@@ -364,6 +719,10 @@ impl Default for ReplayContext {
 /// A complete replay session that can be stored and replayed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplaySession {
+    /// JSON schema version (see `REPLAY_SESSION_SCHEMA_VERSION`).
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+
     /// Session identifier.
     pub session_id: String,
 
@@ -387,6 +746,7 @@ impl ReplaySession {
     /// Creates an empty session.
     pub fn empty() -> Self {
         Self {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
             session_id: String::new(),
             seed: Vec::new(),
             events: Vec::new(),
@@ -397,13 +757,42 @@ impl ReplaySession {
     }
 
     /// Serializes the session to JSON.
-    pub fn to_json(&self) -> Result<String, serde_json::Error> {
-        serde_json::to_string_pretty(self)
+    pub fn to_json(&self) -> Result<String, ReplayError> {
+        Ok(serde_json::to_string_pretty(self)?)
     }
 
-    /// Deserializes a session from JSON.
-    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+    /// Deserializes a session from JSON, rejecting unknown schema versions.
+    ///
+    /// The version is read before the event bodies, so a blob from a
+    /// newer schema fails with `SchemaMismatch` rather than a field error.
+    /// A missing version is read as version 1; blank input is
+    /// `EmptySession`.
+    pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+        if json.trim().is_empty() {
+            return Err(ReplayError::EmptySession);
+        }
+
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let got = match value.get("schema_version") {
+            None => default_schema_version(),
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| {
+                    <serde_json::Error as serde::de::Error>::custom(
+                        "schema_version must be an unsigned 32-bit integer",
+                    )
+                })?,
+        };
+
+        if got != REPLAY_SESSION_SCHEMA_VERSION {
+            return Err(ReplayError::SchemaMismatch {
+                expected: REPLAY_SESSION_SCHEMA_VERSION,
+                got,
+            });
+        }
+
+        Ok(serde_json::from_value(value)?)
     }
 
     /// Gets an event by sequence number.
@@ -415,6 +804,32 @@ impl ReplaySession {
     pub fn has_anomalies(&self) -> bool {
         self.total_anomalies > 0
     }
+
+    /// Percentile `p` (0-100) of the per-event anomaly counts.
+    ///
+    /// Linear interpolation between the closest ranks, so p = 50 is the
+    /// median and p = 100 the maximum. `p` is clamped to [0, 100] (NaN is
+    /// read as 0). An empty session returns 0.0.
+    pub fn anomaly_density_percentile(&self, p: f64) -> f64 {
+        if self.events.is_empty() {
+            return 0.0;
+        }
+
+        let mut counts: Vec<usize> = self.events.iter().map(ReplayEvent::anomaly_count).collect();
+        counts.sort_unstable();
+
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) };
+        let rank = p / 100.0 * (counts.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let weight = rank - lower as f64;
+        counts[lower] as f64 * (1.0 - weight) + counts[upper] as f64 * weight
+    }
+
+    /// Events with strictly more than `n` anomalies, in session order.
+    pub fn events_exceeding_anomaly_count(&self, n: usize) -> Vec<&ReplayEvent> {
+        self.events.iter().filter(|e| e.anomaly_count() > n).collect()
+    }
 }
 
 /// Result of comparing two replay sessions.
@@ -433,6 +848,46 @@ pub struct ReplayComparison {
     pub divergences: Vec<Divergence>,
 }
 
+impl ReplayComparison {
+    /// Human-readable multi-line summary.
+    ///
+    /// Header lines give the verdict (`IDENTICAL` / `DIVERGENT`), events
+    /// compared and divergence count, followed by at most `max_lines`
+    /// divergences as `[seq N] Type: description`. Divergences not shown
+    /// (cut by `max_lines` or not retained by the comparison) are
+    /// summarized on a final line.
+    pub fn report(&self, max_lines: usize) -> String {
+        let verdict = if self.identical { "IDENTICAL" } else { "DIVERGENT" };
+        let mut lines = vec![
+            format!("Replay comparison: {}", verdict),
+            format!("Events compared: {}", self.events_compared),
+            format!("Divergences: {}", self.divergence_count),
+        ];
+
+        let shown = self.divergences.len().min(max_lines);
+        for d in &self.divergences[..shown] {
+            lines.push(format!(
+                "  [seq {}] {:?}: {}",
+                d.sequence, d.divergence_type, d.description
+            ));
+        }
+
+        let hidden = self.divergence_count.saturating_sub(shown as u64);
+        if hidden > 0 {
+            lines.push(format!("  ... {} more divergence(s) not shown", hidden));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl std::fmt::Display for ReplayComparison {
+    /// `report` with every retained divergence.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.report(self.divergences.len()))
+    }
+}
+
 /// A specific divergence between two replay sessions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Divergence {
@@ -463,6 +918,44 @@ pub enum DivergenceType {
     AnomalyMismatch,
     /// Missing event in one session.
     MissingEvent,
+    /// Different recorded RNG states.
+    RngMismatch,
+}
+
+/// Default tolerance for score comparison.
+pub const DEFAULT_SCORE_TOLERANCE: f64 = 1e-15;
+
+/// Settings for `ReplayVerifier::compare_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CompareOptions {
+    /// Maximum divergences to report in detail (all are counted).
+    pub max_divergences: usize,
+
+    /// Largest score difference still treated as equal.
+    pub score_tolerance: f64,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            max_divergences: 100,
+            score_tolerance: DEFAULT_SCORE_TOLERANCE,
+        }
+    }
+}
+
+impl CompareOptions {
+    /// Sets the maximum number of divergences reported in detail.
+    pub fn with_max_divergences(mut self, max_divergences: usize) -> Self {
+        self.max_divergences = max_divergences;
+        self
+    }
+
+    /// Sets the score tolerance (negative values are treated as 0).
+    pub fn with_score_tolerance(mut self, score_tolerance: f64) -> Self {
+        self.score_tolerance = score_tolerance.max(0.0);
+        self
+    }
 }
 
 /// Verifier for comparing replay sessions.
@@ -480,6 +973,17 @@ impl ReplayVerifier {
         actual: &ReplaySession,
         max_divergences: usize,
     ) -> ReplayComparison {
+        let options = CompareOptions::default().with_max_divergences(max_divergences);
+        Self::compare_with(expected, actual, &options)
+    }
+
+    /// Compares two replay sessions using explicit `options`.
+    pub fn compare_with(
+        expected: &ReplaySession,
+        actual: &ReplaySession,
+        options: &CompareOptions,
+    ) -> ReplayComparison {
+        let max_divergences = options.max_divergences;
         let mut divergences = Vec::new();
         let mut divergence_count = 0u64;
 
@@ -531,7 +1035,7 @@ impl ReplayVerifier {
                     }
 
                     // Compare scores with tolerance
-                    if (exp.score - act.score).abs() > 1e-15 {
+                    if (exp.score - act.score).abs() > options.score_tolerance {
                         divergence_count += 1;
                         if divergences.len() < max_divergences {
                             divergences.push(Divergence {
@@ -561,7 +1065,7 @@ impl ReplayVerifier {
                     }
 
                     // Compare anomaly counts
-                    if exp.anomalies.len() != act.anomalies.len() {
+                    if exp.anomaly_count() != act.anomaly_count() {
                         divergence_count += 1;
                         if divergences.len() < max_divergences {
                             divergences.push(Divergence {
@@ -569,8 +1073,27 @@ impl ReplayVerifier {
                                 divergence_type: DivergenceType::AnomalyMismatch,
                                 description: format!(
                                     "Anomaly count: {} vs {}",
-                                    exp.anomalies.len(),
-                                    act.anomalies.len()
+                                    exp.anomaly_count(),
+                                    act.anomaly_count()
+                                ),
+                            });
+                        }
+                    }
+
+                    // Compare RNG state (None == None)
+                    if exp.rng_state != act.rng_state {
+                        divergence_count += 1;
+                        if divergences.len() < max_divergences {
+                            let show = |state: &Option<Vec<u8>>| {
+                                state.as_deref().map(hex::encode).unwrap_or_else(|| "none".to_string())
+                            };
+                            divergences.push(Divergence {
+                                sequence: seq as u64,
+                                divergence_type: DivergenceType::RngMismatch,
+                                description: format!(
+                                    "RNG state: {} vs {}",
+                                    show(&exp.rng_state),
+                                    show(&act.rng_state)
                                 ),
                             });
                         }
@@ -607,6 +1130,52 @@ impl ReplayVerifier {
             divergences,
         }
     }
+
+    /// Compares two sessions and renders the result as a JSON report.
+    ///
+    /// The report holds the effective `options`, the summary counts,
+    /// divergence counts keyed by type and by motor (over every
+    /// divergence, not only the reported ones) and the first
+    /// `options.max_divergences` divergences. Object keys are sorted, so
+    /// the same inputs always give the same string.
+    pub fn compare_to_json(
+        expected: &ReplaySession,
+        actual: &ReplaySession,
+        options: &CompareOptions,
+    ) -> String {
+        let all = options.with_max_divergences(usize::MAX);
+        let comparison = Self::compare_with(expected, actual, &all);
+
+        let mut by_type: BTreeMap<String, u64> = BTreeMap::new();
+        let mut by_motor: BTreeMap<String, u64> = BTreeMap::new();
+        for d in &comparison.divergences {
+            *by_type.entry(format!("{:?}", d.divergence_type)).or_insert(0) += 1;
+            let index = d.sequence as usize;
+            if let Some(event) = expected.events.get(index).or_else(|| actual.events.get(index)) {
+                *by_motor.entry(event.motor.name().to_string()).or_insert(0) += 1;
+            }
+        }
+
+        let reported: Vec<&Divergence> = comparison
+            .divergences
+            .iter()
+            .take(options.max_divergences)
+            .collect();
+
+        serde_json::json!({
+            "options": options,
+            "summary": {
+                "identical": comparison.identical,
+                "events_compared": comparison.events_compared,
+                "divergence_count": comparison.divergence_count,
+                "divergences_reported": reported.len(),
+            },
+            "by_type": by_type,
+            "by_motor": by_motor,
+            "divergences": reported,
+        })
+        .to_string()
+    }
 }
 
 /// Helper trait for creating replay events from motor outputs.
@@ -615,6 +1184,109 @@ pub trait Replayable {
     fn to_replay_event(&self, sequence: u64, motor: MotorType, input_hash: String) -> ReplayEvent;
 }
 
+/// SHA-256 (hex) of an output's canonical JSON serialization.
+fn hash_output<T: Serialize>(output: &T) -> String {
+    use sha2::{Digest, Sha256};
+
+    let bytes = serde_json::to_vec(output).unwrap_or_default();
+    hex::encode(Sha256::digest(&bytes))
+}
+
+/// Records the common motor diagnostics on an event as anomalies.
+///
+/// - `was_clamped` → `ValueClamped` on `score`
+/// - validation error → `ValidationWarning`
+fn add_motor_diagnostics(
+    event: &mut ReplayEvent,
+    unclamped_score: Option<f64>,
+    validation_error: Option<&str>,
+) {
+    if let Some(original) = unclamped_score {
+        event.add_anomaly(Anomaly::ValueClamped {
+            field: "score".to_string(),
+            original,
+            clamped: event.score,
+        });
+    }
+
+    if let Some(message) = validation_error {
+        event.add_anomaly(Anomaly::ValidationWarning {
+            message: message.to_string(),
+        });
+    }
+}
+
+impl Replayable for PraxisOutput {
+    fn to_replay_event(&self, sequence: u64, motor: MotorType, input_hash: String) -> ReplayEvent {
+        let mut event =
+            ReplayEvent::new(sequence, motor, input_hash, hash_output(self), self.score, true);
+        add_motor_diagnostics(
+            &mut event,
+            self.unclamped_score.filter(|_| self.was_clamped),
+            None,
+        );
+
+        event
+    }
+}
+
+impl Replayable for NashOutput {
+    fn to_replay_event(&self, sequence: u64, motor: MotorType, input_hash: String) -> ReplayEvent {
+        let mut event =
+            ReplayEvent::new(sequence, motor, input_hash, hash_output(self), self.score, self.valid);
+        add_motor_diagnostics(
+            &mut event,
+            self.unclamped_score.filter(|_| self.was_clamped),
+            self.validation_error.as_deref(),
+        );
+
+        event
+    }
+}
+
+impl Replayable for ChaosOutput {
+    fn to_replay_event(&self, sequence: u64, motor: MotorType, input_hash: String) -> ReplayEvent {
+        let mut event =
+            ReplayEvent::new(sequence, motor, input_hash, hash_output(self), self.score, self.valid);
+        add_motor_diagnostics(
+            &mut event,
+            self.unclamped_score.filter(|_| self.was_clamped),
+            self.validation_error.as_deref(),
+        );
+
+        if self.degenerate_perturbation {
+            event.add_anomaly(Anomaly::NumericEdgeCase {
+                description: "degenerate perturbation: divergence rate unreliable".to_string(),
+            });
+        }
+
+        event
+    }
+}
+
+impl Replayable for MeristicOutput {
+    fn to_replay_event(&self, sequence: u64, motor: MotorType, input_hash: String) -> ReplayEvent {
+        let mut event =
+            ReplayEvent::new(sequence, motor, input_hash, hash_output(self), self.score, self.valid);
+        add_motor_diagnostics(
+            &mut event,
+            self.unclamped_score.filter(|_| self.was_clamped),
+            self.validation_error.as_deref(),
+        );
+
+        let unadjusted = self.unadjusted_novelty_weight.filter(|_| self.novelty_weight_adjusted);
+        if let Some(original) = unadjusted {
+            event.add_anomaly(Anomaly::InputAdjusted {
+                field: "novelty_weight".to_string(),
+                original,
+                adjusted: self.effective_novelty_weight,
+            });
+        }
+
+        event
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -645,6 +1317,41 @@ mod tests {
         assert_ne!(id1a, id1b);
     }
 
+    #[test]
+    fn test_deterministic_graph_id_generation() {
+        let mut ctx1 = ReplayContext::from_seed(b"graph-seed");
+        let mut ctx2 = ReplayContext::from_seed(b"graph-seed");
+
+        let seq1: Vec<_> = (0..3)
+            .map(|_| (ctx1.deterministic_neuron_id(), ctx1.deterministic_synapse_id()))
+            .collect();
+        let seq2: Vec<_> = (0..3)
+            .map(|_| (ctx2.deterministic_neuron_id(), ctx2.deterministic_synapse_id()))
+            .collect();
+
+        // Same seed → identical id sequences
+        assert_eq!(seq1, seq2);
+        assert_ne!(seq1[0].0, seq1[1].0);
+        assert_ne!(seq1[0].1, seq1[1].1);
+    }
+
+    #[test]
+    fn test_deterministic_id_domains_do_not_collide() {
+        let mut neurons = ReplayContext::from_seed(b"domain-seed");
+        let mut synapses = ReplayContext::from_seed(b"domain-seed");
+        let mut actions = ReplayContext::from_seed(b"domain-seed");
+
+        // Same seed and sequence number, different domains
+        for _ in 0..4 {
+            let neuron = neurons.deterministic_neuron_id().0;
+            let synapse = synapses.deterministic_synapse_id().0;
+            let action = actions.deterministic_action_id().0;
+            assert_ne!(neuron, synapse);
+            assert_ne!(neuron, action);
+            assert_ne!(synapse, action);
+        }
+    }
+
     #[test]
     fn test_event_recording() {
         let mut ctx = ReplayContext::new();
@@ -670,7 +1377,7 @@ mod tests {
     }
 
     #[test]
-    fn test_session_export_import() {
+    fn test_session_export_import() -> Result<(), ReplayError> {
         let mut ctx = ReplayContext::from_seed(b"export-test");
 
         let event = ReplayEvent::new(
@@ -684,11 +1391,47 @@ mod tests {
         ctx.record_event(event);
 
         let session = ctx.export_session();
-        let json = session.to_json().unwrap();
-        let restored = ReplaySession::from_json(&json).unwrap();
+        // Both halves share one error type
+        let json = session.to_json()?;
+        let restored = ReplaySession::from_json(&json)?;
 
         assert_eq!(session.session_id, restored.session_id);
         assert_eq!(session.events.len(), restored.events.len());
+        assert_eq!(restored.schema_version, REPLAY_SESSION_SCHEMA_VERSION);
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_from_json_malformed() {
+        assert!(matches!(
+            ReplaySession::from_json("{\"session_id\": "),
+            Err(ReplayError::Serialization(_))
+        ));
+        assert!(matches!(ReplaySession::from_json("  "), Err(ReplayError::EmptySession)));
+    }
+
+    #[test]
+    fn test_session_from_json_future_version() {
+        let mut value = serde_json::to_value(ReplaySession::empty()).unwrap();
+        value["schema_version"] = serde_json::json!(REPLAY_SESSION_SCHEMA_VERSION + 1);
+        // Body from the future schema need not parse; the version is checked first
+        value["events"] = serde_json::json!("opaque");
+
+        match ReplaySession::from_json(&value.to_string()) {
+            Err(ReplayError::SchemaMismatch { expected, got }) => {
+                assert_eq!(expected, REPLAY_SESSION_SCHEMA_VERSION);
+                assert_eq!(got, REPLAY_SESSION_SCHEMA_VERSION + 1);
+            }
+            other => panic!("expected SchemaMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_session_from_json_legacy_without_version() {
+        let mut value = serde_json::to_value(ReplaySession::empty()).unwrap();
+        value.as_object_mut().unwrap().remove("schema_version");
+        let restored = ReplaySession::from_json(&value.to_string()).unwrap();
+        assert_eq!(restored.schema_version, 1);
     }
 
     #[test]
@@ -733,6 +1476,7 @@ mod tests {
         );
 
         let session1 = ReplaySession {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
             session_id: "s1".to_string(),
             seed: vec![],
             events: vec![event1],
@@ -742,6 +1486,7 @@ mod tests {
         };
 
         let session2 = ReplaySession {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
             session_id: "s2".to_string(),
             seed: vec![],
             events: vec![event2],
@@ -756,6 +1501,47 @@ mod tests {
         assert!(comparison.divergence_count > 0);
     }
 
+    #[test]
+    fn test_compare_to_json_report() {
+        let event = |score: f64, output: &str| {
+            ReplayEvent::new(0, MotorType::Nash, "in".to_string(), output.to_string(), score, true)
+        };
+        let shared = ReplayEvent::new(1, MotorType::Praxis, "in".to_string(), "out".to_string(), 0.2, true);
+        let session = |id: &str, events: Vec<ReplayEvent>| ReplaySession {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
+            session_id: id.to_string(),
+            seed: vec![],
+            total_events: events.len() as u64,
+            events,
+            anomaly_summary: BTreeMap::new(),
+            total_anomalies: 0,
+        };
+        let expected = session("expected", vec![event(0.5, "out"), shared.clone()]);
+        let actual = session("actual", vec![event(0.5004, "other"), shared]);
+
+        // Within a loose tolerance only the output hash differs
+        let loose = CompareOptions::default().with_score_tolerance(1e-3);
+        assert_eq!(ReplayVerifier::compare_with(&expected, &actual, &loose).divergence_count, 1);
+
+        let options = CompareOptions::default()
+            .with_score_tolerance(1e-6)
+            .with_max_divergences(1);
+        let json = ReplayVerifier::compare_to_json(&expected, &actual, &options);
+        assert_eq!(json, ReplayVerifier::compare_to_json(&expected, &actual, &options));
+
+        let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(report["options"]["score_tolerance"], 1e-6);
+        assert_eq!(report["options"]["max_divergences"], 1);
+        assert_eq!(report["summary"]["identical"], false);
+        assert_eq!(report["summary"]["events_compared"], 2);
+        assert_eq!(report["summary"]["divergence_count"], 2);
+        assert_eq!(report["summary"]["divergences_reported"], 1);
+        assert_eq!(report["by_type"]["OutputMismatch"], 1);
+        assert_eq!(report["by_type"]["ScoreMismatch"], 1);
+        assert_eq!(report["by_motor"]["Nash"], 2);
+        assert_eq!(report["divergences"].as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_anomaly_types() {
         let anomalies = vec![
@@ -776,4 +1562,460 @@ mod tests {
 
         assert_eq!(anomalies.len(), 3);
     }
+
+    #[test]
+    fn test_motor_type_conversions() {
+        use crate::motors::MotorType as Canonical;
+
+        for motor in Canonical::all() {
+            let replay: MotorType = motor.into();
+            assert_eq!(Canonical::try_from(replay), Ok(motor));
+            assert_eq!(replay.name(), motor.name());
+            assert_eq!(replay.to_string(), motor.to_string());
+        }
+
+        // CraftPerformance has no competition/motor equivalent
+        assert_eq!(MotorType::CraftPerformance.as_motor(), None);
+        assert_eq!(
+            Canonical::try_from(MotorType::CraftPerformance),
+            Err(MotorType::CraftPerformance)
+        );
+        assert_eq!(MotorType::CraftPerformance.to_string(), "CraftPerformance");
+
+        // Same order as the canonical motors, CraftPerformance last
+        for pair in Canonical::all().windows(2) {
+            assert!(MotorType::from(pair[0]) < MotorType::from(pair[1]));
+        }
+        assert!(MotorType::Meristic < MotorType::CraftPerformance);
+    }
+
+    fn single_event_session(event: ReplayEvent) -> ReplaySession {
+        ReplaySession {
+            schema_version: REPLAY_SESSION_SCHEMA_VERSION,
+            session_id: "s".to_string(),
+            seed: vec![],
+            events: vec![event],
+            anomaly_summary: BTreeMap::new(),
+            total_events: 1,
+            total_anomalies: 0,
+        }
+    }
+
+    #[test]
+    fn test_rng_state_divergence() {
+        let base = ReplayEvent::new(
+            0,
+            MotorType::Meristic,
+            "hash1".to_string(),
+            "hash2".to_string(),
+            0.5,
+            true,
+        );
+
+        let mut a = base.clone();
+        let mut b = base.clone();
+        a.set_rng_state(&[1, 2, 3, 4]);
+        b.set_rng_state(&[1, 2, 3, 5]);
+
+        let comparison =
+            ReplayVerifier::compare(&single_event_session(a.clone()), &single_event_session(b), 10);
+        assert!(!comparison.identical);
+        assert_eq!(comparison.divergence_count, 1);
+        assert_eq!(comparison.divergences[0].divergence_type, DivergenceType::RngMismatch);
+
+        let mut c = base.clone();
+        c.set_rng_state(&[1, 2, 3, 4]);
+        let comparison =
+            ReplayVerifier::compare(&single_event_session(a), &single_event_session(c), 10);
+        assert!(comparison.identical);
+
+        // Events without RNG state remain comparable
+        let comparison = ReplayVerifier::compare(
+            &single_event_session(base.clone()),
+            &single_event_session(base),
+            10,
+        );
+        assert!(comparison.identical);
+    }
+
+    #[test]
+    fn test_rng_state_defaults_when_absent_in_json() {
+        let event = ReplayEvent::new(
+            0,
+            MotorType::Nash,
+            "hash1".to_string(),
+            "hash2".to_string(),
+            0.5,
+            true,
+        );
+        let mut value = serde_json::to_value(&event).unwrap();
+        value.as_object_mut().unwrap().remove("rng_state");
+        let restored: ReplayEvent = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.rng_state, None);
+    }
+
+    #[test]
+    fn test_event_accessors() {
+        let mut ctx = ReplayContext::from_seed(b"accessors");
+        assert!(ctx.events().is_empty());
+        assert!(ctx.last_event().is_none());
+
+        for score in [0.1, 0.2, 0.3] {
+            let sequence = ctx.next_sequence();
+            ctx.record_event(ReplayEvent::new(sequence, MotorType::Nash, String::new(), String::new(), score, true));
+        }
+
+        let scores: Vec<f64> = ctx.events().iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![0.1, 0.2, 0.3]);
+        let last = ctx.last_event().unwrap();
+        assert_eq!(last.sequence, 2);
+        assert_eq!(last.score, 0.3);
+        assert_eq!(ctx.events().len(), ctx.export_session().events.len());
+    }
+
+    #[test]
+    fn test_anomaly_density_percentile() {
+        let empty = ReplaySession::empty();
+        assert_eq!(empty.anomaly_density_percentile(90.0), 0.0);
+        assert!(empty.events_exceeding_anomaly_count(0).is_empty());
+
+        // Anomalies per event: 0 x7, then 1, 5, 8
+        let mut ctx = ReplayContext::from_seed(b"density");
+        for anomalies in [0, 0, 5, 0, 0, 1, 0, 8, 0, 0] {
+            let sequence = ctx.next_sequence();
+            let mut event = ReplayEvent::new(sequence, MotorType::Chaos, String::new(), String::new(), 0.5, true);
+            for _ in 0..anomalies {
+                event.add_anomaly(Anomaly::ValidationWarning { message: "w".into() });
+            }
+            ctx.record_event(event);
+        }
+        let session = ctx.export_session();
+
+        assert_eq!(session.anomaly_density_percentile(50.0), 0.0);
+        assert_eq!(session.anomaly_density_percentile(100.0), 8.0);
+        // rank 0.9 × 9 = 8.1 between the sorted counts 5 and 8
+        assert!((session.anomaly_density_percentile(90.0) - 5.3).abs() < 1e-12);
+        assert_eq!(session.anomaly_density_percentile(250.0), 8.0);
+
+        let heavy: Vec<u64> = session.events_exceeding_anomaly_count(1).iter().map(|e| e.sequence).collect();
+        assert_eq!(heavy, vec![2, 7]);
+        assert_eq!(session.events_exceeding_anomaly_count(0).len(), 3);
+        assert!(session.events_exceeding_anomaly_count(8).is_empty());
+    }
+
+    #[test]
+    fn test_compact_anomalies() {
+        let clamp = |field: &str| Anomaly::ValueClamped {
+            field: field.to_string(),
+            original: 1.2,
+            clamped: 1.0,
+        };
+
+        let mut event = ReplayEvent::new(
+            0,
+            MotorType::Chaos,
+            "hash1".to_string(),
+            "hash2".to_string(),
+            1.0,
+            true,
+        );
+        for _ in 0..5 {
+            event.add_anomaly(clamp("score"));
+        }
+        event.add_anomaly(Anomaly::ValidationWarning {
+            message: "warn".to_string(),
+        });
+        event.add_anomaly(clamp("score"));
+        event.add_anomaly(clamp("lyapunov"));
+        event.add_anomaly(clamp("lyapunov"));
+
+        assert_eq!(event.anomaly_count(), 9);
+        event.compact_anomalies();
+
+        assert_eq!(event.anomaly_count(), 9);
+        assert_eq!(
+            event.anomalies,
+            vec![
+                Anomaly::ValueClampedRepeated {
+                    field: "score".to_string(),
+                    original: 1.2,
+                    clamped: 1.0,
+                    count: 5,
+                },
+                Anomaly::ValidationWarning {
+                    message: "warn".to_string(),
+                },
+                clamp("score"),
+                Anomaly::ValueClampedRepeated {
+                    field: "lyapunov".to_string(),
+                    original: 1.2,
+                    clamped: 1.0,
+                    count: 2,
+                },
+            ]
+        );
+
+        // Compaction is idempotent
+        let before = event.anomalies.clone();
+        event.compact_anomalies();
+        assert_eq!(event.anomalies, before);
+
+        let mut ctx = ReplayContext::new();
+        ctx.record_event(event);
+        assert_eq!(*ctx.anomaly_summary().get("ValueClamped").unwrap(), 8);
+        assert_eq!(ctx.export_session().total_anomalies, 9);
+    }
+
+    #[test]
+    fn test_nash_output_hash_unchanged_without_degenerate_players() {
+        let output = NashOutput {
+            valid: true,
+            validation_error: None,
+            epsilon_vector: vec![0, 3],
+            eta_equilibrium: 0.75,
+            score: 0.75,
+            was_clamped: false,
+            unclamped_score: None,
+            degenerate_players: Vec::new(),
+        };
+        // Serialization from before `degenerate_players` existed
+        let legacy = r#"{"valid":true,"validation_error":null,"epsilon_vector":[0,3],"eta_equilibrium":0.75,"score":0.75,"was_clamped":false,"unclamped_score":null}"#;
+        assert_eq!(serde_json::to_string(&output).unwrap(), legacy);
+        let legacy_hash = {
+            use sha2::{Digest, Sha256};
+            hex::encode(Sha256::digest(legacy.as_bytes()))
+        };
+        assert_eq!(hash_output(&output), legacy_hash);
+
+        let mut degenerate = output.clone();
+        degenerate.degenerate_players = vec![1];
+        assert_ne!(hash_output(&degenerate), legacy_hash);
+        let restored: NashOutput =
+            serde_json::from_str(&serde_json::to_string(&degenerate).unwrap()).unwrap();
+        assert_eq!(restored.degenerate_players, vec![1]);
+    }
+
+    #[test]
+    fn test_replayable_clamped_nash_output() {
+        let output = NashOutput {
+            valid: true,
+            validation_error: None,
+            epsilon_vector: vec![0, 0],
+            eta_equilibrium: 1.2,
+            score: 1.0,
+            was_clamped: true,
+            unclamped_score: Some(1.2),
+            degenerate_players: Vec::new(),
+        };
+
+        let event = output.to_replay_event(3, MotorType::Nash, "in".to_string());
+
+        assert_eq!(event.sequence, 3);
+        assert!(event.valid);
+        assert_eq!(event.score, 1.0);
+        assert_eq!(
+            event.anomalies,
+            vec![Anomaly::ValueClamped {
+                field: "score".to_string(),
+                original: 1.2,
+                clamped: 1.0,
+            }]
+        );
+
+        // Output hash is deterministic
+        let again = output.to_replay_event(3, MotorType::Nash, "in".to_string());
+        assert_eq!(event.output_hash, again.output_hash);
+        assert_eq!(event.output_hash.len(), 64);
+    }
+
+    #[test]
+    fn test_replayable_invalid_motor_outputs() {
+        use crate::motors::{ChaosInput, ChaosMotor, CognitiveMotor, DivergenceMethod, NashInput, NashMotor};
+
+        let nash = NashMotor::new().evaluate(&NashInput {
+            num_players: 2,
+            action_sizes: vec![2, 2],
+            payoffs: vec![vec![1, 0, 0, 1], vec![1, 0, 0, 1]],
+            strategies: vec![vec![50, 50], vec![50, 50]],
+            scale: 0,
+        });
+        let event = nash.to_replay_event(0, MotorType::Nash, "in".to_string());
+        assert!(!event.valid);
+        assert!(matches!(event.anomalies[..], [Anomaly::ValidationWarning { .. }]));
+
+        let chaos = ChaosMotor::new().evaluate(&ChaosInput {
+            reference_trajectory: vec![vec![0.0], vec![1.0]],
+            perturbed_trajectory: vec![vec![0.1], vec![1.1]],
+            delta_0: 0.1,
+            dt: 1.0,
+            epsilon_tolerance: None,
+            divergence_method: DivergenceMethod::LocalRatio,
+        });
+        let event = chaos.to_replay_event(1, MotorType::Chaos, "in".to_string());
+        assert!(event.valid);
+        assert!(!event.has_anomalies());
+    }
+
+    #[test]
+    fn test_replayable_meristic_novelty_adjustment() {
+        use crate::motors::{CognitiveMotor, MeristicInput, MeristicMotor};
+
+        let meristic = MeristicMotor::new().evaluate(&MeristicInput {
+            current_embedding: vec![1.0, 2.0, 3.0],
+            historical_embeddings: vec![],
+            domain_characteristics: None,
+            exploration_depth: 3,
+            novelty_weight: 1.5,
+        });
+        let event = meristic.to_replay_event(0, MotorType::Meristic, "in".to_string());
+
+        assert!(event.valid);
+        assert_eq!(
+            event.anomalies,
+            vec![Anomaly::InputAdjusted {
+                field: "novelty_weight".to_string(),
+                original: 1.5,
+                adjusted: 1.0,
+            }]
+        );
+        assert_eq!(event.anomalies[0].kind(), "InputAdjusted");
+    }
+
+    fn clamped_event(ctx: &mut ReplayContext, motor: MotorType) -> ReplayEvent {
+        let mut event = ReplayEvent::new(
+            ctx.next_sequence(),
+            motor,
+            "in".to_string(),
+            "out".to_string(),
+            1.0,
+            true,
+        );
+        event.add_anomaly(Anomaly::ValueClamped {
+            field: "score".to_string(),
+            original: 1.2,
+            clamped: 1.0,
+        });
+        event
+    }
+
+    #[test]
+    fn test_checkpoint_rollback_restores_state() {
+        let mut ctx = ReplayContext::from_seed(b"branching");
+        for motor in [MotorType::Praxis, MotorType::Nash] {
+            let event = clamped_event(&mut ctx, motor);
+            ctx.record_event(event);
+        }
+        let checkpoint = ctx.checkpoint();
+        let before = ctx.export_session().to_json().unwrap();
+        let next_id = ctx.clone().deterministic_action_id();
+
+        // Speculative branch
+        for motor in [MotorType::Chaos, MotorType::Meristic, MotorType::Praxis] {
+            let event = clamped_event(&mut ctx, motor);
+            ctx.record_event(event);
+        }
+        ctx.deterministic_dna_id();
+        assert_eq!(ctx.event_count(), 5);
+        assert_eq!(ctx.anomaly_summary()["ValueClamped"], 5);
+
+        ctx.rollback(checkpoint.clone()).unwrap();
+        assert_eq!(ctx.checkpoint(), checkpoint);
+        assert_eq!(ctx.current_sequence(), 2);
+        assert_eq!(ctx.anomaly_summary()["ValueClamped"], 2);
+        assert_eq!(ctx.export_session().to_json().unwrap(), before);
+        // IDs after the rollback replay the same sequence
+        assert_eq!(ctx.deterministic_action_id(), next_id);
+    }
+
+    #[test]
+    fn test_rollback_rejects_foreign_or_stale_checkpoint() {
+        let mut ctx = ReplayContext::from_seed(b"branching");
+        let start = ctx.checkpoint();
+        let event = clamped_event(&mut ctx, MotorType::Praxis);
+        ctx.record_event(event);
+        let later = ctx.checkpoint();
+        ctx.rollback(start).unwrap();
+
+        assert!(matches!(ctx.rollback(later), Err(ReplayError::InvalidCheckpoint { .. })));
+        let foreign = ReplayContext::from_seed(b"other").checkpoint();
+        assert!(matches!(ctx.rollback(foreign), Err(ReplayError::InvalidCheckpoint { .. })));
+        assert_eq!(ctx.event_count(), 0);
+    }
+
+    #[test]
+    fn test_comparison_report() {
+        let event =
+            ReplayEvent::new(0, MotorType::Praxis, "in".to_string(), "out".to_string(), 0.5, true);
+        let session = single_event_session(event.clone());
+
+        let identical = ReplayVerifier::compare(&session, &session, 10);
+        assert_eq!(
+            identical.report(5),
+            "Replay comparison: IDENTICAL\nEvents compared: 1\nDivergences: 0"
+        );
+
+        let mut other = event;
+        other.score = 0.6;
+        other.valid = false;
+        other.output_hash = "changed".to_string();
+        let divergent = ReplayVerifier::compare(&session, &single_event_session(other), 10);
+        let report = divergent.report(2);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Replay comparison: DIVERGENT",
+                "Events compared: 1",
+                "Divergences: 3",
+                "  [seq 0] OutputMismatch: Output hashes differ",
+                "  [seq 0] ScoreMismatch: Score difference: 0.5 vs 0.6",
+                "  ... 1 more divergence(s) not shown",
+            ]
+        );
+        assert_eq!(divergent.to_string().lines().count(), 6);
+        assert!(divergent.to_string().contains("ValidityMismatch"));
+    }
+
+    #[test]
+    fn test_context_rng_streams() {
+        let draw = |ctx: &mut ReplayContext| -> (Vec<u64>, ReplayEvent) {
+            let mut rng = ctx.rng();
+            let mut event = ReplayEvent::new(
+                ctx.next_sequence(),
+                MotorType::Meristic,
+                "in".to_string(),
+                "out".to_string(),
+                0.5,
+                true,
+            );
+            event.set_rng_state(&rng.initial_state());
+            ((0..8).map(|_| rng.next_u64()).collect(), event)
+        };
+
+        let mut a = ReplayContext::from_seed(b"stochastic");
+        let mut b = ReplayContext::from_seed(b"stochastic");
+        let mut c = ReplayContext::from_seed(b"other");
+
+        let (stream_a, event_a) = draw(&mut a);
+        let (stream_b, event_b) = draw(&mut b);
+        let (stream_c, event_c) = draw(&mut c);
+        assert_eq!(stream_a, stream_b);
+        assert_ne!(stream_a, stream_c);
+
+        // Successive generators of one context differ
+        assert_ne!(draw(&mut a).0, stream_a);
+
+        let comparison = ReplayVerifier::compare(
+            &single_event_session(event_a.clone()),
+            &single_event_session(event_b),
+            10,
+        );
+        assert!(comparison.identical);
+        let comparison = ReplayVerifier::compare(
+            &single_event_session(event_a),
+            &single_event_session(event_c),
+            10,
+        );
+        assert_eq!(comparison.divergences[0].divergence_type, DivergenceType::RngMismatch);
+    }
 }
@@ -0,0 +1,106 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Deterministic RNG
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 0.3.0
+//! Description: Small replay-safe pseudo-random generator for motors that
+//! need randomness. Handed out by `ReplayContext::rng`, seeded from the
+//! session seed and sequence number, so draws are identical on replay.
+//! Layer: Community
+//! Dependencies: sha2
+//! Affected Components: replay, motors
+//!
+//! --------------------------
+//! ALGORITHM
+//! --------------------------
+//! xoshiro256** (Blackman & Vigna). 256-bit state from SHA-256 of the
+//! seed material; the all-zero state (the only invalid one) is replaced
+//! by a fixed constant. Not cryptographically secure.
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation
+//! --------------------------
+
+use sha2::{Digest, Sha256};
+
+/// Replay-deterministic xoshiro256** generator.
+///
+/// The same seed material always yields the same stream, on every
+/// platform. Record `initial_state` in the `ReplayEvent` of the evaluation
+/// that used it (`ReplayEvent::set_rng_state`) so a divergent stream shows
+/// up as `RngMismatch`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicRng {
+    state: [u64; 4],
+    initial: [u8; 32],
+}
+
+impl DeterministicRng {
+    /// Creates a generator from arbitrary seed material.
+    pub fn from_seed(material: &[u8]) -> Self {
+        let initial: [u8; 32] = Sha256::digest(material).into();
+        let mut state = [0u64; 4];
+        for (word, chunk) in state.iter_mut().zip(initial.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(bytes);
+        }
+        if state == [0; 4] {
+            state[0] = 0x9E37_79B9_7F4A_7C15;
+        }
+        Self { state, initial }
+    }
+
+    /// State the generator was created with (32 bytes).
+    pub fn initial_state(&self) -> [u8; 32] {
+        self.initial
+    }
+
+    /// Next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Uniform f64 in [0, 1) (53 random bits).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_is_reproducible() {
+        let mut a = DeterministicRng::from_seed(b"seed");
+        let mut b = DeterministicRng::from_seed(b"seed");
+        let mut c = DeterministicRng::from_seed(b"seed2");
+
+        let xs: Vec<u64> = (0..16).map(|_| a.next_u64()).collect();
+        let ys: Vec<u64> = (0..16).map(|_| b.next_u64()).collect();
+        let zs: Vec<u64> = (0..16).map(|_| c.next_u64()).collect();
+        assert_eq!(xs, ys);
+        assert_ne!(xs, zs);
+
+        for _ in 0..1000 {
+            let x = a.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
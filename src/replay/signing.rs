@@ -0,0 +1,167 @@
+//! --------------------------
+//! INFORMATION
+//! --------------------------
+//! Title: Signed Replay Sessions
+//! Author: Carlos Eduardo Favini
+//! Date: 2026-10-16
+//! Version: 0.7.0
+//! Description: Tamper-evidence envelope for a recorded `ReplaySession`.
+//! The session's canonical serialization is hashed (SHA-256) and the
+//! hash is signed by a caller-supplied closure, so the crate carries no
+//! signature scheme of its own.
+//! Layer: Community
+//! Dependencies: sha2, serde_json
+//! Affected Components: replay
+//!
+//! --------------------------
+//! CANONICAL PAYLOAD
+//! --------------------------
+//! Compact JSON of the session (`serde_json::to_vec`). Field order is
+//! fixed by the struct definitions and every map is a `BTreeMap`, so
+//! the same session always yields the same bytes.
+//!
+//! --------------------------
+//! CHANGE LOG
+//! --------------------------
+//! 2026-10-16 - Carlos Eduardo Favini - Initial creation
+//! --------------------------
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{ReplayError, ReplaySession};
+
+/// A `ReplaySession` with a detached signature over its payload hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedSession {
+    /// The recorded session.
+    pub session: ReplaySession,
+
+    /// Who signed it (key identifier, opaque to the crate).
+    pub signer: String,
+
+    /// Signature over `payload_hash`, produced by the signer's closure.
+    pub signature: Vec<u8>,
+
+    /// SHA-256 of the session's canonical serialization at signing time.
+    pub payload_hash: [u8; 32],
+}
+
+impl SignedSession {
+    /// SHA-256 of the canonical serialization of `session`.
+    pub fn payload_hash_of(session: &ReplaySession) -> Result<[u8; 32], ReplayError> {
+        let bytes = serde_json::to_vec(session)?;
+        Ok(Sha256::digest(&bytes).into())
+    }
+
+    /// Hashes `session` and signs the hash with `sign_fn`.
+    pub fn sign<F>(session: ReplaySession, signer: &str, sign_fn: F) -> Result<Self, ReplayError>
+    where
+        F: FnOnce(&[u8; 32]) -> Vec<u8>,
+    {
+        let payload_hash = Self::payload_hash_of(&session)?;
+        let signature = sign_fn(&payload_hash);
+        Ok(Self {
+            session,
+            signer: signer.to_string(),
+            signature,
+            payload_hash,
+        })
+    }
+
+    /// Checks the session against the recorded hash, then the signature.
+    ///
+    /// `verify_fn(signer, payload_hash, signature)` must return true for
+    /// a valid signature. Any change to the session after signing fails
+    /// with `PayloadHashMismatch`; a hash that was recomputed without the
+    /// signer's key fails with `InvalidSignature`.
+    pub fn verify<F>(&self, verify_fn: F) -> Result<(), ReplayError>
+    where
+        F: FnOnce(&str, &[u8; 32], &[u8]) -> bool,
+    {
+        if Self::payload_hash_of(&self.session)? != self.payload_hash {
+            return Err(ReplayError::PayloadHashMismatch {
+                session_id: self.session.session_id.clone(),
+            });
+        }
+        if !verify_fn(&self.signer, &self.payload_hash, &self.signature) {
+            return Err(ReplayError::InvalidSignature {
+                signer: self.signer.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::{MotorType, ReplayContext, ReplayEvent};
+
+    const KEY: &[u8] = b"test-key";
+
+    /// Keyed hash standing in for a real signature scheme.
+    fn mac(key: &[u8], hash: &[u8; 32]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(key);
+        hasher.update(hash);
+        hasher.finalize().to_vec()
+    }
+
+    fn recorded_session() -> ReplaySession {
+        let mut ctx = ReplayContext::from_seed(b"signed");
+        for i in 0..3 {
+            let sequence = ctx.next_sequence();
+            let event = ReplayEvent::new(
+                sequence,
+                MotorType::Praxis,
+                format!("in-{}", i),
+                format!("out-{}", i),
+                0.5 + f64::from(i) * 0.1,
+                true,
+            );
+            ctx.record_event(event);
+        }
+        ctx.export_session()
+    }
+
+    fn verify_with(key: &'static [u8]) -> impl FnOnce(&str, &[u8; 32], &[u8]) -> bool {
+        move |signer, hash, signature| signer == "auditor" && mac(key, hash) == signature
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signed = SignedSession::sign(recorded_session(), "auditor", |hash| mac(KEY, hash)).unwrap();
+        assert_eq!(signed.payload_hash, SignedSession::payload_hash_of(&signed.session).unwrap());
+        assert!(signed.verify(verify_with(KEY)).is_ok());
+
+        // Survives a JSON round trip
+        let json = serde_json::to_string(&signed).unwrap();
+        let restored: SignedSession = serde_json::from_str(&json).unwrap();
+        assert!(restored.verify(verify_with(KEY)).is_ok());
+
+        assert!(matches!(
+            signed.verify(verify_with(b"other-key")),
+            Err(ReplayError::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tampered_event_fails_verification() {
+        let signed = SignedSession::sign(recorded_session(), "auditor", |hash| mac(KEY, hash)).unwrap();
+
+        let mut tampered = signed.clone();
+        tampered.session.events[1].score = 0.99;
+        assert!(matches!(
+            tampered.verify(verify_with(KEY)),
+            Err(ReplayError::PayloadHashMismatch { .. })
+        ));
+
+        // Recomputing the hash without the key does not help
+        tampered.payload_hash = SignedSession::payload_hash_of(&tampered.session).unwrap();
+        assert!(matches!(
+            tampered.verify(verify_with(KEY)),
+            Err(ReplayError::InvalidSignature { .. })
+        ));
+    }
+}
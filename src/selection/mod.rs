@@ -20,11 +20,13 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - Moved selection to Enterprise
 //! 2025-01-02 - Carlos Eduardo Favini - Canonical comparison threshold (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Equality zone from math::CP_EQUALITY_THRESHOLD
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
 use crate::hierarchy::GoldenDna;
+use crate::math::CP_EQUALITY_THRESHOLD;
 
 /// Comparison result between two DNA strands.
 ///
@@ -46,6 +48,25 @@ pub enum ComparisonResult {
     Invalid,
 }
 
+/// Size of a CP improvement relative to configurable thresholds.
+///
+/// Decision support only - Enterprise still decides evolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ImprovementClass {
+    /// New CP is lower than old CP.
+    Regression,
+
+    /// Improvement below the marginal threshold.
+    Negligible,
+
+    /// Improvement at or above marginal, below substantial.
+    Marginal,
+
+    /// Improvement at or above the substantial threshold
+    /// (includes any positive CP over a zero baseline).
+    Substantial,
+}
+
 /// Golden Index for DNA comparison.
 ///
 /// Provides methods to compare and rank DNA by Craft Performance.
@@ -67,12 +88,9 @@ impl GoldenIndex {
         let cp_a = dna_a.craft_performance;
         let cp_b = dna_b.craft_performance;
 
-        // Use threshold derived from VETO_THRESHOLD for consistency
         // CP_EQUALITY_THRESHOLD = VETO_THRESHOLD^0.5 ≈ 3.16e-8
         // This provides a meaningful equality zone while remaining
         // well above numerical noise.
-        const CP_EQUALITY_THRESHOLD: f64 = 3.16e-8; // sqrt(1e-15)
-
         if (cp_a - cp_b).abs() < CP_EQUALITY_THRESHOLD {
             ComparisonResult::Equal
         } else if cp_a > cp_b {
@@ -106,6 +124,31 @@ impl GoldenIndex {
         }
     }
 
+    /// Classifies the improvement of `new` over `old`.
+    ///
+    /// Uses `improvement_factor` against relative thresholds
+    /// (e.g. marginal = 0.01 for 1%, substantial = 0.10 for 10%).
+    /// A zero baseline with positive new CP (infinite factor) is
+    /// `Substantial`; zero to zero is `Negligible`.
+    pub fn classify_improvement(
+        old: &GoldenDna,
+        new: &GoldenDna,
+        marginal: f64,
+        substantial: f64,
+    ) -> ImprovementClass {
+        let factor = Self::improvement_factor(old, new);
+
+        if factor < 0.0 {
+            ImprovementClass::Regression
+        } else if factor >= substantial {
+            ImprovementClass::Substantial
+        } else if factor >= marginal {
+            ImprovementClass::Marginal
+        } else {
+            ImprovementClass::Negligible
+        }
+    }
+
     // Note: find_highest() and rank() have been moved to Enterprise Edition.
     // Community Edition only performs pairwise comparisons, not selection.
     // See ENTERPRISE-BACKLOG.md for the moved functions.
@@ -206,6 +249,50 @@ mod tests {
         assert_eq!(GoldenIndex::compare(&dna_a, &dna_b), ComparisonResult::SecondHigher);
     }
 
+    #[test]
+    fn test_classify_improvement_boundaries() {
+        let old = create_test_dna(0.5);
+        let classify = |cp: f64| {
+            GoldenIndex::classify_improvement(&old, &create_test_dna(cp), 0.01, 0.10)
+        };
+
+        assert_eq!(classify(0.5), ImprovementClass::Negligible);
+        assert_eq!(classify(0.504), ImprovementClass::Negligible);
+        assert_eq!(classify(0.506), ImprovementClass::Marginal);
+        assert_eq!(classify(0.54), ImprovementClass::Marginal);
+        assert_eq!(classify(0.56), ImprovementClass::Substantial);
+        assert_eq!(classify(0.4), ImprovementClass::Regression);
+    }
+
+    #[test]
+    fn test_classify_improvement_zero_baseline() {
+        let zero = create_test_dna(0.0);
+        assert_eq!(
+            GoldenIndex::classify_improvement(&zero, &create_test_dna(0.2), 0.01, 0.10),
+            ImprovementClass::Substantial
+        );
+        assert_eq!(
+            GoldenIndex::classify_improvement(&zero, &create_test_dna(0.0), 0.01, 0.10),
+            ImprovementClass::Negligible
+        );
+    }
+
+    #[test]
+    fn test_compare_equality_zone_matches_veto_threshold() {
+        use crate::math::VETO_THRESHOLD;
+
+        assert_eq!(CP_EQUALITY_THRESHOLD, VETO_THRESHOLD.sqrt());
+
+        let mut dna_a = create_test_dna(0.5);
+        let mut dna_b = dna_a.clone();
+        dna_a.craft_performance = 0.5;
+        dna_b.craft_performance = 0.5 + CP_EQUALITY_THRESHOLD * 0.5;
+        assert_eq!(GoldenIndex::compare(&dna_a, &dna_b), ComparisonResult::Equal);
+
+        dna_b.craft_performance = 0.5 + CP_EQUALITY_THRESHOLD * 2.0;
+        assert_eq!(GoldenIndex::compare(&dna_a, &dna_b), ComparisonResult::SecondHigher);
+    }
+
     #[test]
     fn test_compare_vetoed() {
         let dna_a = create_test_dna(0.0);
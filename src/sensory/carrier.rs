@@ -9,14 +9,18 @@
 //! Computes basic statistical properties of raw signal.
 //! Shannon entropy, basic statistics, byte distribution.
 //! Layer: Community
-//! Dependencies: None (pure math)
+//! Dependencies: math::stats
 //! Affected Components: sensory/signals
 //!
 //! --------------------------
 //! MATHEMATICAL BASIS
 //! --------------------------
-//! Shannon Entropy: H = -Σ p(x) log₂ p(x)
-//! Normalized: H_norm = H / log₂(N) where N = number of unique values
+//! Shannon Entropy: H = -Σ p(x) log_b p(x)
+//! Normalized: H_norm = H / log_b(N) where N = alphabet size (bins)
+//!
+//! Defaults: b = 2 (bits), N = 256 (byte alphabet). Both are
+//! configurable via `CarrierConfig`; the normalized value does not
+//! depend on the base, only the raw entropy does.
 //!
 //! This measures the "surprise" or "uncertainty" in the signal.
 //! Low entropy = ordered, predictable
@@ -26,9 +30,31 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Entropy delegated to math::stats
 //! --------------------------
 
-use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+use crate::math::stats;
+pub use crate::math::stats::EntropyBase;
+
+/// Configuration of the carrier entropy computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierConfig {
+    /// Number of symbols (histogram bins). Values below 2 are treated as 2.
+    pub alphabet_size: usize,
+    /// Logarithm base of the raw entropy.
+    pub base: EntropyBase,
+}
+
+impl Default for CarrierConfig {
+    fn default() -> Self {
+        Self {
+            alphabet_size: 256,
+            base: EntropyBase::Bits,
+        }
+    }
+}
 
 /// Result of carrier-level analysis
 #[derive(Debug, Clone)]
@@ -36,6 +62,9 @@ pub struct CarrierAnalysis {
     /// Shannon entropy (normalized 0-1)
     pub entropy: f64,
 
+    /// Shannon entropy in the configured base (not normalized)
+    pub raw_entropy: f64,
+
     /// Number of samples
     pub sample_count: usize,
 
@@ -72,6 +101,11 @@ impl CarrierAnalysis {
 
     /// Analyzes a slice of f64 values
     pub fn from_values(values: &[f64]) -> Self {
+        Self::from_values_with_config(values, &CarrierConfig::default())
+    }
+
+    /// Analyzes a slice of f64 values with a custom alphabet size and base
+    pub fn from_values_with_config(values: &[f64], config: &CarrierConfig) -> Self {
         if values.is_empty() {
             return Self::empty();
         }
@@ -100,10 +134,11 @@ impl CarrierAnalysis {
         };
 
         // Entropy calculation
-        let (entropy, unique_count) = Self::calculate_entropy(values);
+        let (entropy, raw_entropy, unique_count) = Self::calculate_entropy(values, config);
 
         Self {
             entropy,
+            raw_entropy,
             sample_count: n,
             unique_count,
             min,
@@ -118,6 +153,7 @@ impl CarrierAnalysis {
     fn empty() -> Self {
         Self {
             entropy: 0.0,
+            raw_entropy: 0.0,
             sample_count: 0,
             unique_count: 0,
             min: 0.0,
@@ -129,54 +165,30 @@ impl CarrierAnalysis {
     }
 
     /// Calculates Shannon entropy of the signal
-    /// Returns (normalized_entropy, unique_count)
-    fn calculate_entropy(values: &[f64]) -> (f64, usize) {
+    /// Returns (normalized_entropy, raw_entropy, unique_count)
+    fn calculate_entropy(values: &[f64], config: &CarrierConfig) -> (f64, f64, usize) {
         if values.is_empty() {
-            return (0.0, 0);
+            return (0.0, 0.0, 0);
         }
 
         // Discretize to histogram bins for continuous data
-        // Use 256 bins (like byte values) for consistency
+        // (256 bins by default, like byte values)
         let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
         let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
 
         if (max - min).abs() < f64::EPSILON {
             // All values are the same - zero entropy
-            return (0.0, 1);
-        }
-
-        let num_bins = 256usize;
-        let bin_width = (max - min) / num_bins as f64;
-
-        // Count frequency of each bin
-        let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
-        for &v in values {
-            let bin = ((v - min) / bin_width).floor() as usize;
-            let bin = bin.min(num_bins - 1); // Clamp to valid range
-            *counts.entry(bin).or_insert(0) += 1;
+            return (0.0, 0.0, 1);
         }
 
-        let n = values.len() as f64;
-        let unique_count = counts.len();
+        let counts = stats::histogram(values, config.alphabet_size.max(2));
+        let unique_count = counts.iter().filter(|&&count| count > 0).count();
 
-        // Shannon entropy: H = -Σ p(x) log₂ p(x)
-        let mut entropy = 0.0;
-        for &count in counts.values() {
-            if count > 0 {
-                let p = count as f64 / n;
-                entropy -= p * p.log2();
-            }
-        }
-
-        // Normalize by maximum possible entropy (log₂ of bin count)
-        let max_entropy = (num_bins as f64).log2();
-        let normalized_entropy = if max_entropy > 0.0 {
-            entropy / max_entropy
-        } else {
-            0.0
-        };
+        // Shannon entropy in the configured base, normalized by log_b of bin count
+        let entropy = stats::shannon_entropy(&counts, config.base);
+        let normalized_entropy = stats::normalized_entropy(&counts);
 
-        (normalized_entropy.clamp(0.0, 1.0), unique_count)
+        (normalized_entropy.clamp(0.0, 1.0), entropy, unique_count)
     }
 }
 
@@ -265,6 +277,47 @@ mod tests {
         assert_eq!(analysis.entropy, 0.0);
     }
 
+    #[test]
+    fn test_bits_vs_nats_ratio() {
+        let values: Vec<f64> = (0..1000).map(|i| ((i * 37) % 101) as f64).collect();
+        let bits = CarrierAnalysis::from_values_with_config(
+            &values,
+            &CarrierConfig { alphabet_size: 64, base: EntropyBase::Bits },
+        );
+        let nats = CarrierAnalysis::from_values_with_config(
+            &values,
+            &CarrierConfig { alphabet_size: 64, base: EntropyBase::Nats },
+        );
+
+        assert!((nats.raw_entropy / bits.raw_entropy - std::f64::consts::LN_2).abs() < 1e-12);
+        // Normalized entropy is base-independent
+        assert!((nats.entropy - bits.entropy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_uniform_reaches_normalized_max() {
+        // 4 equally frequent symbols over an alphabet of 4
+        let values: Vec<f64> = (0..400).map(|i| (i % 4) as f64).collect();
+        for base in [EntropyBase::Bits, EntropyBase::Nats, EntropyBase::Hartley] {
+            let analysis = CarrierAnalysis::from_values_with_config(
+                &values,
+                &CarrierConfig { alphabet_size: 4, base },
+            );
+            assert!((analysis.entropy - 1.0).abs() < 1e-12);
+            assert!((analysis.raw_entropy - base.log(4.0)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_default_config_matches_legacy() {
+        let data: Vec<u8> = (0..=255).cycle().take(256 * 10).collect();
+        let values = ByteInterpreter::as_u8(&data);
+        let a = CarrierAnalysis::from_values(&values);
+        let b = CarrierAnalysis::from_values_with_config(&values, &CarrierConfig::default());
+        assert_eq!(a.entropy, b.entropy);
+        assert!((a.raw_entropy - 8.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_zero_crossing_rate() {
         // Signal that crosses zero frequently
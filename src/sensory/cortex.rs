@@ -41,13 +41,26 @@
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Perceptual Maturation (v1.5.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Structured output comparison (CortexOutput::diff)
+//! 2026-10-16 - Carlos Eduardo Favini - Maturation iteration spans (`tracing` feature)
+//! 2026-10-16 - Carlos Eduardo Favini - Proto-agency margin in maturation metrics
+//! 2026-10-16 - Carlos Eduardo Favini - Serializable CortexConfig (from_config / config)
 //! --------------------------
 
+use std::borrow::Cow;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use serde::{Deserialize, Serialize};
+
+use crate::completeness::AbstractionLevel;
+use crate::maturation::MaturationConfig;
+use crate::budget::{
+    check_numerical_stability, check_size_budget, BudgetGuard, ComputationalBudget,
+    IntegrityCheck,
+};
 use crate::sensory::carrier::{ByteInterpreter, CarrierAnalysis};
 use crate::sensory::pattern::PatternAnalysis;
-use crate::sensory::proto_agency::{runs_test, ProtoAgencyDetector};
+use crate::sensory::proto_agency::{runs_test, ProtoAgencyDetector, ProtoAgencyEvaluation};
 use crate::sensory::signals::SensorySignals;
 use crate::sensory::state::{PerceptualState, StateHistory};
 use crate::sensory::structure::StructureAnalysis;
@@ -58,6 +71,10 @@ pub struct RawInput {
     /// Raw bytes - the cortex does NOT know what these are
     pub bytes: Vec<u8>,
 
+    /// Already-decoded samples. When present, stages consume these
+    /// directly and `bytes` is ignored.
+    pub samples: Option<Vec<f64>>,
+
     /// Optional timestamp (nanoseconds since epoch)
     pub timestamp: Option<u64>,
 }
@@ -67,6 +84,7 @@ impl RawInput {
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
         Self {
             bytes,
+            samples: None,
             timestamp: None,
         }
     }
@@ -75,10 +93,54 @@ impl RawInput {
     pub fn with_timestamp(bytes: Vec<u8>, timestamp: u64) -> Self {
         Self {
             bytes,
+            samples: None,
             timestamp: Some(timestamp),
         }
     }
 
+    /// Creates raw input from f64 samples (no byte round-trip)
+    pub fn from_samples(samples: Vec<f64>) -> Self {
+        Self {
+            bytes: Vec::new(),
+            samples: Some(samples),
+            timestamp: None,
+        }
+    }
+
+    /// Creates raw input from samples in [0, 1].
+    ///
+    /// Out-of-range values are clamped. NaN is kept as-is so that
+    /// budgeted perception can report it as numerical collapse.
+    pub fn from_normalized(samples: Vec<f64>) -> Self {
+        Self::from_samples(samples.into_iter().map(|v| v.clamp(0.0, 1.0)).collect())
+    }
+
+    /// Decoded samples if this input was built from f64 values
+    pub fn samples(&self) -> Option<&[f64]> {
+        self.samples.as_deref()
+    }
+
+    /// Values the stages consume: the samples, or bytes read as u8
+    pub fn values(&self) -> Cow<'_, [f64]> {
+        match &self.samples {
+            Some(samples) => Cow::Borrowed(samples),
+            None => Cow::Owned(ByteInterpreter::as_u8(&self.bytes)),
+        }
+    }
+
+    /// Size of the input payload in bytes (8 per sample)
+    pub fn size_bytes(&self) -> usize {
+        match &self.samples {
+            Some(samples) => samples.len() * std::mem::size_of::<f64>(),
+            None => self.bytes.len(),
+        }
+    }
+
+    /// Returns true if there is nothing to process
+    pub fn is_empty(&self) -> bool {
+        self.size_bytes() == 0
+    }
+
     /// Creates raw input from a slice
     pub fn from_slice(data: &[u8]) -> Self {
         Self::from_bytes(data.to_vec())
@@ -97,10 +159,100 @@ pub struct CortexOutput {
     /// Final perceptual state
     pub final_state: PerceptualState,
 
+    /// Proto-Agency conditions and their margins (observation only)
+    pub proto_agency: ProtoAgencyEvaluation,
+
     /// Processing timestamp
     pub processed_at: u64,
 }
 
+/// One difference between two cortex outputs.
+///
+/// Perception-level analog of a replay `Divergence`: names the signal,
+/// the abstraction level it belongs to, and the numeric delta.
+/// Booleans are reported as 0.0 / 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CortexDiff {
+    /// Abstraction level the signal belongs to
+    pub level: AbstractionLevel,
+
+    /// Signal name (field of `SensorySignals` or `ConditionMargins`)
+    pub field: &'static str,
+
+    /// Value in `self`
+    pub left: f64,
+
+    /// Value in `other`
+    pub right: f64,
+
+    /// `right - left`
+    pub delta: f64,
+}
+
+impl CortexOutput {
+    /// Compares two outputs signal by signal.
+    ///
+    /// Continuous signals differ when `|delta| > tolerance`; counts,
+    /// indices and test results differ on any change. A NaN on only one
+    /// side always differs. Timing data (`state_history`, `processed_at`)
+    /// is ignored. Entries follow level order, then field order.
+    pub fn diff(&self, other: &Self, tolerance: f64) -> Vec<CortexDiff> {
+        let (a, b) = (&self.signals, &other.signals);
+        let (ma, mb) = (&self.proto_agency.margins, &other.proto_agency.margins);
+        let flag = |v: bool| if v { 1.0 } else { 0.0 };
+
+        // (level, field, left, right, exact)
+        use AbstractionLevel::*;
+        let fields: [(AbstractionLevel, &'static str, f64, f64, bool); 24] = [
+            (Carrier, "entropy", a.entropy, b.entropy, false),
+            (Carrier, "zero_crossing_rate", a.zero_crossing_rate, b.zero_crossing_rate, false),
+            (Carrier, "sample_count", a.sample_count as f64, b.sample_count as f64, true),
+            (Carrier, "unique_values", a.unique_values as f64, b.unique_values as f64, true),
+            (Carrier, "min_value", a.min_value, b.min_value, false),
+            (Carrier, "max_value", a.max_value, b.max_value, false),
+            (Carrier, "mean", a.mean, b.mean, false),
+            (Carrier, "std_dev", a.std_dev, b.std_dev, false),
+            (Pattern, "max_autocorrelation", a.max_autocorrelation, b.max_autocorrelation, false),
+            (Pattern, "autocorrelation_lag", a.autocorrelation_lag as f64, b.autocorrelation_lag as f64, true),
+            (Pattern, "spectral_centroid", a.spectral_centroid, b.spectral_centroid, false),
+            (Pattern, "spectral_flatness", a.spectral_flatness, b.spectral_flatness, false),
+            (Pattern, "dominant_frequency_index", a.dominant_frequency_index as f64, b.dominant_frequency_index as f64, true),
+            (Pattern, "periodicity_detected", flag(a.periodicity_detected), flag(b.periodicity_detected), true),
+            (Pattern, "periodicity_significance", a.periodicity_significance, b.periodicity_significance, false),
+            (Pattern, "randomness_test_passed", flag(a.randomness_test_passed), flag(b.randomness_test_passed), true),
+            (Pattern, "randomness_test_p_value", a.randomness_test_p_value, b.randomness_test_p_value, false),
+            (Structure, "local_global_entropy_ratio", a.local_global_entropy_ratio, b.local_global_entropy_ratio, false),
+            (Structure, "compressibility", a.compressibility, b.compressibility, false),
+            (Structure, "variance_ratio", a.variance_ratio, b.variance_ratio, false),
+            (Structure, "stationarity_test_passed", flag(a.stationarity_test_passed), flag(b.stationarity_test_passed), true),
+            (ProtoAgency, "predictability", ma.predictability, mb.predictability, false),
+            (ProtoAgency, "non_randomness", ma.non_randomness, mb.non_randomness, false),
+            (ProtoAgency, "temporal_coherence", ma.temporal_coherence, mb.temporal_coherence, false),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|&(_, _, left, right, exact)| {
+                if left.is_nan() || right.is_nan() {
+                    return left.is_nan() != right.is_nan();
+                }
+                if exact {
+                    left != right
+                } else {
+                    (right - left).abs() > tolerance
+                }
+            })
+            .map(|(level, field, left, right, _)| CortexDiff {
+                level,
+                field,
+                left,
+                right,
+                delta: right - left,
+            })
+            .collect()
+    }
+}
+
 /// Result of perception with maturation (v1.5.0)
 ///
 /// Contains both the final perception and the maturation state.
@@ -161,9 +313,30 @@ impl MatureOutput {
     }
 }
 
+/// Every tunable of a `SensoryCortex`, for persisting the exact setup
+/// that produced a result.
+///
+/// Stage thresholds and minimum sample counts are constants of the
+/// crate version, not per-cortex settings, so they are not part of it.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct CortexConfig {
+    /// Use the scalar FFT plan (see `SensoryCortex::new_deterministic`)
+    pub deterministic_fft: bool,
+
+    /// Maturation settings used by `SensoryCortex::perceive_mature_default`
+    pub maturation: MaturationConfig,
+}
+
 /// The sensory cortex
 /// Processes raw input through abstraction levels without interpretation.
-pub struct SensoryCortex;
+#[derive(Debug, Clone, Copy)]
+pub struct SensoryCortex {
+    /// Use the scalar FFT fallback (bit-exact from the first call)
+    deterministic: bool,
+
+    /// Default maturation settings
+    maturation: MaturationConfig,
+}
 
 impl Default for SensoryCortex {
     fn default() -> Self {
@@ -179,7 +352,46 @@ impl SensoryCortex {
     ///
     /// Thread-safe: SensoryCortex is Send + Sync.
     pub fn new() -> Self {
-        SensoryCortex
+        Self::from_config(CortexConfig::default())
+    }
+
+    /// Creates a sensory cortex whose FFT stages use a fixed scalar plan.
+    ///
+    /// The very first `perceive` call is bit-exact with every later one,
+    /// so no warm-up perception is needed. Trade-off: the scalar FFT
+    /// skips SIMD kernels and plan caching, so throughput on large
+    /// inputs is lower than `new()`.
+    pub fn new_deterministic() -> Self {
+        Self::from_config(CortexConfig {
+            deterministic_fft: true,
+            ..CortexConfig::default()
+        })
+    }
+
+    /// Creates a sensory cortex from a (possibly restored) configuration.
+    pub fn from_config(config: CortexConfig) -> Self {
+        SensoryCortex {
+            deterministic: config.deterministic_fft,
+            maturation: config.maturation,
+        }
+    }
+
+    /// Returns the configuration; `from_config` of it perceives identically.
+    pub fn config(&self) -> CortexConfig {
+        CortexConfig {
+            deterministic_fft: self.deterministic,
+            maturation: self.maturation,
+        }
+    }
+
+    /// Returns true if this cortex uses the deterministic FFT path
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Maturation settings used by `perceive_mature_default`.
+    pub fn maturation_config(&self) -> &MaturationConfig {
+        &self.maturation
     }
 
     /// Processes raw input through the sensory cortex.
@@ -193,55 +405,75 @@ impl SensoryCortex {
     /// Stateless: Each call is independent - no memory between calls.
     /// Deterministic: Same input always produces same output.
     pub fn perceive(&self, input: &RawInput) -> CortexOutput {
+        // Convert bytes to values for analysis
+        let values = input.values();
+
+        self.perceive_values(&values, None)
+            .unwrap_or_else(|_| unreachable!("unguarded perception has no budget to exceed"))
+    }
+
+    /// Runs the pipeline over decoded values.
+    ///
+    /// With a guard, the time budget is checked between levels and the
+    /// pipeline aborts with `ExceedsTime` instead of finishing.
+    fn perceive_values(
+        &self,
+        values: &[f64],
+        guard: Option<&BudgetGuard>,
+    ) -> Result<CortexOutput, IntegrityCheck> {
         let start_time = Self::now_ns();
         let mut history = StateHistory::new(start_time);
 
         // Start processing
         history.transition_to(PerceptualState::PerceivingCarrier, Self::now_ns());
 
-        // Convert bytes to values for analysis
-        let values = ByteInterpreter::as_u8(&input.bytes);
-
         if values.is_empty() {
-            return Self::empty_output(history, start_time);
+            return Ok(Self::empty_output(history, start_time));
         }
 
         // ═══════════════════════════════════════════════════════════════════
         // LEVEL 0: CARRIER ANALYSIS
         // ═══════════════════════════════════════════════════════════════════
-        let carrier = CarrierAnalysis::from_values(&values);
+        let carrier = CarrierAnalysis::from_values(values);
+        Self::check_guard(guard)?;
 
         // ═══════════════════════════════════════════════════════════════════
         // LEVEL 1: PATTERN ANALYSIS
         // ═══════════════════════════════════════════════════════════════════
         history.transition_to(PerceptualState::PerceivingPattern, Self::now_ns());
-        let pattern = PatternAnalysis::analyze(&values);
+        let pattern = if self.deterministic {
+            PatternAnalysis::analyze_deterministic(values)
+        } else {
+            PatternAnalysis::analyze(values)
+        };
+        Self::check_guard(guard)?;
 
         // ═══════════════════════════════════════════════════════════════════
         // LEVEL 2: STRUCTURE ANALYSIS
         // ═══════════════════════════════════════════════════════════════════
         history.transition_to(PerceptualState::PerceivingStructure, Self::now_ns());
-        let structure = StructureAnalysis::analyze(&values);
+        let structure = StructureAnalysis::analyze(values);
+        Self::check_guard(guard)?;
 
         // ═══════════════════════════════════════════════════════════════════
         // STATISTICAL TESTS
         // ═══════════════════════════════════════════════════════════════════
-        let (randomness_test_passed, randomness_p_value) = runs_test(&values);
+        let (randomness_test_passed, randomness_p_value) = runs_test(values);
 
         // ═══════════════════════════════════════════════════════════════════
         // LEVEL 2.5: PROTO-AGENCY DETECTION
         // ═══════════════════════════════════════════════════════════════════
-        let proto_trigger = ProtoAgencyDetector::evaluate(
+        let proto_agency = ProtoAgencyDetector::evaluate_detailed(
             pattern.max_autocorrelation,
-            randomness_test_passed,
+            randomness_p_value,
             structure.local_global_entropy_ratio,
             pattern.periodicity_significance,
         );
 
-        if ProtoAgencyDetector::should_trigger(&proto_trigger) {
+        if proto_agency.should_trigger() {
             history.transition_to(
                 PerceptualState::ProtoAgencyDetected {
-                    trigger: proto_trigger,
+                    trigger: proto_agency.trigger.clone(),
                 },
                 Self::now_ns(),
             );
@@ -288,11 +520,23 @@ impl SensoryCortex {
         // Return to listening
         history.transition_to(PerceptualState::Listening, Self::now_ns());
 
-        CortexOutput {
+        Ok(CortexOutput {
             signals,
             state_history: history.clone(),
             final_state: history.current().clone(),
+            proto_agency,
             processed_at: Self::now_ns(),
+        })
+    }
+
+    /// Aborts with `ExceedsTime` if the guard's time budget is spent
+    fn check_guard(guard: Option<&BudgetGuard>) -> Result<(), IntegrityCheck> {
+        match guard {
+            Some(guard) if guard.time_exhausted() => Err(IntegrityCheck::ExceedsTime {
+                estimated_ns: guard.elapsed_ns(),
+                budget_ns: guard.budget().max_time_ns,
+            }),
+            _ => Ok(()),
         }
     }
 
@@ -301,10 +545,19 @@ impl SensoryCortex {
         history.transition_to(PerceptualState::Emitting, Self::now_ns());
         history.transition_to(PerceptualState::Listening, Self::now_ns());
 
+        let signals = SensorySignals::empty();
+        let proto_agency = ProtoAgencyDetector::evaluate_detailed(
+            signals.max_autocorrelation,
+            signals.randomness_test_p_value,
+            signals.local_global_entropy_ratio,
+            signals.periodicity_significance,
+        );
+
         CortexOutput {
-            signals: SensorySignals::empty(),
+            signals,
             state_history: history.clone(),
             final_state: history.current().clone(),
+            proto_agency,
             processed_at: Self::now_ns(),
         }
     }
@@ -340,7 +593,7 @@ impl SensoryCortex {
         input: &RawInput,
         budget: &crate::budget::ComputationalBudget,
     ) -> crate::budget::IntegrityCheck {
-        crate::budget::check_bytes_budget(&input.bytes, budget)
+        check_size_budget(input.size_bytes(), budget)
     }
 
     /// Processes raw input with budget verification.
@@ -385,6 +638,47 @@ impl SensoryCortex {
         }
     }
 
+    /// Processes raw input with the budget enforced throughout the pipeline.
+    ///
+    /// Unlike `perceive_checked`, this also:
+    /// 1. Verifies numerical stability of the decoded values up front
+    /// 2. Checks the time budget between abstraction levels and aborts
+    ///    cleanly with `ExceedsTime` once it is spent
+    ///
+    /// `perceive` stays budget-free.
+    ///
+    /// # Returns
+    /// `Ok(CortexOutput)` if processing completed within budget,
+    /// `Err(IntegrityCheck)` describing the first limit hit.
+    pub fn perceive_with_budget(
+        &self,
+        input: &RawInput,
+        budget: &ComputationalBudget,
+    ) -> Result<CortexOutput, IntegrityCheck> {
+        Self::admit(check_size_budget(input.size_bytes(), budget))?;
+        let values = input.values();
+        self.perceive_values_with_budget(&values, budget)
+    }
+
+    /// Budgeted perception of decoded values
+    fn perceive_values_with_budget(
+        &self,
+        values: &[f64],
+        budget: &ComputationalBudget,
+    ) -> Result<CortexOutput, IntegrityCheck> {
+        Self::admit(check_numerical_stability(values))?;
+        let guard = BudgetGuard::new(*budget);
+        self.perceive_values(values, Some(&guard))
+    }
+
+    /// Maps an up-front check to a result (empty input is not an error)
+    fn admit(check: IntegrityCheck) -> Result<(), IntegrityCheck> {
+        match check {
+            IntegrityCheck::WithinBudget | IntegrityCheck::EmptyInput => Ok(()),
+            other => Err(other),
+        }
+    }
+
     // =========================================================================
     // PERCEPTUAL MATURATION (v1.5.0)
     // =========================================================================
@@ -423,24 +717,72 @@ impl SensoryCortex {
         input: &RawInput,
         config: &crate::maturation::MaturationConfig,
     ) -> MatureOutput {
+        let values = input.values();
+        self.mature_values(&values, config, None)
+            .unwrap_or_else(|_| unreachable!("unguarded maturation has no budget to exceed"))
+    }
+
+    /// Processes raw input with maturation using the cortex's own
+    /// maturation settings (`CortexConfig::maturation`).
+    pub fn perceive_mature_default(&self, input: &RawInput) -> MatureOutput {
+        self.perceive_mature(input, &self.maturation)
+    }
+
+    /// Processes raw input with maturation under a computational budget.
+    ///
+    /// Each maturation pass is recorded on a `BudgetGuard`; the loop
+    /// aborts with `ExceedsIterations` or `ExceedsTime` as soon as a
+    /// limit is hit, and the partial maturation is discarded.
+    pub fn perceive_mature_with_budget(
+        &self,
+        input: &RawInput,
+        config: &crate::maturation::MaturationConfig,
+        budget: &ComputationalBudget,
+    ) -> Result<MatureOutput, IntegrityCheck> {
+        Self::admit(check_size_budget(input.size_bytes(), budget))?;
+        let values = input.values();
+        Self::admit(check_numerical_stability(&values))?;
+        let mut guard = BudgetGuard::new(*budget);
+        self.mature_values(&values, config, Some(&mut guard))
+    }
+
+    /// Maturation loop over decoded values, optionally guarded
+    fn mature_values(
+        &self,
+        values: &[f64],
+        config: &crate::maturation::MaturationConfig,
+        mut guard: Option<&mut BudgetGuard>,
+    ) -> Result<MatureOutput, IntegrityCheck> {
         use crate::maturation::{MaturationState, StopReason};
         use std::time::Instant;
 
         let mut maturation = MaturationState::new();
 
         // Handle empty input
-        if input.bytes.is_empty() {
+        if values.is_empty() {
             maturation.stop_reason = StopReason::EmptyInput;
-            return MatureOutput {
-                perception: self.perceive(input),
+            return Ok(MatureOutput {
+                perception: self.perceive_values(values, None)?,
                 maturation,
-            };
+            });
         }
 
         // First perception
+        #[cfg(feature = "tracing")]
+        let first_span = tracing::debug_span!(
+            "maturation_iteration",
+            iteration = 1usize,
+            delta = tracing::field::Empty,
+        )
+        .entered();
         let iter_start = Instant::now();
-        let mut current_output = self.perceive(input);
+        if let Some(guard) = guard.as_deref_mut() {
+            guard.record_iteration()?;
+        }
+        let mut current_output = self.perceive_values(values, guard.as_deref())?;
         let first_time = iter_start.elapsed().as_nanos() as u64;
+        #[cfg(feature = "tracing")]
+        drop(first_span);
         
         // Initial metrics
         let mut prev_metrics = Self::extract_metrics(&current_output);
@@ -449,14 +791,22 @@ impl SensoryCortex {
         // Check if single pass
         if config.max_iterations <= 1 {
             maturation.stop_reason = StopReason::MaxIterations;
-            return MatureOutput {
+            return Ok(MatureOutput {
                 perception: current_output,
                 maturation,
-            };
+            });
         }
 
         // Refinement loop
         for iteration in 2..=config.max_iterations {
+            #[cfg(feature = "tracing")]
+            let span = tracing::debug_span!(
+                "maturation_iteration",
+                iteration,
+                delta = tracing::field::Empty,
+                converged = tracing::field::Empty,
+            )
+            .entered();
             let iter_start = Instant::now();
 
             // Check timeout
@@ -471,20 +821,30 @@ impl SensoryCortex {
             // In maturation, each pass can use the previous signals as "priors"
             // However, we maintain statelessness - the system doesn't "remember"
             // across calls, only within this single call.
-            let refined_output = self.perceive_with_hints(input, &current_output.signals);
+            if let Some(guard) = guard.as_deref_mut() {
+                guard.record_iteration()?;
+            }
+            let refined_output =
+                self.perceive_with_hints(values, &current_output.signals, guard.as_deref())?;
             let iter_time = iter_start.elapsed().as_nanos() as u64;
 
             // Calculate delta
             let current_metrics = Self::extract_metrics(&refined_output);
             let delta = prev_metrics.delta_from(&current_metrics);
+            let converged = config.has_converged(&prev_metrics, &current_metrics);
             maturation.record_iteration(delta, iter_time);
+            #[cfg(feature = "tracing")]
+            {
+                span.record("delta", delta);
+                span.record("converged", converged);
+            }
 
             // Update for next iteration
             current_output = refined_output;
             prev_metrics = current_metrics;
 
-            // Check convergence
-            if iteration >= config.min_iterations && delta <= config.convergence_threshold {
+            // Check convergence (policy from config)
+            if iteration >= config.min_iterations && converged {
                 maturation.mark_converged();
                 break;
             }
@@ -495,17 +855,22 @@ impl SensoryCortex {
             maturation.mark_max_iterations();
         }
 
-        MatureOutput {
+        Ok(MatureOutput {
             perception: current_output,
             maturation,
-        }
+        })
     }
 
     /// Internal: Perception with hints from previous pass.
     ///
     /// This allows refinement within a single perceptual cycle.
     /// The hints do NOT persist beyond this function call.
-    fn perceive_with_hints(&self, input: &RawInput, _hints: &SensorySignals) -> CortexOutput {
+    fn perceive_with_hints(
+        &self,
+        values: &[f64],
+        _hints: &SensorySignals,
+        guard: Option<&BudgetGuard>,
+    ) -> Result<CortexOutput, IntegrityCheck> {
         // Currently, hints are not used for refinement.
         // This is a placeholder for future refinement strategies.
         // The important thing is that this is called WITHIN perceive_mature,
@@ -517,17 +882,20 @@ impl SensoryCortex {
         // - Use hint structure to weight features
         //
         // But for now, each pass is independent perception.
-        self.perceive(input)
+        self.perceive_values(values, guard)
     }
 
     /// Extracts metrics from perception for delta calculation.
     fn extract_metrics(output: &CortexOutput) -> crate::maturation::RefinementMetrics {
+        // Flag and margin both come from this pass's evaluation
+        let proto_agency = &output.proto_agency;
         crate::maturation::RefinementMetrics::new(
             output.signals.entropy,
             output.signals.compressibility,
             if output.signals.periodicity_detected { 1.0 } else { 0.0 },
-            output.state_history.proto_agency_detected(),
+            proto_agency.should_trigger(),
         )
+        .with_proto_agency_margin(proto_agency.proto_agency_margin)
     }
 
     /// Returns current timestamp in nanoseconds
@@ -543,6 +911,52 @@ impl SensoryCortex {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_diff_identical_outputs() {
+        let cortex = SensoryCortex::new_deterministic();
+        let input = RawInput::from_bytes((0..200).map(|i| (i % 17) as u8).collect());
+        let first = cortex.perceive(&input);
+        let second = cortex.perceive(&input);
+
+        assert!(first.diff(&second, 0.0).is_empty());
+        assert!(first.diff(&first, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_diff_single_entropy_change() {
+        let cortex = SensoryCortex::new_deterministic();
+        let output = cortex.perceive(&RawInput::from_bytes((0..200).map(|i| (i % 17) as u8).collect()));
+        let mut changed = output.clone();
+        changed.signals.entropy += 0.05;
+
+        let diffs = output.diff(&changed, 1e-9);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].level, AbstractionLevel::Carrier);
+        assert_eq!(diffs[0].field, "entropy");
+        assert!((diffs[0].delta - 0.05).abs() < 1e-12);
+
+        // Within tolerance: ignored
+        assert!(output.diff(&changed, 0.1).is_empty());
+    }
+
+    #[test]
+    fn test_diff_exact_fields_ignore_tolerance() {
+        let output = SensoryCortex::new().perceive(&RawInput::from_bytes(vec![1, 2, 3, 4]));
+        let mut changed = output.clone();
+        changed.signals.stationarity_test_passed = !changed.signals.stationarity_test_passed;
+        changed.signals.sample_count += 1;
+
+        let diffs = output.diff(&changed, 10.0);
+        let fields: Vec<_> = diffs.iter().map(|d| (d.level, d.field)).collect();
+        assert_eq!(
+            fields,
+            vec![
+                (AbstractionLevel::Carrier, "sample_count"),
+                (AbstractionLevel::Structure, "stationarity_test_passed"),
+            ]
+        );
+    }
+
     #[test]
     fn test_empty_input() {
         let cortex = SensoryCortex::new();
@@ -621,6 +1035,21 @@ mod tests {
         let _ = output.state_history.proto_agency_detected();
     }
 
+    #[test]
+    fn test_refinement_metrics_agency_from_one_evaluation() {
+        let cortex = SensoryCortex::new_deterministic();
+        let periodic: Vec<u8> = [0, 50, 100, 150, 200, 150, 100, 50].repeat(10);
+        let scrambled: Vec<u8> = (0..500).map(|i| ((i * 17 + 31) % 256) as u8).collect();
+
+        for bytes in [periodic, scrambled, Vec::new()] {
+            let output = cortex.perceive(&RawInput::from_bytes(bytes));
+            let metrics = SensoryCortex::extract_metrics(&output);
+            let margin = metrics.proto_agency_margin.unwrap();
+            assert_eq!(metrics.proto_agency, margin >= 0.0);
+            assert_eq!(metrics.proto_agency, output.proto_agency.should_trigger());
+        }
+    }
+
     // =========================================================================
     // COMPUTATIONAL SELF-PRESERVATION TESTS (v1.4.0)
     // =========================================================================
@@ -814,4 +1243,193 @@ mod tests {
         // Deep config requires at least 3 iterations before checking convergence
         assert!(output.iterations() >= 3 || output.converged());
     }
+
+    #[test]
+    fn test_config_roundtrip_reproduces_perception() {
+        let cortex = SensoryCortex::from_config(CortexConfig {
+            deterministic_fft: true,
+            maturation: crate::maturation::MaturationConfig::deep(),
+        });
+        let json = serde_json::to_string(&cortex.config()).unwrap();
+        let restored = SensoryCortex::from_config(serde_json::from_str(&json).unwrap());
+        assert_eq!(restored.config(), cortex.config());
+        assert_eq!(SensoryCortex::new().config(), CortexConfig::default());
+
+        let input = RawInput::from_bytes((0..512).map(|i| ((i * 13) % 97) as u8).collect());
+        let a = cortex.perceive(&input);
+        let b = restored.perceive(&input);
+        assert!(a.diff(&b, 0.0).is_empty());
+
+        let a = cortex.perceive_mature_default(&input);
+        let b = restored.perceive_mature_default(&input);
+        assert!(a.perception.diff(&b.perception, 0.0).is_empty());
+        assert_eq!(a.iterations(), b.iterations());
+        assert_eq!(a.stop_reason(), b.stop_reason());
+
+        // The restored maturation settings are the ones applied
+        let shallow = SensoryCortex::from_config(CortexConfig {
+            maturation: crate::maturation::MaturationConfig::single_pass(),
+            ..restored.config()
+        });
+        assert_eq!(shallow.perceive_mature_default(&input).iterations(), 1);
+        assert!(b.iterations() >= 3 || b.converged());
+    }
+
+    #[test]
+    fn test_deterministic_cortex_first_call_bit_exact() {
+        let input = RawInput::from_bytes((0..1000).map(|i| ((i * 37) % 251) as u8).collect());
+
+        // Fresh cortices, no warm-up
+        let a = SensoryCortex::new_deterministic().perceive(&input);
+        let b = SensoryCortex::new_deterministic().perceive(&input);
+
+        assert!(SensoryCortex::new_deterministic().is_deterministic());
+        assert!(!SensoryCortex::new().is_deterministic());
+        assert_eq!(
+            serde_json::to_string(&a.signals).unwrap(),
+            serde_json::to_string(&b.signals).unwrap()
+        );
+        assert_eq!(a.proto_agency, b.proto_agency);
+        assert_eq!(a.final_state, b.final_state);
+        assert_eq!(
+            a.signals.spectral_flatness.to_bits(),
+            b.signals.spectral_flatness.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_perceive_with_budget_exceeds_memory() {
+        let cortex = SensoryCortex::new();
+        let budget = ComputationalBudget::new().with_max_bytes(100);
+        let input = RawInput::from_bytes(vec![7u8; 101]);
+
+        match cortex.perceive_with_budget(&input, &budget) {
+            Err(IntegrityCheck::ExceedsMemory { requested, available }) => {
+                assert_eq!(requested, 101);
+                assert_eq!(available, 100);
+            }
+            other => panic!("Expected ExceedsMemory, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_perceive_with_budget_nan_input() {
+        let cortex = SensoryCortex::new();
+        let mut values: Vec<f64> = (0..64).map(|i| i as f64).collect();
+        values[10] = f64::NAN;
+
+        assert_eq!(
+            cortex
+                .perceive_values_with_budget(&values, &ComputationalBudget::new())
+                .unwrap_err(),
+            IntegrityCheck::NumericalCollapse {
+                reason: crate::budget::NumericalIssue::ContainsNaN
+            }
+        );
+    }
+
+    #[test]
+    fn test_perceive_with_budget_matches_perceive() {
+        let cortex = SensoryCortex::new();
+        let input = RawInput::from_bytes((0..200).map(|i| (i % 17) as u8).collect());
+
+        let budgeted = cortex
+            .perceive_with_budget(&input, &ComputationalBudget::new())
+            .unwrap();
+        let plain = cortex.perceive(&input);
+        assert_eq!(budgeted.signals.entropy, plain.signals.entropy);
+        assert_eq!(budgeted.proto_agency, plain.proto_agency);
+
+        let empty = cortex
+            .perceive_with_budget(&RawInput::from_bytes(vec![]), &ComputationalBudget::new())
+            .unwrap();
+        assert_eq!(empty.signals.sample_count, 0);
+    }
+
+    #[test]
+    fn test_perceive_mature_with_budget_aborts_on_iterations() {
+        let cortex = SensoryCortex::new();
+        let config = crate::maturation::MaturationConfig::deep();
+        let budget = ComputationalBudget::new().with_max_iterations(2);
+        let input = RawInput::from_bytes((0..100).collect::<Vec<u8>>());
+
+        assert_eq!(
+            cortex
+                .perceive_mature_with_budget(&input, &config, &budget)
+                .unwrap_err(),
+            IntegrityCheck::ExceedsIterations { estimated: 3, budget: 2 }
+        );
+
+        let roomy = ComputationalBudget::new();
+        let output = cortex
+            .perceive_mature_with_budget(&input, &config, &roomy)
+            .unwrap();
+        assert!(output.iterations() >= 3 || output.converged());
+    }
+
+    #[test]
+    fn test_from_samples_matches_byte_encoding() {
+        let bytes: Vec<u8> = (0..500).map(|i| ((i * 13) % 256) as u8).collect();
+        let samples: Vec<f64> = bytes.iter().map(|&b| b as f64).collect();
+
+        let from_bytes = RawInput::from_bytes(bytes);
+        let from_samples = RawInput::from_samples(samples.clone());
+        assert_eq!(from_samples.samples(), Some(samples.as_slice()));
+        assert_eq!(from_bytes.values(), from_samples.values());
+        assert_eq!(from_samples.size_bytes(), 500 * 8);
+
+        let cortex = SensoryCortex::new();
+        let a = cortex.perceive(&from_bytes).signals;
+        let b = cortex.perceive(&from_samples).signals;
+        assert_eq!(a.entropy, b.entropy);
+        assert_eq!(a.sample_count, b.sample_count);
+        assert_eq!(a.unique_values, b.unique_values);
+        assert_eq!(a.min_value, b.min_value);
+        assert_eq!(a.max_value, b.max_value);
+        assert_eq!(a.mean, b.mean);
+        assert_eq!(a.std_dev, b.std_dev);
+    }
+
+    #[test]
+    fn test_from_normalized_clamps() {
+        let input = RawInput::from_normalized(vec![-0.5, 0.25, 1.5]);
+        assert_eq!(input.samples(), Some(&[0.0, 0.25, 1.0][..]));
+
+        let output = SensoryCortex::new().perceive(&input);
+        assert_eq!(output.signals.min_value, 0.0);
+        assert_eq!(output.signals.max_value, 1.0);
+    }
+
+    #[test]
+    fn test_from_samples_budget_checks() {
+        let cortex = SensoryCortex::new();
+        let budget = ComputationalBudget::new().with_max_bytes(80);
+
+        // 11 samples = 88 bytes
+        let large = RawInput::from_samples(vec![0.5; 11]);
+        assert!(matches!(
+            cortex.perceive_with_budget(&large, &budget),
+            Err(IntegrityCheck::ExceedsMemory { requested: 88, available: 80 })
+        ));
+
+        let nan = RawInput::from_samples(vec![0.0, f64::NAN, 1.0]);
+        assert!(matches!(
+            cortex.perceive_with_budget(&nan, &budget),
+            Err(IntegrityCheck::NumericalCollapse { .. })
+        ));
+        assert!(RawInput::from_samples(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_pipeline_transitions_are_legal() {
+        let cortex = SensoryCortex::new();
+        let inputs = [
+            RawInput::from_bytes(vec![]),
+            RawInput::from_bytes((0..255).collect()),
+            RawInput::from_bytes((0..1000).map(|i| ((i % 8) * 30) as u8).collect()),
+        ];
+        for input in &inputs {
+            assert!(cortex.perceive(input).state_history.is_legal());
+        }
+    }
 }
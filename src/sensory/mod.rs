@@ -67,7 +67,13 @@ pub mod cortex;
 pub mod output;
 
 // Re-exports
-pub use cortex::{CortexOutput, MatureOutput, RawInput, SensoryCortex};
-pub use output::{CommunityOutput, CommunityOutputBuilder};
-pub use signals::SensorySignals;
-pub use state::{PerceptualState, ProtoAgencyTrigger, StateHistory, StateTransition};
+pub use cortex::{CortexConfig, CortexDiff, CortexOutput, MatureOutput, RawInput, SensoryCortex};
+pub use output::{
+    CommunityOutput, CommunityOutputBuilder, OutputSchemaError, COMMUNITY_OUTPUT_SCHEMA_VERSION,
+};
+pub use signals::{SensorySignals, SignalAccumulator, DEFAULT_AUTOCORRELATION_WINDOW};
+pub use proto_agency::{ConditionMargins, ProtoAgencyEvaluation};
+pub use state::{
+    IllegalTransition, LoopInfo, PerceptualState, PerceptualStateKind, ProtoAgencyCondition,
+    ProtoAgencyTrigger, StateHistory, StateTransition,
+};
@@ -42,10 +42,63 @@ use crate::math::craft::{CpResult, VetoCause};
 use crate::sensory::signals::SensorySignals;
 use crate::sensory::state::{PerceptualState, StateTransition};
 
+/// Current `CommunityOutput` JSON schema version.
+/// Bump whenever a field is added, removed, renamed or changes meaning.
+pub const COMMUNITY_OUTPUT_SCHEMA_VERSION: u32 = 1;
+
+fn default_schema_version() -> u32 {
+    // Blobs written before versioning share the version 1 layout
+    1
+}
+
+/// Error from versioned CommunityOutput deserialization
+#[derive(Debug)]
+pub enum OutputSchemaError {
+    /// The blob carries a schema version this build does not understand
+    SchemaMismatch {
+        /// Version this build reads
+        expected: u32,
+        /// Version found in the blob
+        found: u32,
+    },
+    /// The blob is not valid JSON for the expected schema
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for OutputSchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputSchemaError::SchemaMismatch { expected, found } => {
+                write!(f, "Schema version mismatch: expected {}, found {}", expected, found)
+            }
+            OutputSchemaError::Json(e) => write!(f, "Invalid output JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for OutputSchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OutputSchemaError::Json(e) => Some(e),
+            OutputSchemaError::SchemaMismatch { .. } => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for OutputSchemaError {
+    fn from(e: serde_json::Error) -> Self {
+        OutputSchemaError::Json(e)
+    }
+}
+
 /// Complete output of the Community Edition.
 /// This is what gets emitted after processing raw input.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommunityOutput {
+    /// JSON schema version (see `COMMUNITY_OUTPUT_SCHEMA_VERSION`)
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+
     // ═══════════════════════════════════════════════════════════════════════
     // CRAFT PERFORMANCE
     // ═══════════════════════════════════════════════════════════════════════
@@ -152,6 +205,7 @@ impl CommunityOutput {
             .fold(0.0_f64, f64::max);
 
         Self {
+            schema_version: COMMUNITY_OUTPUT_SCHEMA_VERSION,
             craft_performance,
             vetoed,
             veto_source,
@@ -186,6 +240,35 @@ impl CommunityOutput {
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    /// Deserializes from JSON, rejecting unknown schema versions.
+    ///
+    /// The version is read before the body, so a blob from a newer
+    /// schema fails with `SchemaMismatch` rather than a field error.
+    /// A missing version is read as version 1.
+    pub fn from_json_versioned(json: &str) -> Result<Self, OutputSchemaError> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let found = match value.get("schema_version") {
+            None => default_schema_version(),
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| {
+                    <serde_json::Error as serde::de::Error>::custom(
+                        "schema_version must be an unsigned 32-bit integer",
+                    )
+                })?,
+        };
+
+        if found != COMMUNITY_OUTPUT_SCHEMA_VERSION {
+            return Err(OutputSchemaError::SchemaMismatch {
+                expected: COMMUNITY_OUTPUT_SCHEMA_VERSION,
+                found,
+            });
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
 }
 
 /// Builder for CommunityOutput
@@ -327,6 +410,50 @@ mod tests {
         assert!(!restored.vetoed);
     }
 
+    #[test]
+    fn test_versioned_roundtrip() {
+        let cp_result = CpResult::Valid {
+            value: 0.5,
+            was_clamped: false,
+            unclamped_value: None,
+        };
+        let output = CommunityOutputBuilder::new()
+            .motors(0.8, 0.9, 0.85, 0.95)
+            .build(&cp_result);
+        assert_eq!(output.schema_version, COMMUNITY_OUTPUT_SCHEMA_VERSION);
+
+        let json = output.to_json_compact().unwrap();
+        let restored = CommunityOutput::from_json_versioned(&json).unwrap();
+        assert_eq!(restored.schema_version, COMMUNITY_OUTPUT_SCHEMA_VERSION);
+        assert_eq!(restored.craft_performance, 0.5);
+    }
+
+    #[test]
+    fn test_versioned_rejects_future_version() {
+        let output = CommunityOutputBuilder::new().build(&CpResult::Valid {
+            value: 0.5,
+            was_clamped: false,
+            unclamped_value: None,
+        });
+        let mut value = serde_json::to_value(&output).unwrap();
+        value["schema_version"] = serde_json::json!(COMMUNITY_OUTPUT_SCHEMA_VERSION + 1);
+        value["field_from_the_future"] = serde_json::json!(true);
+
+        match CommunityOutput::from_json_versioned(&value.to_string()) {
+            Err(OutputSchemaError::SchemaMismatch { expected, found }) => {
+                assert_eq!(expected, COMMUNITY_OUTPUT_SCHEMA_VERSION);
+                assert_eq!(found, COMMUNITY_OUTPUT_SCHEMA_VERSION + 1);
+            }
+            other => panic!("Expected SchemaMismatch, got {:?}", other),
+        }
+
+        // Pre-versioning blobs still parse as version 1
+        value.as_object_mut().unwrap().remove("schema_version");
+        value.as_object_mut().unwrap().remove("field_from_the_future");
+        let legacy = CommunityOutput::from_json_versioned(&value.to_string()).unwrap();
+        assert_eq!(legacy.schema_version, 1);
+    }
+
     #[test]
     fn test_output_with_veto() {
         let cp_result = CpResult::Vetoed {
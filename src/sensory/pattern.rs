@@ -23,14 +23,17 @@
 //! We use FFT-based autocorrelation for efficiency:
 //! R = IFFT(|FFT(x)|²)
 //!
+//! Dominant period: first local maximum of R(τ), τ ≥ 1, above the
+//! white-noise confidence bound 2/√N (Bartlett, 95%).
+//!
 //! --------------------------
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
 //! --------------------------
 
-use rustfft::{num_complex::Complex, FftPlanner};
-use std::sync::Mutex;
+use rustfft::{num_complex::Complex, Fft, FftPlanner, FftPlannerScalar};
+use std::sync::{Arc, Mutex};
 
 // Persistent FFT planner for deterministic behavior
 // First call may involve auto-tuning; subsequent calls are deterministic
@@ -44,6 +47,31 @@ fn get_fft_planner() -> std::sync::MutexGuard<'static, Option<FftPlanner<f64>>>
     guard
 }
 
+/// Plans an FFT of `size`.
+///
+/// Deterministic mode uses a fresh scalar planner per call: no CPU
+/// feature detection and no shared planner state, so the very first
+/// call is bit-exact with every later one. The price is throughput -
+/// no SIMD kernels and no plan reuse across calls.
+fn plan_fft(size: usize, inverse: bool, deterministic: bool) -> Arc<dyn Fft<f64>> {
+    if deterministic {
+        let mut planner = FftPlannerScalar::new();
+        return if inverse {
+            planner.plan_fft_inverse(size)
+        } else {
+            planner.plan_fft_forward(size)
+        };
+    }
+
+    let mut planner_guard = get_fft_planner();
+    let planner = planner_guard.as_mut().unwrap();
+    if inverse {
+        planner.plan_fft_inverse(size)
+    } else {
+        planner.plan_fft_forward(size)
+    }
+}
+
 /// Result of pattern-level analysis
 #[derive(Debug, Clone)]
 pub struct PatternAnalysis {
@@ -72,15 +100,93 @@ pub struct PatternAnalysis {
     pub spectrum: Vec<f64>,
 }
 
+/// Autocorrelation function over lags 0..=max_lag and dominant period
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutocorrelationProfile {
+    /// R(τ) for τ = 0..=max_lag (empty for degenerate input)
+    pub autocorrelation: Vec<f64>,
+
+    /// Lag of the first significant peak (None if no peak clears the floor)
+    pub dominant_period: Option<usize>,
+
+    /// Noise floor used for significance (2/√N)
+    pub noise_floor: f64,
+}
+
+impl AutocorrelationProfile {
+    /// Degenerate profile (constant or too-short signal)
+    fn empty() -> Self {
+        Self {
+            autocorrelation: Vec::new(),
+            dominant_period: None,
+            noise_floor: 0.0,
+        }
+    }
+}
+
 impl PatternAnalysis {
+    /// Computes the autocorrelation function and dominant period.
+    ///
+    /// `max_lag` defaults to half the signal length and is capped at N-1.
+    /// Constant signals and signals shorter than 4 samples return an
+    /// empty profile.
+    pub fn autocorrelation_profile(values: &[f64], max_lag: Option<usize>) -> AutocorrelationProfile {
+        let n = values.len();
+        if n < 4 {
+            return AutocorrelationProfile::empty();
+        }
+
+        let mean: f64 = values.iter().sum::<f64>() / n as f64;
+        let var: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+        if var < f64::EPSILON {
+            return AutocorrelationProfile::empty();
+        }
+
+        let max_lag = max_lag.unwrap_or(n / 2).min(n - 1);
+        let mut autocorrelation = Self::compute_autocorrelation(values, false);
+        autocorrelation.truncate(max_lag + 1);
+
+        let noise_floor = 2.0 / (n as f64).sqrt();
+        let dominant_period = (1..autocorrelation.len().saturating_sub(1)).find(|&lag| {
+            let r = autocorrelation[lag];
+            r > noise_floor && r > autocorrelation[lag - 1] && r >= autocorrelation[lag + 1]
+        });
+
+        AutocorrelationProfile {
+            autocorrelation,
+            dominant_period,
+            noise_floor,
+        }
+    }
+
     /// Analyzes pattern/periodicity in a signal
     pub fn analyze(values: &[f64]) -> Self {
+        Self::analyze_with_mode(values, false)
+    }
+
+    /// Analyzes pattern/periodicity using the scalar FFT fallback.
+    ///
+    /// Bit-exact from the first call (no planner auto-tuning), at the
+    /// cost of SIMD throughput.
+    pub fn analyze_deterministic(values: &[f64]) -> Self {
+        Self::analyze_with_mode(values, true)
+    }
+
+    /// Periodicity rule shared with signal accumulation.
+    ///
+    /// Significant if peak is at least 3x noise floor
+    /// OR if the signal has very strong absolute autocorrelation (clean periodic signal)
+    pub fn is_periodic(significance: f64, max_autocorr: f64, lag: usize) -> bool {
+        (significance > 3.0 || max_autocorr > 0.9) && lag > 0
+    }
+
+    fn analyze_with_mode(values: &[f64], deterministic: bool) -> Self {
         if values.len() < 4 {
             return Self::empty();
         }
 
         // Compute autocorrelation
-        let autocorr = Self::compute_autocorrelation(values);
+        let autocorr = Self::compute_autocorrelation(values, deterministic);
 
         // Find maximum autocorrelation (excluding lag 0)
         let (max_autocorr, max_lag) = Self::find_max_autocorrelation(&autocorr);
@@ -95,13 +201,11 @@ impl PatternAnalysis {
             0.0
         };
 
-        // Significant if peak is at least 3x noise floor
-        // OR if the signal has very strong absolute autocorrelation (clean periodic signal)
         let periodicity_detected =
-            (periodicity_significance > 3.0 || max_autocorr > 0.9) && max_lag > 0;
+            Self::is_periodic(periodicity_significance, max_autocorr, max_lag);
 
         // Compute spectrum
-        let spectrum = Self::compute_spectrum(values);
+        let spectrum = Self::compute_spectrum(values, deterministic);
         let spectral_centroid = Self::compute_spectral_centroid(&spectrum);
         let spectral_flatness = Self::compute_spectral_flatness(&spectrum);
         let dominant_frequency_index = Self::find_dominant_frequency(&spectrum);
@@ -133,7 +237,7 @@ impl PatternAnalysis {
     }
 
     /// Computes autocorrelation using FFT method
-    fn compute_autocorrelation(values: &[f64]) -> Vec<f64> {
+    fn compute_autocorrelation(values: &[f64], deterministic: bool) -> Vec<f64> {
         let n = values.len();
         let fft_size = (2 * n).next_power_of_two();
 
@@ -149,9 +253,7 @@ impl PatternAnalysis {
         input.resize(fft_size, Complex::new(0.0, 0.0));
 
         // Forward FFT
-        let mut planner_guard = get_fft_planner();
-        let planner = planner_guard.as_mut().unwrap();
-        let fft = planner.plan_fft_forward(fft_size);
+        let fft = plan_fft(fft_size, false, deterministic);
         fft.process(&mut input);
 
         // Power spectrum (|FFT|²)
@@ -160,9 +262,8 @@ impl PatternAnalysis {
         }
 
         // Inverse FFT
-        let ifft = planner.plan_fft_inverse(fft_size);
+        let ifft = plan_fft(fft_size, true, deterministic);
         ifft.process(&mut input);
-        drop(planner_guard);
 
         // Normalize and extract real part
         let var: f64 = centered.iter().map(|v| v * v).sum();
@@ -220,7 +321,7 @@ impl PatternAnalysis {
     }
 
     /// Computes magnitude spectrum using FFT
-    fn compute_spectrum(values: &[f64]) -> Vec<f64> {
+    fn compute_spectrum(values: &[f64], deterministic: bool) -> Vec<f64> {
         let n = values.len();
         let fft_size = n.next_power_of_two();
 
@@ -236,11 +337,8 @@ impl PatternAnalysis {
         windowed.resize(fft_size, Complex::new(0.0, 0.0));
 
         // FFT
-        let mut planner_guard = get_fft_planner();
-        let planner = planner_guard.as_mut().unwrap();
-        let fft = planner.plan_fft_forward(fft_size);
+        let fft = plan_fft(fft_size, false, deterministic);
         fft.process(&mut windowed);
-        drop(planner_guard);
 
         // Magnitude spectrum (only positive frequencies)
         let magnitudes: Vec<f64> = windowed
@@ -399,6 +497,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dominant_period_sine() {
+        let period = 16;
+        let values: Vec<f64> = (0..256)
+            .map(|i| (2.0 * std::f64::consts::PI * i as f64 / period as f64).sin())
+            .collect();
+
+        let profile = PatternAnalysis::autocorrelation_profile(&values, None);
+        assert_eq!(profile.autocorrelation.len(), 129);
+        assert!((profile.autocorrelation[0] - 1.0).abs() < 1e-9);
+        assert_eq!(profile.dominant_period, Some(period));
+    }
+
+    #[test]
+    fn test_dominant_period_repeating_sequence() {
+        let cycle = [0.0, 1.0, 3.0, 1.0, 0.0, -2.0, -3.0];
+        let values: Vec<f64> = cycle.iter().cycle().take(7 * 30).cloned().collect();
+
+        let profile = PatternAnalysis::autocorrelation_profile(&values, Some(20));
+        assert_eq!(profile.autocorrelation.len(), 21);
+        assert_eq!(profile.dominant_period, Some(7));
+    }
+
+    #[test]
+    fn test_autocorrelation_profile_degenerate() {
+        let constant = vec![2.5; 100];
+        let profile = PatternAnalysis::autocorrelation_profile(&constant, None);
+        assert!(profile.autocorrelation.is_empty());
+        assert_eq!(profile.dominant_period, None);
+
+        let short = vec![1.0, 2.0, 3.0];
+        assert!(PatternAnalysis::autocorrelation_profile(&short, None).autocorrelation.is_empty());
+    }
+
     #[test]
     fn test_empty_signal() {
         let values: Vec<f64> = vec![];
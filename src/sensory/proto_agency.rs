@@ -44,9 +44,97 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Overall proto_agency_margin
+//! 2026-10-16 - Carlos Eduardo Favini - Inclusive thresholds (zero margin = met)
 //! --------------------------
 
-use crate::sensory::state::ProtoAgencyTrigger;
+use serde::{Deserialize, Serialize};
+
+use crate::sensory::state::{ProtoAgencyCondition, ProtoAgencyTrigger};
+
+/// Autocorrelation at or above this exceeds what random signals produce
+pub const AUTOCORRELATION_THRESHOLD: f64 = 0.3;
+
+/// Periodicity significance at or above this exceeds the noise floor
+pub const PERIODICITY_SIGNIFICANCE_THRESHOLD: f64 = 2.0;
+
+/// Runs test significance level (p at or below rejects randomness)
+pub const RANDOMNESS_SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Local/global entropy ratio at or below this indicates local structure
+pub const TEMPORAL_COHERENCE_RATIO_THRESHOLD: f64 = 0.9;
+
+/// Signed distance of each condition from its threshold.
+/// Non-negative = condition met (thresholds are inclusive), negative = not met.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConditionMargins {
+    /// max(autocorrelation - 0.3, periodicity_significance - 2.0)
+    pub predictability: f64,
+
+    /// 0.05 - runs test p-value
+    pub non_randomness: f64,
+
+    /// 0.9 - local/global entropy ratio
+    pub temporal_coherence: f64,
+}
+
+impl ConditionMargins {
+    /// Margin of a single condition
+    pub fn margin(&self, condition: ProtoAgencyCondition) -> f64 {
+        match condition {
+            ProtoAgencyCondition::PredictabilityExceedsRandom => self.predictability,
+            ProtoAgencyCondition::NonRandomnessConfirmed => self.non_randomness,
+            ProtoAgencyCondition::TemporalCoherenceDetected => self.temporal_coherence,
+        }
+    }
+
+    /// Overall Proto-Agency margin in [-1, 1].
+    ///
+    /// Proto-Agency needs two of the three conditions, so the deciding
+    /// condition is the one with the second-largest margin. Each margin
+    /// is clamped to [-1, 1] and the second-largest is returned:
+    /// non-negative when Proto-Agency triggers, negative when it does
+    /// not. Zero means the deciding condition sits exactly on its
+    /// threshold, which counts as met. Its magnitude says how far the
+    /// decision is from flipping.
+    pub fn overall(&self) -> f64 {
+        let mut margins = [self.predictability, self.non_randomness, self.temporal_coherence]
+            .map(|m| if m.is_nan() { -1.0 } else { m.clamp(-1.0, 1.0) });
+        margins.sort_by(|a, b| b.total_cmp(a));
+        margins[1]
+    }
+}
+
+/// Inspectable Proto-Agency evaluation: which conditions fired and by how much.
+/// Observation only - it reports thresholds crossed, not intent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtoAgencyEvaluation {
+    /// Which conditions were met
+    pub trigger: ProtoAgencyTrigger,
+
+    /// Distance of each condition from its threshold
+    pub margins: ConditionMargins,
+
+    /// Overall margin in [-1, 1] (see `ConditionMargins::overall`)
+    #[serde(default)]
+    pub proto_agency_margin: f64,
+}
+
+impl ProtoAgencyEvaluation {
+    /// Conditions that fired, with their margins
+    pub fn fired(&self) -> Vec<(ProtoAgencyCondition, f64)> {
+        self.trigger
+            .fired()
+            .into_iter()
+            .map(|c| (c, self.margins.margin(c)))
+            .collect()
+    }
+
+    /// Whether Proto-Agency state should be entered
+    pub fn should_trigger(&self) -> bool {
+        ProtoAgencyDetector::should_trigger(&self.trigger)
+    }
+}
 
 /// Proto-Agency detector
 /// Determines if mathematical conditions for Proto-Agency are met.
@@ -73,8 +161,8 @@ impl ProtoAgencyDetector {
         // Autocorrelation significantly above noise floor suggests predictability
         // We use 0.3 as threshold because random signals typically have
         // autocorrelation < 0.2 (derived from statistical theory, not arbitrary)
-        let predictability_exceeds_random =
-            max_autocorrelation > 0.3 || periodicity_significance > 2.0;
+        let predictability_exceeds_random = max_autocorrelation >= AUTOCORRELATION_THRESHOLD
+            || periodicity_significance >= PERIODICITY_SIGNIFICANCE_THRESHOLD;
 
         // Condition 2: Non-randomness confirmed
         // If the runs test FAILS (passed = false), the signal is non-random
@@ -82,8 +170,9 @@ impl ProtoAgencyDetector {
 
         // Condition 3: Temporal coherence
         // If local entropy < global entropy, there is local structure
-        // Ratio < 0.9 means at least 10% reduction in local entropy
-        let temporal_coherence_detected = local_global_entropy_ratio < 0.9;
+        // Ratio <= 0.9 means at least 10% reduction in local entropy
+        let temporal_coherence_detected =
+            local_global_entropy_ratio <= TEMPORAL_COHERENCE_RATIO_THRESHOLD;
 
         ProtoAgencyTrigger {
             predictability_exceeds_random,
@@ -92,6 +181,39 @@ impl ProtoAgencyDetector {
         }
     }
 
+    /// Evaluates the conditions and reports the margin by which each
+    /// crossed (or missed) its threshold.
+    ///
+    /// Takes the runs test p-value instead of its pass/fail flag so the
+    /// non-randomness margin can be measured.
+    pub fn evaluate_detailed(
+        max_autocorrelation: f64,
+        randomness_p_value: f64,
+        local_global_entropy_ratio: f64,
+        periodicity_significance: f64,
+    ) -> ProtoAgencyEvaluation {
+        let randomness_test_passed = randomness_p_value > RANDOMNESS_SIGNIFICANCE_LEVEL;
+        let trigger = Self::evaluate(
+            max_autocorrelation,
+            randomness_test_passed,
+            local_global_entropy_ratio,
+            periodicity_significance,
+        );
+
+        let margins = ConditionMargins {
+            predictability: (max_autocorrelation - AUTOCORRELATION_THRESHOLD)
+                .max(periodicity_significance - PERIODICITY_SIGNIFICANCE_THRESHOLD),
+            non_randomness: RANDOMNESS_SIGNIFICANCE_LEVEL - randomness_p_value,
+            temporal_coherence: TEMPORAL_COHERENCE_RATIO_THRESHOLD - local_global_entropy_ratio,
+        };
+
+        ProtoAgencyEvaluation {
+            trigger,
+            margins,
+            proto_agency_margin: margins.overall(),
+        }
+    }
+
     /// Determines if Proto-Agency state should be entered.
     ///
     /// Proto-Agency is entered when AT LEAST TWO conditions are met.
@@ -159,7 +281,7 @@ pub fn runs_test(values: &[f64]) -> (bool, f64) {
     let p_value = 2.0 * (1.0 - normal_cdf(z.abs()));
 
     // Passes if p > 0.05 (cannot reject randomness hypothesis)
-    let passed = p_value > 0.05;
+    let passed = p_value > RANDOMNESS_SIGNIFICANCE_LEVEL;
 
     (passed, p_value)
 }
@@ -253,6 +375,83 @@ mod tests {
         assert!(!ProtoAgencyDetector::should_trigger(&trigger));
     }
 
+    fn assert_only(evaluation: &ProtoAgencyEvaluation, expected: ProtoAgencyCondition) {
+        let fired = evaluation.fired();
+        assert_eq!(fired.len(), 1, "Expected only {:?}, got {:?}", expected, fired);
+        assert_eq!(fired[0].0, expected);
+        assert!(fired[0].1 > 0.0, "Margin should be positive: {:?}", fired[0]);
+        assert!(!evaluation.should_trigger());
+    }
+
+    #[test]
+    fn test_detailed_only_predictability() {
+        let evaluation = ProtoAgencyDetector::evaluate_detailed(0.5, 0.8, 1.0, 0.5);
+        assert_only(&evaluation, ProtoAgencyCondition::PredictabilityExceedsRandom);
+        assert!((evaluation.margins.predictability - 0.2).abs() < 1e-12);
+        assert!(evaluation.margins.non_randomness < 0.0);
+        assert!(evaluation.margins.temporal_coherence < 0.0);
+    }
+
+    #[test]
+    fn test_detailed_only_non_randomness() {
+        let evaluation = ProtoAgencyDetector::evaluate_detailed(0.1, 0.01, 1.0, 0.5);
+        assert_only(&evaluation, ProtoAgencyCondition::NonRandomnessConfirmed);
+        assert!((evaluation.margins.non_randomness - 0.04).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_detailed_only_temporal_coherence() {
+        let evaluation = ProtoAgencyDetector::evaluate_detailed(0.1, 0.8, 0.6, 0.5);
+        assert_only(&evaluation, ProtoAgencyCondition::TemporalCoherenceDetected);
+        assert!((evaluation.margins.temporal_coherence - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_detailed_matches_boolean_evaluation() {
+        let evaluation = ProtoAgencyDetector::evaluate_detailed(0.5, 0.01, 0.7, 3.0);
+        let trigger = ProtoAgencyDetector::evaluate(0.5, false, 0.7, 3.0);
+        assert_eq!(evaluation.trigger, trigger);
+        assert_eq!(evaluation.fired().len(), 3);
+        // Predictability margin is the larger of the two sub-margins
+        assert!((evaluation.margins.predictability - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_overall_margin_sign_matches_trigger() {
+        // Two conditions met: deciding margin is the weaker of them
+        let triggered = ProtoAgencyDetector::evaluate_detailed(0.5, 0.8, 0.6, 0.5);
+        assert!(triggered.should_trigger());
+        assert!((triggered.proto_agency_margin - 0.2).abs() < 1e-12);
+
+        // One condition met: deciding margin is the best of the misses
+        let single = ProtoAgencyDetector::evaluate_detailed(0.5, 0.8, 0.95, 0.5);
+        assert!(!single.should_trigger());
+        assert!((single.proto_agency_margin + 0.05).abs() < 1e-12);
+
+        // Clamped to [-1, 1]
+        let strong = ProtoAgencyDetector::evaluate_detailed(0.9, 0.0, 0.0, 10.0);
+        assert!(strong.proto_agency_margin <= 1.0);
+        let none = ProtoAgencyDetector::evaluate_detailed(-1.0, 1.0, 5.0, -10.0);
+        assert_eq!(none.proto_agency_margin, -1.0);
+    }
+
+    #[test]
+    fn test_zero_margin_is_met() {
+        // Every condition exactly on its threshold
+        let boundary = ProtoAgencyDetector::evaluate_detailed(
+            AUTOCORRELATION_THRESHOLD,
+            RANDOMNESS_SIGNIFICANCE_LEVEL,
+            TEMPORAL_COHERENCE_RATIO_THRESHOLD,
+            0.0,
+        );
+        assert_eq!(boundary.margins.predictability, 0.0);
+        assert_eq!(boundary.margins.non_randomness, 0.0);
+        assert_eq!(boundary.margins.temporal_coherence, 0.0);
+        assert_eq!(boundary.fired().len(), 3);
+        assert_eq!(boundary.proto_agency_margin, 0.0);
+        assert!(boundary.should_trigger());
+    }
+
     #[test]
     fn test_runs_test_random() {
         // Generate pseudo-random sequence
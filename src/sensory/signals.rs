@@ -31,10 +31,16 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Multi-frame accumulation (accumulate/finalize)
+//! 2026-10-16 - Carlos Eduardo Favini - Bonferroni over all accumulated frames (pending_frames)
+//! 2026-10-16 - Carlos Eduardo Favini - SignalAccumulator replaces accumulate/finalize; concatenated autocorrelation window
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
 
+use super::pattern::PatternAnalysis;
+use super::proto_agency::RANDOMNESS_SIGNIFICANCE_LEVEL;
+
 /// Sensory signals computed from raw input.
 /// ONLY mathematics. ZERO interpretation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -211,6 +217,208 @@ impl SensorySignals {
     }
 }
 
+/// Default number of most recent samples kept for the concatenated
+/// autocorrelation window of a `SignalAccumulator`.
+pub const DEFAULT_AUTOCORRELATION_WINDOW: usize = 65_536;
+
+/// Fuses the signals of consecutive frames of one logical input.
+///
+/// Push each frame in order with `push_frame` (signals plus the values
+/// they were computed from) or `push` (signals only), then call
+/// `finalize()` for the fused `SensorySignals`. Frames with
+/// `sample_count == 0` are ignored.
+///
+/// # Exact (up to floating point)
+/// - `sample_count`, `min_value`, `max_value`
+/// - `mean` and `std_dev` (population), via the pairwise
+///   mean/variance merge (Chan et al.)
+/// - `max_autocorrelation`, `autocorrelation_lag`,
+///   `periodicity_significance`: recomputed over the concatenated
+///   frame samples, so autocorrelation spanning frame boundaries is
+///   seen. Exact while every frame came through `push_frame` and the
+///   input fits the window; beyond the window they describe the most
+///   recent `window` samples.
+///
+/// # Approximate
+/// - `entropy`, `spectral_centroid`, `spectral_flatness`,
+///   `local_global_entropy_ratio`, `compressibility`,
+///   `variance_ratio`: sample-weighted means of the per-frame values.
+///   For entropy this is a lower bound on the pooled entropy (concavity).
+/// - `zero_crossing_rate`: transition-weighted mean; crossings at
+///   frame boundaries are not seen.
+/// - `dominant_frequency_index`: from the frame with the stronger
+///   autocorrelation peak, rescaled to the fused length.
+/// - `unique_values`: the larger per-frame count (a lower bound).
+/// - `randomness_test_p_value`: Bonferroni bound `min(1, k·min(p))`
+///   over the `k` fused frames (conservative).
+/// - `stationarity_test_passed`: true only if every frame passed.
+/// - Once a frame arrives through `push`, the window is dropped and the
+///   autocorrelation fields fall back to sample-weighted means, with
+///   the lag of the frame with the stronger peak.
+#[derive(Debug, Clone)]
+pub struct SignalAccumulator {
+    /// Running fusion of the pushed frames (p-value not yet corrected)
+    fused: SensorySignals,
+
+    /// Number of non-empty frames pushed
+    frames: usize,
+
+    /// Most recent concatenated samples; None once a frame came without them
+    window: Option<Vec<f64>>,
+
+    /// Capacity of the autocorrelation window in samples
+    window_limit: usize,
+}
+
+impl Default for SignalAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SignalAccumulator {
+    /// Creates an empty accumulator with the default window.
+    pub fn new() -> Self {
+        Self {
+            fused: SensorySignals::empty(),
+            frames: 0,
+            window: Some(Vec::new()),
+            window_limit: DEFAULT_AUTOCORRELATION_WINDOW,
+        }
+    }
+
+    /// Sets how many of the most recent samples the autocorrelation
+    /// window keeps (at least 1).
+    pub fn with_window(mut self, samples: usize) -> Self {
+        self.window_limit = samples.max(1);
+        self
+    }
+
+    /// Number of non-empty frames fused so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Fuses a frame's signals together with the values they were
+    /// computed from (`RawInput::values`), extending the concatenated
+    /// autocorrelation window.
+    pub fn push_frame(&mut self, values: &[f64], signals: &SensorySignals) {
+        if signals.sample_count == 0 {
+            return;
+        }
+        if let Some(window) = &mut self.window {
+            window.extend_from_slice(values);
+            let excess = window.len().saturating_sub(self.window_limit);
+            window.drain(..excess);
+        }
+        self.fuse(signals);
+    }
+
+    /// Fuses a frame's signals alone.
+    ///
+    /// Without the values the autocorrelation window can no longer be
+    /// kept contiguous, so it is dropped for the rest of the accumulation.
+    pub fn push(&mut self, signals: &SensorySignals) {
+        if signals.sample_count == 0 {
+            return;
+        }
+        self.window = None;
+        self.fuse(signals);
+    }
+
+    fn fuse(&mut self, other: &SensorySignals) {
+        self.frames += 1;
+        let fused = &mut self.fused;
+        if fused.sample_count == 0 {
+            *fused = other.clone();
+            return;
+        }
+
+        let n_a = fused.sample_count as f64;
+        let n_b = other.sample_count as f64;
+        let n = n_a + n_b;
+        let weighted = |a: f64, b: f64| (a * n_a + b * n_b) / n;
+
+        // Exact: pairwise mean / variance merge
+        let delta = other.mean - fused.mean;
+        let m2 = fused.std_dev.powi(2) * n_a
+            + other.std_dev.powi(2) * n_b
+            + delta * delta * n_a * n_b / n;
+        fused.mean += delta * n_b / n;
+        fused.std_dev = (m2 / n).sqrt();
+        fused.min_value = fused.min_value.min(other.min_value);
+        fused.max_value = fused.max_value.max(other.max_value);
+
+        // Approximate: weighted per-frame statistics
+        let t_a = n_a - 1.0;
+        let t_b = n_b - 1.0;
+        if t_a + t_b > 0.0 {
+            fused.zero_crossing_rate =
+                (fused.zero_crossing_rate * t_a + other.zero_crossing_rate * t_b) / (n - 1.0);
+        }
+        fused.entropy = weighted(fused.entropy, other.entropy);
+        fused.spectral_centroid = weighted(fused.spectral_centroid, other.spectral_centroid);
+        fused.spectral_flatness = weighted(fused.spectral_flatness, other.spectral_flatness);
+        fused.local_global_entropy_ratio =
+            weighted(fused.local_global_entropy_ratio, other.local_global_entropy_ratio);
+        fused.compressibility = weighted(fused.compressibility, other.compressibility);
+        fused.variance_ratio = weighted(fused.variance_ratio, other.variance_ratio);
+        fused.periodicity_significance =
+            weighted(fused.periodicity_significance, other.periodicity_significance);
+
+        let (peak, peak_n) = if other.max_autocorrelation > fused.max_autocorrelation {
+            (other, n_b)
+        } else {
+            (&*fused, n_a)
+        };
+        let lag = peak.autocorrelation_lag;
+        let frequency_index = (peak.dominant_frequency_index as f64 * n / peak_n).round() as usize;
+        fused.autocorrelation_lag = lag;
+        fused.dominant_frequency_index = frequency_index;
+        fused.max_autocorrelation = weighted(fused.max_autocorrelation, other.max_autocorrelation);
+
+        fused.unique_values = fused.unique_values.max(other.unique_values);
+        fused.randomness_test_p_value =
+            fused.randomness_test_p_value.min(other.randomness_test_p_value);
+        fused.stationarity_test_passed &= other.stationarity_test_passed;
+        fused.periodicity_detected |= other.periodicity_detected;
+        fused.sample_count += other.sample_count;
+    }
+
+    /// Completes the accumulation.
+    ///
+    /// Recomputes the autocorrelation fields over the concatenated
+    /// window (scalar FFT, bit-exact), applies the Bonferroni correction
+    /// to the randomness p-value, then re-derives the binary test
+    /// results with the same rules as single-frame perception:
+    /// randomness from the p-value, periodicity from significance,
+    /// autocorrelation and lag.
+    pub fn finalize(self) -> SensorySignals {
+        let mut signals = self.fused;
+        if self.frames == 0 {
+            return signals;
+        }
+
+        if let Some(window) = &self.window {
+            let pattern = PatternAnalysis::analyze_deterministic(window);
+            signals.max_autocorrelation = pattern.max_autocorrelation;
+            signals.autocorrelation_lag = pattern.max_autocorrelation_lag;
+            signals.periodicity_significance = pattern.periodicity_significance;
+        }
+
+        let frames = self.frames as f64;
+        signals.randomness_test_p_value = (frames * signals.randomness_test_p_value).min(1.0);
+        signals.randomness_test_passed =
+            signals.randomness_test_p_value > RANDOMNESS_SIGNIFICANCE_LEVEL;
+        signals.periodicity_detected = PatternAnalysis::is_periodic(
+            signals.periodicity_significance,
+            signals.max_autocorrelation,
+            signals.autocorrelation_lag,
+        );
+        signals
+    }
+}
+
 /// Errors in signal validation
 #[derive(Debug, Clone)]
 pub enum SignalValidationError {
@@ -280,4 +488,139 @@ mod tests {
         signals.entropy = 1.5; // Invalid
         assert!(signals.validate().is_err());
     }
+
+    fn perceive(values: &[f64]) -> SensorySignals {
+        use super::super::cortex::{RawInput, SensoryCortex};
+        SensoryCortex::new_deterministic()
+            .perceive(&RawInput::from_samples(values.to_vec()))
+            .signals
+    }
+
+    #[test]
+    fn test_accumulate_halves_approximates_whole() {
+        let values: Vec<f64> = (0..1024)
+            .map(|i| 0.5 + 0.4 * (i as f64 * std::f64::consts::TAU / 32.0).sin())
+            .collect();
+        let whole = perceive(&values);
+
+        let mut accumulator = SignalAccumulator::new();
+        accumulator.push(&perceive(&values[..512]));
+        accumulator.push(&perceive(&values[512..]));
+        let fused = accumulator.finalize();
+
+        // Exact
+        assert_eq!(fused.sample_count, whole.sample_count);
+        assert_eq!(fused.min_value, whole.min_value);
+        assert_eq!(fused.max_value, whole.max_value);
+        assert!((fused.mean - whole.mean).abs() < 1e-9);
+        assert!((fused.std_dev - whole.std_dev).abs() < 1e-9);
+
+        // Approximate
+        assert!((fused.entropy - whole.entropy).abs() < 0.05);
+        assert!((fused.max_autocorrelation - whole.max_autocorrelation).abs() < 0.05);
+        assert!((fused.zero_crossing_rate - whole.zero_crossing_rate).abs() < 0.01);
+        // Both lags sit on the period; the whole may peak at a multiple
+        assert_eq!(fused.autocorrelation_lag % 32, 0);
+        assert_eq!(whole.autocorrelation_lag % 32, 0);
+        assert_eq!(fused.dominant_frequency_index, whole.dominant_frequency_index);
+        assert_eq!(fused.periodicity_detected, whole.periodicity_detected);
+    }
+
+    #[test]
+    fn test_accumulate_concatenated_autocorrelation() {
+        // Frames of 20 samples are shorter than the period of 32
+        let values: Vec<f64> = (0..640)
+            .map(|i| 0.5 + 0.4 * (i as f64 * std::f64::consts::TAU / 32.0).sin())
+            .collect();
+        let whole = perceive(&values);
+
+        let mut accumulator = SignalAccumulator::new();
+        for frame in values.chunks(20) {
+            accumulator.push_frame(frame, &perceive(frame));
+        }
+        assert_eq!(accumulator.frames(), 32);
+        let fused = accumulator.finalize();
+
+        assert_eq!(fused.max_autocorrelation, whole.max_autocorrelation);
+        assert_eq!(fused.autocorrelation_lag, whole.autocorrelation_lag);
+        assert_eq!(fused.periodicity_significance, whole.periodicity_significance);
+        assert!(fused.periodicity_detected);
+        assert_eq!(fused.periodicity_detected, whole.periodicity_detected);
+
+        // A bounded window describes the most recent samples
+        let mut bounded = SignalAccumulator::new().with_window(256);
+        for frame in values.chunks(20) {
+            bounded.push_frame(frame, &perceive(frame));
+        }
+        let bounded = bounded.finalize();
+        let tail = perceive(&values[640 - 256..]);
+        assert_eq!(bounded.sample_count, 640);
+        assert_eq!(bounded.max_autocorrelation, tail.max_autocorrelation);
+        assert_eq!(bounded.autocorrelation_lag, tail.autocorrelation_lag);
+    }
+
+    #[test]
+    fn test_accumulate_mean_variance_exact() {
+        let values: Vec<f64> = (0..300).map(|i| ((i * 37) % 101) as f64 / 100.0).collect();
+        let whole = perceive(&values);
+
+        let mut accumulator = SignalAccumulator::new();
+        for chunk in values.chunks(70) {
+            accumulator.push_frame(chunk, &perceive(chunk));
+        }
+        let fused = accumulator.finalize();
+
+        assert_eq!(fused.sample_count, 300);
+        assert!((fused.mean - whole.mean).abs() < 1e-9);
+        assert!((fused.std_dev - whole.std_dev).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accumulate_bonferroni_over_all_frames() {
+        let values: Vec<f64> = (0..500).map(|i| ((i * 37) % 101) as f64 / 100.0).collect();
+        let frames: Vec<SensorySignals> = values.chunks(100).map(perceive).collect();
+        let min_p = frames
+            .iter()
+            .map(|f| f.randomness_test_p_value)
+            .fold(f64::INFINITY, f64::min);
+
+        let mut accumulator = SignalAccumulator::new();
+        for frame in &frames {
+            accumulator.push(frame);
+        }
+        assert_eq!(accumulator.frames(), 5);
+
+        let fused = accumulator.finalize();
+        assert_eq!(fused.randomness_test_p_value, (5.0 * min_p).min(1.0));
+        assert_eq!(
+            fused.randomness_test_passed,
+            fused.randomness_test_p_value > RANDOMNESS_SIGNIFICANCE_LEVEL
+        );
+
+        // A single frame is left unchanged
+        let mut single = SignalAccumulator::new();
+        single.push(&frames[0]);
+        let single = single.finalize();
+        assert_eq!(single.randomness_test_p_value, frames[0].randomness_test_p_value);
+    }
+
+    #[test]
+    fn test_accumulate_empty_is_identity() {
+        let values = [0.1, 0.9, 0.4, 0.6, 0.2, 0.8];
+        let signals = perceive(&values);
+
+        let mut accumulator = SignalAccumulator::new();
+        accumulator.push_frame(&values, &signals);
+        accumulator.push(&SensorySignals::empty());
+        assert_eq!(accumulator.frames(), 1);
+        let fused = accumulator.finalize();
+
+        assert_eq!(fused.sample_count, signals.sample_count);
+        assert_eq!(fused.mean, signals.mean);
+        assert_eq!(fused.entropy, signals.entropy);
+        assert_eq!(fused.max_autocorrelation, signals.max_autocorrelation);
+
+        let nothing = SignalAccumulator::new().finalize();
+        assert_eq!(nothing.sample_count, 0);
+    }
 }
@@ -32,6 +32,7 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
+//! 2026-10-16 - Carlos Eduardo Favini - PerceptualStateKind classifier
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
@@ -99,6 +100,54 @@ impl PerceptualState {
             Self::Emitting => "Emitting",
         }
     }
+
+    /// Returns the coarse kind of this state
+    pub fn kind(&self) -> PerceptualStateKind {
+        match self {
+            Self::Listening => PerceptualStateKind::Idle,
+            Self::PerceivingCarrier | Self::PerceivingPattern | Self::PerceivingStructure => {
+                PerceptualStateKind::Perceiving
+            }
+            Self::ProtoAgencyDetected { .. } => PerceptualStateKind::ProtoAgency,
+            Self::Emitting => PerceptualStateKind::Output,
+        }
+    }
+
+    /// Returns true while perception is in progress (including Proto-Agency)
+    pub fn is_perceiving(&self) -> bool {
+        self.kind().is_perceiving()
+    }
+
+    /// Returns true for states that begin or end a cycle (Listening, Emitting)
+    pub fn is_terminal(&self) -> bool {
+        self.kind().is_terminal()
+    }
+}
+
+/// Coarse classification of a perceptual state.
+/// Derived metadata only; it carries no information beyond the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PerceptualStateKind {
+    /// Waiting for input (Listening)
+    Idle,
+    /// Processing levels 0-2 (Carrier, Pattern, Structure)
+    Perceiving,
+    /// Suspected intentionality (Level 2.5)
+    ProtoAgency,
+    /// Emitting output
+    Output,
+}
+
+impl PerceptualStateKind {
+    /// Returns true for Perceiving and ProtoAgency
+    pub fn is_perceiving(&self) -> bool {
+        matches!(self, Self::Perceiving | Self::ProtoAgency)
+    }
+
+    /// Returns true for Idle and Output
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Idle | Self::Output)
+    }
 }
 
 impl Default for PerceptualState {
@@ -162,6 +211,33 @@ impl ProtoAgencyTrigger {
         }
         count
     }
+
+    /// Returns the conditions that were met, in declaration order
+    pub fn fired(&self) -> Vec<ProtoAgencyCondition> {
+        let mut fired = Vec::new();
+        if self.predictability_exceeds_random {
+            fired.push(ProtoAgencyCondition::PredictabilityExceedsRandom);
+        }
+        if self.non_randomness_confirmed {
+            fired.push(ProtoAgencyCondition::NonRandomnessConfirmed);
+        }
+        if self.temporal_coherence_detected {
+            fired.push(ProtoAgencyCondition::TemporalCoherenceDetected);
+        }
+        fired
+    }
+}
+
+/// Identifies one mathematical Proto-Agency condition.
+/// Names a statistical test, never an intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ProtoAgencyCondition {
+    /// Autocorrelation or periodicity above the noise threshold
+    PredictabilityExceedsRandom,
+    /// Runs test rejects randomness
+    NonRandomnessConfirmed,
+    /// Local entropy significantly below global entropy
+    TemporalCoherenceDetected,
 }
 
 impl Default for ProtoAgencyTrigger {
@@ -197,6 +273,89 @@ impl StateTransition {
             sequence,
         }
     }
+
+    /// Creates a transition, rejecting jumps the hierarchy does not allow.
+    ///
+    /// `new` stays permissive so recorded histories can always be
+    /// reconstructed for replay.
+    pub fn new_checked(
+        from: PerceptualState,
+        to: PerceptualState,
+        timestamp_ns: u64,
+        sequence: u64,
+    ) -> Result<Self, IllegalTransition> {
+        let transition = Self::new(from, to, timestamp_ns, sequence);
+        if transition.is_legal() {
+            Ok(transition)
+        } else {
+            Err(IllegalTransition {
+                from: transition.from,
+                to: transition.to,
+            })
+        }
+    }
+
+    /// Returns true if this transition respects the abstraction hierarchy.
+    ///
+    /// # Allowed
+    /// - Forward by one level: Listening → Carrier → Pattern → Structure → Proto-Agency
+    /// - Emitting from any perceiving state (the pipeline may stop at any level)
+    /// - Regression to a lower perceiving level, or back to Listening
+    ///
+    /// Everything else (multi-level jumps, self-transitions, leaving
+    /// Listening for anything but Carrier) is illegal.
+    pub fn is_legal(&self) -> bool {
+        use PerceptualState::*;
+
+        match (&self.from, &self.to) {
+            // Forward by one level
+            (Listening, PerceivingCarrier)
+            | (PerceivingCarrier, PerceivingPattern)
+            | (PerceivingPattern, PerceivingStructure)
+            | (PerceivingStructure, ProtoAgencyDetected { .. }) => true,
+
+            // Emit from any perceiving state
+            (Listening | Emitting, Emitting) => false,
+            (_, Emitting) => true,
+
+            // Return to listening
+            (Listening, Listening) => false,
+            (_, Listening) => true,
+
+            // Regression to a lower perceiving level
+            (from, to) => to.level() < from.level() && !matches!(from, Emitting | Listening),
+        }
+    }
+}
+
+/// A transition that skips or reverses levels illegally.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IllegalTransition {
+    /// State before the rejected transition
+    pub from: PerceptualState,
+    /// Rejected target state
+    pub to: PerceptualState,
+}
+
+impl std::fmt::Display for IllegalTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Illegal transition {} -> {}", self.from.name(), self.to.name())
+    }
+}
+
+impl std::error::Error for IllegalTransition {}
+
+/// A repeating cycle at the end of a state history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoopInfo {
+    /// Index of the first transition of the earliest repetition
+    pub start: usize,
+
+    /// Number of transitions in one cycle
+    pub length: usize,
+
+    /// Number of consecutive complete cycles (at least 2)
+    pub repetitions: usize,
 }
 
 /// Tracks the complete state history of a perception cycle.
@@ -263,6 +422,45 @@ impl StateHistory {
             .map(|t| t.to.level())
             .fold(0.0_f64, f64::max)
     }
+
+    /// Returns true if every recorded transition is legal
+    pub fn is_legal(&self) -> bool {
+        self.transitions.iter().all(StateTransition::is_legal)
+    }
+
+    /// Detects the shortest state cycle repeating at the end of the history.
+    ///
+    /// Compares the target states of the transitions by name (same
+    /// criterion as the observability `OscillationDetector`). A cycle is
+    /// reported when its last occurrence is immediately preceded by at
+    /// least one identical occurrence; `start` then points at the
+    /// earliest of the consecutive repetitions.
+    pub fn detect_loop(&self) -> Option<LoopInfo> {
+        let names: Vec<&'static str> = self.transitions.iter().map(|t| t.to.name()).collect();
+        let n = names.len();
+
+        for length in 1..=n / 2 {
+            let cycle = &names[n - length..];
+            let mut repetitions = 1;
+            while (repetitions + 1) * length <= n {
+                let end = n - repetitions * length;
+                if &names[end - length..end] != cycle {
+                    break;
+                }
+                repetitions += 1;
+            }
+
+            if repetitions >= 2 {
+                return Some(LoopInfo {
+                    start: n - repetitions * length,
+                    length,
+                    repetitions,
+                });
+            }
+        }
+
+        None
+    }
 }
 
 #[cfg(test)]
@@ -284,6 +482,98 @@ mod tests {
         );
     }
 
+    fn proto() -> PerceptualState {
+        PerceptualState::ProtoAgencyDetected { trigger: ProtoAgencyTrigger::new() }
+    }
+
+    #[test]
+    fn test_state_kinds() {
+        let cases = [
+            (PerceptualState::Listening, PerceptualStateKind::Idle),
+            (PerceptualState::PerceivingCarrier, PerceptualStateKind::Perceiving),
+            (PerceptualState::PerceivingPattern, PerceptualStateKind::Perceiving),
+            (PerceptualState::PerceivingStructure, PerceptualStateKind::Perceiving),
+            (
+                PerceptualState::ProtoAgencyDetected { trigger: ProtoAgencyTrigger::new() },
+                PerceptualStateKind::ProtoAgency,
+            ),
+            (PerceptualState::Emitting, PerceptualStateKind::Output),
+        ];
+
+        for (state, kind) in cases {
+            assert_eq!(state.kind(), kind, "{}", state.name());
+            assert_eq!(state.is_perceiving(), kind.is_perceiving());
+            assert_eq!(state.is_terminal(), kind.is_terminal());
+            // Every state is exactly one of perceiving / terminal
+            assert_ne!(state.is_perceiving(), state.is_terminal());
+        }
+
+        assert!(PerceptualState::Listening.is_terminal());
+        assert!(PerceptualState::Emitting.is_terminal());
+        assert!(PerceptualState::PerceivingPattern.is_perceiving());
+    }
+
+    #[test]
+    fn test_legal_single_step_advances() {
+        use PerceptualState::*;
+        let chain = [Listening, PerceivingCarrier, PerceivingPattern, PerceivingStructure, proto()];
+        for pair in chain.windows(2) {
+            let t = StateTransition::new_checked(pair[0].clone(), pair[1].clone(), 0, 0);
+            assert!(t.is_ok(), "{} -> {} should be legal", pair[0].name(), pair[1].name());
+        }
+
+        // Emitting and returning to Listening are always reachable while perceiving
+        for from in [PerceivingCarrier, PerceivingPattern, PerceivingStructure, proto()] {
+            assert!(StateTransition::new(from.clone(), Emitting, 0, 0).is_legal());
+        }
+        assert!(StateTransition::new(Emitting, Listening, 0, 0).is_legal());
+        // Regression
+        assert!(StateTransition::new(PerceivingStructure, PerceivingPattern, 0, 0).is_legal());
+    }
+
+    #[test]
+    fn test_illegal_multi_level_jump() {
+        use PerceptualState::*;
+        let err = StateTransition::new_checked(PerceivingCarrier, proto(), 0, 0).unwrap_err();
+        assert_eq!(err.from, PerceivingCarrier);
+        assert!(err.to.is_proto_agency());
+
+        assert!(!StateTransition::new(PerceivingCarrier, PerceivingStructure, 0, 0).is_legal());
+        assert!(!StateTransition::new(Listening, PerceivingPattern, 0, 0).is_legal());
+        assert!(!StateTransition::new(PerceivingPattern, PerceivingPattern, 0, 0).is_legal());
+        assert!(!StateTransition::new(Listening, Emitting, 0, 0).is_legal());
+
+        // `new` stays permissive for replay reconstruction
+        let recorded = StateTransition::new(PerceivingCarrier, proto(), 0, 0);
+        assert!(!recorded.is_legal());
+    }
+
+    #[test]
+    fn test_detect_loop_alternating() {
+        let mut history = StateHistory::new(0);
+        history.transition_to(PerceptualState::PerceivingCarrier, 1);
+        for ts in 0..3 {
+            history.transition_to(PerceptualState::PerceivingPattern, 10 + 2 * ts);
+            history.transition_to(PerceptualState::PerceivingStructure, 11 + 2 * ts);
+        }
+
+        let info = history.detect_loop().expect("A→B→A→B loop should be detected");
+        assert_eq!(info, LoopInfo { start: 1, length: 2, repetitions: 3 });
+    }
+
+    #[test]
+    fn test_detect_loop_progressing() {
+        let mut history = StateHistory::new(0);
+        assert!(history.detect_loop().is_none());
+
+        history.transition_to(PerceptualState::PerceivingCarrier, 1);
+        history.transition_to(PerceptualState::PerceivingPattern, 2);
+        history.transition_to(PerceptualState::PerceivingStructure, 3);
+        history.transition_to(PerceptualState::Emitting, 4);
+        history.transition_to(PerceptualState::Listening, 5);
+        assert!(history.detect_loop().is_none());
+    }
+
     #[test]
     fn test_trigger_count() {
         let mut trigger = ProtoAgencyTrigger::new();
@@ -31,9 +31,10 @@
 //! CHANGE LOG
 //! --------------------------
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation (v1.1.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Entropy delegated to math::stats
 //! --------------------------
 
-use std::collections::BTreeMap;
+use crate::math::stats::{self, EntropyBase};
 
 /// Result of structure-level analysis
 #[derive(Debug, Clone)]
@@ -58,7 +59,86 @@ pub struct StructureAnalysis {
     pub mean_segment_length: f64,
 }
 
+/// Sliding-window entropy profile (localizes where structure exists)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyProfile {
+    /// Shannon entropy of each window, in window order
+    pub local_entropy_profile: Vec<f64>,
+
+    /// Shannon entropy of the whole signal
+    pub global_entropy: f64,
+
+    /// Standard deviation of the local entropies
+    /// (0 = homogeneous, higher = structure concentrated in regions)
+    pub structure_heterogeneity: f64,
+}
+
+/// Invalid entropy profile parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntropyProfileError {
+    /// Window size must be at least 1
+    ZeroWindow,
+    /// Step must be at least 1
+    ZeroStep,
+}
+
+impl std::fmt::Display for EntropyProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntropyProfileError::ZeroWindow => write!(f, "Window size must be greater than zero"),
+            EntropyProfileError::ZeroStep => write!(f, "Step must be greater than zero"),
+        }
+    }
+}
+
+impl std::error::Error for EntropyProfileError {}
+
 impl StructureAnalysis {
+    /// Computes local entropy over a sliding window alongside global entropy.
+    ///
+    /// Windows start at 0, step, 2·step, ... while they fit in the input.
+    /// A window larger than the input is clamped to the full input
+    /// (single window). Empty input yields an empty profile.
+    pub fn entropy_profile(
+        values: &[f64],
+        window_size: usize,
+        step: usize,
+    ) -> Result<EntropyProfile, EntropyProfileError> {
+        if window_size == 0 {
+            return Err(EntropyProfileError::ZeroWindow);
+        }
+        if step == 0 {
+            return Err(EntropyProfileError::ZeroStep);
+        }
+        if values.is_empty() {
+            return Ok(EntropyProfile {
+                local_entropy_profile: Vec::new(),
+                global_entropy: 0.0,
+                structure_heterogeneity: 0.0,
+            });
+        }
+
+        let window = window_size.min(values.len());
+        let local_entropy_profile: Vec<f64> = (0..=values.len() - window)
+            .step_by(step)
+            .map(|start| Self::compute_entropy(&values[start..start + window]))
+            .collect();
+
+        let n = local_entropy_profile.len() as f64;
+        let mean = local_entropy_profile.iter().sum::<f64>() / n;
+        let variance = local_entropy_profile
+            .iter()
+            .map(|h| (h - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Ok(EntropyProfile {
+            local_entropy_profile,
+            global_entropy: Self::compute_entropy(values),
+            structure_heterogeneity: variance.sqrt(),
+        })
+    }
+
     /// Analyzes structure in a signal
     pub fn analyze(values: &[f64]) -> Self {
         if values.len() < 16 {
@@ -112,39 +192,9 @@ impl StructureAnalysis {
         }
     }
 
-    /// Computes Shannon entropy of values
+    /// Computes Shannon entropy of values (64 bins, bits)
     fn compute_entropy(values: &[f64]) -> f64 {
-        if values.is_empty() {
-            return 0.0;
-        }
-
-        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
-        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
-
-        if (max - min).abs() < f64::EPSILON {
-            return 0.0;
-        }
-
-        let num_bins = 64usize;
-        let bin_width = (max - min) / num_bins as f64;
-
-        let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
-        for &v in values {
-            let bin = ((v - min) / bin_width).floor() as usize;
-            let bin = bin.min(num_bins - 1);
-            *counts.entry(bin).or_insert(0) += 1;
-        }
-
-        let n = values.len() as f64;
-        let mut entropy = 0.0;
-        for &count in counts.values() {
-            if count > 0 {
-                let p = count as f64 / n;
-                entropy -= p * p.log2();
-            }
-        }
-
-        entropy
+        stats::shannon_entropy(&stats::histogram(values, 64), EntropyBase::Bits)
     }
 
     /// Computes average local entropy over windows
@@ -406,6 +456,65 @@ mod tests {
             "Mean-shifted signal should be non-stationary");
     }
 
+    /// First half alternates between two levels, second half is LCG noise
+    fn half_ordered_half_random() -> Vec<f64> {
+        let mut values: Vec<f64> = (0..256).map(|i| (i % 2) as f64).collect();
+        let mut state: u64 = 12345;
+        for _ in 0..256 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            values.push((state >> 33) as f64 / (1u64 << 31) as f64);
+        }
+        values
+    }
+
+    #[test]
+    fn test_entropy_profile_localizes_structure() {
+        let values = half_ordered_half_random();
+        let profile = StructureAnalysis::entropy_profile(&values, 32, 32).unwrap();
+
+        assert_eq!(profile.local_entropy_profile.len(), 16);
+        let (ordered, random) = profile.local_entropy_profile.split_at(8);
+        let max_ordered = ordered.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let min_random = random.iter().cloned().fold(f64::INFINITY, f64::min);
+
+        assert!(max_ordered <= 1.0 + 1e-9, "Ordered windows: {:?}", ordered);
+        assert!(min_random > 3.0, "Random windows: {:?}", random);
+        assert!(profile.structure_heterogeneity > 1.0);
+        assert!(profile.global_entropy > max_ordered);
+    }
+
+    #[test]
+    fn test_entropy_profile_homogeneous_signal() {
+        let values: Vec<f64> = (0..256).map(|i| (i % 2) as f64).collect();
+        let profile = StructureAnalysis::entropy_profile(&values, 16, 8).unwrap();
+        assert_eq!(profile.local_entropy_profile.len(), 31);
+        assert!(profile.structure_heterogeneity < 1e-12);
+    }
+
+    #[test]
+    fn test_entropy_profile_edge_cases() {
+        let values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+
+        // Window larger than input clamps to the full input
+        let profile = StructureAnalysis::entropy_profile(&values, 100, 5).unwrap();
+        assert_eq!(profile.local_entropy_profile.len(), 1);
+        assert_eq!(profile.local_entropy_profile[0], profile.global_entropy);
+        assert_eq!(profile.structure_heterogeneity, 0.0);
+
+        assert_eq!(
+            StructureAnalysis::entropy_profile(&values, 4, 0),
+            Err(EntropyProfileError::ZeroStep)
+        );
+        assert_eq!(
+            StructureAnalysis::entropy_profile(&values, 0, 1),
+            Err(EntropyProfileError::ZeroWindow)
+        );
+        assert!(StructureAnalysis::entropy_profile(&[], 4, 1)
+            .unwrap()
+            .local_entropy_profile
+            .is_empty());
+    }
+
     #[test]
     fn test_empty_signal() {
         let values: Vec<f64> = vec![];
@@ -18,6 +18,8 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Initial creation
 //! 2025-01-02 - Carlos Eduardo Favini - Added Result-based API (v0.2.0)
 //! 2025-01-02 - Carlos Eduardo Favini - Replaced clamp with validation (v0.2.0)
+//! 2026-10-16 - Carlos Eduardo Favini - Observational activation propagation
+//! 2026-10-16 - Carlos Eduardo Favini - Weak-edge and weight histogram observations
 //! --------------------------
 
 use serde::{Deserialize, Serialize};
@@ -269,6 +271,43 @@ impl NeuronalGraph {
     pub fn edge_count(&self) -> usize {
         self.edge_count
     }
+
+    /// Observes edges whose weight is strictly below `threshold`.
+    ///
+    /// These are the connections a downstream consumer might choose to
+    /// prune; the graph itself is left untouched. Sorted by weight, then
+    /// by endpoint ids, so the result is deterministic.
+    pub fn weak_edges(&self, threshold: f64) -> Vec<(NeuronId, NeuronId, f64)> {
+        let mut edges: Vec<(NeuronId, NeuronId, f64)> = self
+            .weights
+            .iter()
+            .filter(|(_, &w)| w < threshold)
+            .map(|(&(from, to), &w)| (from, to, w))
+            .collect();
+        edges.sort_by(|a, b| {
+            a.2.total_cmp(&b.2)
+                .then_with(|| a.0 .0.cmp(&b.0 .0))
+                .then_with(|| a.1 .0.cmp(&b.1 .0))
+        });
+        edges
+    }
+
+    /// Observes the distribution of edge weights.
+    ///
+    /// Splits [0, 1] into `bins` equal-width buckets; bucket `k` counts
+    /// weights in [k/bins, (k+1)/bins), with 1.0 falling in the last
+    /// bucket. Returns an empty vector when `bins` is 0.
+    pub fn edge_weight_histogram(&self, bins: usize) -> Vec<usize> {
+        let mut histogram = vec![0; bins];
+        if bins == 0 {
+            return histogram;
+        }
+        for &w in self.weights.values() {
+            let index = ((w * bins as f64) as usize).min(bins - 1);
+            histogram[index] += 1;
+        }
+        histogram
+    }
 }
 
 impl Default for NeuronalGraph {
@@ -277,6 +316,70 @@ impl Default for NeuronalGraph {
     }
 }
 
+/// Observes how an initial activation spreads through a neuronal graph.
+///
+/// Pure observation: the graph and its weights are never modified.
+///
+/// # Aggregation Rule
+/// At each step every neuron j receives the weighted sum of the current
+/// activations of its predecessors and keeps the larger of that and its
+/// own activation:
+///
+/// a_{t+1}(j) = clamp(max(a_t(j), Σ_i a_t(i) × w(i, j)), 0, 1)
+///
+/// Activations therefore never decrease, a single path attenuates by the
+/// product of its weights, and a neuron k hops away is reached after k
+/// steps. Propagation stops early once no activation changes.
+///
+/// Initial activations are clamped to [0, 1] (NaN becomes 0). Sources
+/// are summed in neuron-id order, so the result is bit-for-bit
+/// deterministic. The result contains every initial neuron plus every
+/// neuron that received a positive activation.
+pub fn propagate_activation(
+    graph: &NeuronalGraph,
+    initial: &HashMap<NeuronId, f64>,
+    steps: usize,
+) -> HashMap<NeuronId, f64> {
+    let clamp = |a: f64| if a.is_nan() { 0.0 } else { a.clamp(0.0, 1.0) };
+    let mut current: HashMap<NeuronId, f64> = initial
+        .iter()
+        .map(|(id, &a)| (*id, clamp(a)))
+        .collect();
+
+    for _ in 0..steps {
+        let mut sources: Vec<(&NeuronId, &f64)> =
+            current.iter().filter(|(_, &a)| a > 0.0).collect();
+        sources.sort_by_key(|(id, _)| id.0);
+
+        let mut incoming: HashMap<NeuronId, f64> = HashMap::new();
+        for (from, &activation) in sources {
+            let Some(targets) = graph.get_outgoing(from) else { continue };
+            let mut targets: Vec<&NeuronId> = targets.iter().collect();
+            targets.sort_by_key(|id| id.0);
+            for to in targets {
+                let weight = graph.get_weight(from, to).unwrap_or(0.0);
+                *incoming.entry(*to).or_insert(0.0) += activation * weight;
+            }
+        }
+
+        let mut next = current.clone();
+        for (id, sum) in incoming {
+            let previous = current.get(&id).copied().unwrap_or(0.0);
+            let value = clamp(previous.max(sum));
+            if value > 0.0 || current.contains_key(&id) {
+                next.insert(id, value);
+            }
+        }
+
+        if next == current {
+            break;
+        }
+        current = next;
+    }
+
+    current
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,4 +465,76 @@ mod tests {
         assert!(!was_clamped);
         assert!((effective - 0.7).abs() < 1e-10);
     }
+
+    fn chain() -> (NeuronalGraph, NeuronId, NeuronId, NeuronId) {
+        let (a, b, c) = (NeuronId::new(), NeuronId::new(), NeuronId::new());
+        let mut graph = NeuronalGraph::new();
+        graph.record_connection(a, b, 0.5).unwrap();
+        graph.record_connection(b, c, 0.4).unwrap();
+        (graph, a, b, c)
+    }
+
+    #[test]
+    fn test_propagation_attenuates_along_chain() {
+        let (graph, a, b, c) = chain();
+        let initial = HashMap::from([(a, 1.0)]);
+
+        let one = propagate_activation(&graph, &initial, 1);
+        assert!((one[&b] - 0.5).abs() < 1e-12);
+        assert!(!one.contains_key(&c));
+
+        let two = propagate_activation(&graph, &initial, 2);
+        assert_eq!(two[&a], 1.0);
+        assert!((two[&b] - 0.5).abs() < 1e-12);
+        assert!((two[&c] - 0.2).abs() < 1e-12);
+
+        // Converged: more steps change nothing
+        assert_eq!(propagate_activation(&graph, &initial, 10), two);
+        // Observation only
+        assert_eq!(graph.get_weight(&a, &b), Some(0.5));
+    }
+
+    #[test]
+    fn test_propagation_sums_and_clamps() {
+        let (a, b, c) = (NeuronId::new(), NeuronId::new(), NeuronId::new());
+        let mut graph = NeuronalGraph::new();
+        graph.record_connection(a, c, 0.8).unwrap();
+        graph.record_connection(b, c, 0.7).unwrap();
+
+        let result = propagate_activation(&graph, &HashMap::from([(a, 1.0), (b, 1.0)]), 1);
+        assert_eq!(result[&c], 1.0); // 0.8 + 0.7 clamped
+
+        let result = propagate_activation(&graph, &HashMap::from([(a, 0.5), (b, 2.0)]), 1);
+        assert_eq!(result[&b], 1.0);
+        assert!((result[&c] - 1.0).abs() < 1e-12); // 0.4 + 0.7 clamped
+    }
+
+    #[test]
+    fn test_propagation_zero_steps_is_identity() {
+        let (graph, a, _, _) = chain();
+        let initial = HashMap::from([(a, 0.3)]);
+        assert_eq!(propagate_activation(&graph, &initial, 0), initial);
+    }
+
+    #[test]
+    fn test_weak_edges_and_histogram() {
+        let n: Vec<NeuronId> = (0..5).map(|_| NeuronId::new()).collect();
+        let mut graph = NeuronalGraph::new();
+        graph.record_connection(n[0], n[1], 0.05).unwrap();
+        graph.record_connection(n[0], n[2], 0.3).unwrap();
+        graph.record_connection(n[1], n[3], 0.1).unwrap();
+        graph.record_connection(n[2], n[3], 0.6).unwrap();
+        graph.record_connection(n[3], n[4], 1.0).unwrap();
+
+        let weak = graph.weak_edges(0.3);
+        assert_eq!(weak, vec![(n[0], n[1], 0.05), (n[1], n[3], 0.1)]);
+        assert!(graph.weak_edges(0.0).is_empty());
+        assert_eq!(graph.weak_edges(1.5).len(), 5);
+
+        assert_eq!(graph.edge_weight_histogram(4), vec![2, 1, 1, 1]);
+        assert_eq!(graph.edge_weight_histogram(1), vec![5]);
+        assert!(graph.edge_weight_histogram(0).is_empty());
+        // Observation only
+        assert_eq!(graph.edge_count(), 5);
+    }
 }
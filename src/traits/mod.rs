@@ -20,6 +20,8 @@
 //! 2025-01-02 - Carlos Eduardo Favini - Added replay traits (v0.3.0)
 //! --------------------------
 
+use sha2::{Digest, Sha256};
+
 use crate::core_types::DnaId;
 use crate::hierarchy::{GoldenDna, FoucaultianTruth, PlatonicTruth};
 use crate::math::craft::CraftPerformanceResult;
@@ -163,6 +165,33 @@ impl MeristicProposal {
             description,
         }
     }
+
+    /// Creates a proposal whose ID is derived from its content.
+    ///
+    /// ID = hex SHA-256 of the proposed DNA's content fingerprint plus
+    /// the description, so identical proposals always share an ID and
+    /// `MeristicHandler` implementations can handle them idempotently.
+    pub fn from_content(
+        proposed_dna: GoldenDna,
+        expected_improvement: f64,
+        confidence: f64,
+        description: String,
+    ) -> Self {
+        let id = Self::content_id(&proposed_dna, &description);
+        Self::new(id, proposed_dna, expected_improvement, confidence, description)
+    }
+
+    /// Computes the content-derived ID used by `from_content`.
+    pub fn content_id(proposed_dna: &GoldenDna, description: &str) -> String {
+        let data = format!(
+            "dna:{}|description:{}",
+            proposed_dna.content_fingerprint(),
+            description
+        );
+        let mut hasher = Sha256::new();
+        hasher.update(data.as_bytes());
+        hex::encode(hasher.finalize())
+    }
 }
 
 /// Trait for governance enforcement.
@@ -179,6 +208,45 @@ pub trait GovernanceEnforcer {
     fn enforce_veto(&mut self, dna_id: &DnaId, reason: &str) -> Result<(), Self::Error>;
 }
 
+/// Machine-readable governance failure category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ViolationCode {
+    /// CP or a motor score fell below VETO_THRESHOLD.
+    VetoThresholdBreached,
+    /// CP is NaN or infinite.
+    NonFiniteCraftPerformance,
+    /// DNA lacks the lineage/provenance governance requires.
+    MissingProvenance,
+    /// Free-form violation (see `Violation::detail`).
+    Other,
+}
+
+/// A single governance violation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// Failure category.
+    pub code: ViolationCode,
+
+    /// Human-readable detail.
+    pub detail: String,
+}
+
+impl Violation {
+    /// Creates a new violation.
+    pub fn new(code: ViolationCode, detail: impl Into<String>) -> Self {
+        Self {
+            code,
+            detail: detail.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.detail)
+    }
+}
+
 /// Result of governance validation.
 #[derive(Debug, Clone)]
 pub struct GovernanceResult {
@@ -186,7 +254,7 @@ pub struct GovernanceResult {
     pub passed: bool,
 
     /// Violations found (if any).
-    pub violations: Vec<String>,
+    pub violations: Vec<Violation>,
 
     /// Recommendations.
     pub recommendations: Vec<String>,
@@ -203,13 +271,28 @@ impl GovernanceResult {
     }
 
     /// Creates a failing result with violations.
-    pub fn fail(violations: Vec<String>) -> Self {
+    pub fn fail(violations: Vec<Violation>) -> Self {
         Self {
             passed: false,
             violations,
             recommendations: Vec::new(),
         }
     }
+
+    /// Creates a failing result from free-form messages (code `Other`).
+    pub fn fail_messages(messages: Vec<String>) -> Self {
+        Self::fail(
+            messages
+                .into_iter()
+                .map(|m| Violation::new(ViolationCode::Other, m))
+                .collect(),
+        )
+    }
+
+    /// Returns true if any violation carries `code`.
+    pub fn has_code(&self, code: ViolationCode) -> bool {
+        self.violations.iter().any(|v| v.code == code)
+    }
 }
 
 // =============================================================================
@@ -270,3 +353,67 @@ pub struct ReplayVerificationResult {
     /// Error message if verification failed.
     pub error: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hierarchy::{ActionSequence, MotorScores, ObservedAction};
+    use std::collections::BTreeMap;
+
+    fn sample_dna() -> GoldenDna {
+        let action = ObservedAction::new(
+            "sensor-1".to_string(),
+            1_000,
+            BTreeMap::new(),
+            serde_json::json!({"value": 42}),
+        )
+        .unwrap();
+        GoldenDna::primordial(
+            ActionSequence::from_actions(vec![action]),
+            MotorScores::new(0.9, 0.8, 0.7, 0.6),
+        )
+    }
+
+    #[test]
+    fn test_content_id_identical_proposals() {
+        let a = MeristicProposal::from_content(sample_dna(), 0.1, 0.5, "swap steps".to_string());
+        let b = MeristicProposal::from_content(sample_dna(), 0.3, 0.9, "swap steps".to_string());
+
+        // Random DNA ids differ, content does not
+        assert_ne!(a.proposed_dna.id, b.proposed_dna.id);
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.id.len(), 64);
+    }
+
+    #[test]
+    fn test_governance_has_code() {
+        let result = GovernanceResult::fail(vec![
+            Violation::new(ViolationCode::VetoThresholdBreached, "M_N below 1e-15"),
+            Violation::new(ViolationCode::MissingProvenance, "empty lineage"),
+        ]);
+
+        assert!(!result.passed);
+        assert!(result.has_code(ViolationCode::VetoThresholdBreached));
+        assert!(result.has_code(ViolationCode::MissingProvenance));
+        assert!(!result.has_code(ViolationCode::NonFiniteCraftPerformance));
+        assert!(!GovernanceResult::pass().has_code(ViolationCode::VetoThresholdBreached));
+    }
+
+    #[test]
+    fn test_governance_fail_messages() {
+        let result = GovernanceResult::fail_messages(vec!["custom rule".to_string()]);
+        assert!(result.has_code(ViolationCode::Other));
+        assert_eq!(result.violations[0].detail, "custom rule");
+    }
+
+    #[test]
+    fn test_content_id_changes_with_description() {
+        let a = MeristicProposal::from_content(sample_dna(), 0.1, 0.5, "swap steps".to_string());
+        let b = MeristicProposal::from_content(sample_dna(), 0.1, 0.5, "merge steps".to_string());
+        assert_ne!(a.id, b.id);
+
+        // Manual constructor keeps the caller's id
+        let manual = MeristicProposal::new("p-1".to_string(), sample_dna(), 0.1, 0.5, String::new());
+        assert_eq!(manual.id, "p-1");
+    }
+}
@@ -11,10 +11,11 @@
 
 use crate::cognitive::{
     TransportCode, ObservationReport, MotorSignatures,
-    CognitiveCycle, MotorContext,
+    CognitiveCycle, MotorContext, MotorScores, FrameError,
 };
 use crate::sensory::SensorySignals;
 use sha2::{Sha256, Digest};
+use std::collections::VecDeque;
 
 /// Default frame size: 64KB (GDO decides this, not GDC)
 pub const DEFAULT_FRAME_SIZE: usize = 64 * 1024;
@@ -25,6 +26,17 @@ pub struct GdoEmulator {
     frame_size: usize,
     cognitive: CognitiveCycle,
     motor_context: MotorContext,
+    /// Envelope being reassembled (opened by BOF, not yet closed)
+    pending: Option<PendingFrame>,
+    /// Frames closed by EOF, waiting for `try_assemble`
+    assembled: VecDeque<PerceptualFrame>,
+}
+
+/// Partial frame buffered between BOF and EOF.
+#[derive(Debug, Clone)]
+struct PendingFrame {
+    payload: Vec<u8>,
+    fragment_depth: usize,
 }
 
 /// A complete observation with multiple frames.
@@ -64,12 +76,106 @@ pub struct GdoResult {
 }
 
 /// Aggregated motor scores across frames.
-#[derive(Debug, Clone, Copy, Default)]
+///
+/// The per-motor fields are raw averages; `aggregate` combines them with
+/// `weights` (normalized, canonical order Praxis → Nash → Chaos → Meristic).
+#[derive(Debug, Clone, Copy)]
 pub struct AggregatedMotorScores {
     pub praxis: f64,
     pub nash: f64,
     pub chaos: f64,
     pub meristic: f64,
+    /// Weighted combination of the four averages
+    pub aggregate: f64,
+    /// Normalized weights used for `aggregate` (sum to 1.0)
+    pub weights: [f64; 4],
+}
+
+/// Weight validation errors for `AggregatedMotorScores`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotorWeightError {
+    /// Weight at this index is negative or not finite
+    InvalidWeight { index: usize, value: f64 },
+    /// Weights sum to zero
+    ZeroSum,
+}
+
+impl std::fmt::Display for MotorWeightError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MotorWeightError::InvalidWeight { index, value } => {
+                write!(f, "Invalid motor weight at index {}: {}", index, value)
+            }
+            MotorWeightError::ZeroSum => write!(f, "Motor weights sum to zero"),
+        }
+    }
+}
+
+impl std::error::Error for MotorWeightError {}
+
+impl AggregatedMotorScores {
+    /// Equal weighting (the default aggregation).
+    pub const UNIFORM_WEIGHTS: [f64; 4] = [0.25; 4];
+
+    /// Average per-frame scores with equal motor weights.
+    pub fn from_scores(scores: &[MotorScores]) -> Self {
+        Self::aggregate_normalized(scores, Self::UNIFORM_WEIGHTS)
+    }
+
+    /// Average per-frame scores, combining motors with operator weights.
+    ///
+    /// Weights are normalized to sum to 1.0, so only their ratios matter.
+    ///
+    /// # Errors
+    /// Returns `MotorWeightError` if any weight is negative or not finite,
+    /// or if all weights are zero.
+    pub fn from_scores_weighted(
+        scores: &[MotorScores],
+        weights: [f64; 4],
+    ) -> Result<Self, MotorWeightError> {
+        for (index, &value) in weights.iter().enumerate() {
+            if !value.is_finite() || value < 0.0 {
+                return Err(MotorWeightError::InvalidWeight { index, value });
+            }
+        }
+        let sum: f64 = weights.iter().sum();
+        if sum <= 0.0 {
+            return Err(MotorWeightError::ZeroSum);
+        }
+        Ok(Self::aggregate_normalized(scores, weights.map(|w| w / sum)))
+    }
+
+    fn aggregate_normalized(scores: &[MotorScores], weights: [f64; 4]) -> Self {
+        let mut result = Self { weights, ..Self::default() };
+        if scores.is_empty() {
+            return result;
+        }
+        let n = scores.len() as f64;
+        result.praxis = scores.iter().map(|s| s.praxis).sum::<f64>() / n;
+        result.nash = scores.iter().map(|s| s.nash).sum::<f64>() / n;
+        result.chaos = scores.iter().map(|s| s.chaos).sum::<f64>() / n;
+        result.meristic = scores.iter().map(|s| s.meristic).sum::<f64>() / n;
+        result.aggregate = result.combine();
+        result
+    }
+
+    fn combine(&self) -> f64 {
+        let [wp, wn, wc, wm] = self.weights;
+        wp * self.praxis + wn * self.nash + wc * self.chaos + wm * self.meristic
+    }
+}
+
+impl Default for AggregatedMotorScores {
+    fn default() -> Self {
+        Self {
+            praxis: 0.0,
+            nash: 0.0,
+            chaos: 0.0,
+            meristic: 0.0,
+            aggregate: 0.0,
+            weights: Self::UNIFORM_WEIGHTS,
+        }
+    }
 }
 
 impl GdoEmulator {
@@ -79,6 +185,8 @@ impl GdoEmulator {
             frame_size: DEFAULT_FRAME_SIZE,
             cognitive: CognitiveCycle::new(),
             motor_context: MotorContext::default(),
+            pending: None,
+            assembled: VecDeque::new(),
         }
     }
 
@@ -143,6 +251,68 @@ impl GdoEmulator {
         }
     }
 
+    /// Feed one piece of a fragmented frame (L-008).
+    ///
+    /// `bytes` is the payload carried under `marker` and is appended to
+    /// the envelope opened by BOF. EOF closes the envelope and queues the
+    /// reassembled frame for `try_assemble`.
+    ///
+    /// # Errors
+    /// Returns a `FrameError` for a marker that breaks the stream (BOF
+    /// while an envelope is still open, anything outside an envelope,
+    /// unbalanced BOFR/EOFR). The partial envelope is discarded.
+    pub fn feed_fragment(&mut self, bytes: &[u8], marker: TransportCode) -> Result<(), FrameError> {
+        let result = self.advance_fragment(bytes, marker);
+        if result.is_err() {
+            self.pending = None;
+        }
+        result
+    }
+
+    /// Take the next frame completed by EOF, if any.
+    pub fn try_assemble(&mut self) -> Option<PerceptualFrame> {
+        self.assembled.pop_front()
+    }
+
+    fn advance_fragment(&mut self, bytes: &[u8], marker: TransportCode) -> Result<(), FrameError> {
+        let Some(pending) = self.pending.as_mut() else {
+            if marker != TransportCode::BOF {
+                return Err(FrameError::MissingBof);
+            }
+            self.pending = Some(PendingFrame {
+                payload: bytes.to_vec(),
+                fragment_depth: 0,
+            });
+            return Ok(());
+        };
+
+        match marker {
+            TransportCode::BOF => return Err(FrameError::MissingEof),
+            TransportCode::BOFR => pending.fragment_depth += 1,
+            TransportCode::EOFR => {
+                if pending.fragment_depth == 0 {
+                    return Err(FrameError::UnbalancedFragment);
+                }
+                pending.fragment_depth -= 1;
+            }
+            TransportCode::EOF => {
+                if pending.fragment_depth != 0 {
+                    return Err(FrameError::UnbalancedFragment);
+                }
+            }
+            TransportCode::VERSION | TransportCode::CHECKSUM => {}
+        }
+        pending.payload.extend_from_slice(bytes);
+
+        if marker == TransportCode::EOF {
+            if let Some(done) = self.pending.take() {
+                let frame = self.create_frame(&done.payload, 0, false);
+                self.assembled.push_back(frame);
+            }
+        }
+        Ok(())
+    }
+
     /// Process observation through GDC, aggregating results.
     pub fn observe(&mut self, data: &[u8]) -> GdoResult {
         let observation = self.frame_observation(data);
@@ -164,11 +334,8 @@ impl GdoEmulator {
         let mut total_bytes = 0usize;
         let mut seq = 0u32;
 
-        // Motor score accumulators
-        let mut sum_praxis = 0.0f64;
-        let mut sum_nash = 0.0f64;
-        let mut sum_chaos = 0.0f64;
-        let mut sum_meristic = 0.0f64;
+        // Per-frame motor scores (averaged by AggregatedMotorScores)
+        let mut frame_scores = Vec::new();
         let mut sum_cp = 0.0f64;
         let mut dna_hasher = Sha256::new();
 
@@ -196,10 +363,7 @@ impl GdoEmulator {
             m2 += delta * (cycle_output.perception.signals.mean - prev_mean);
 
             // Aggregate motor scores
-            sum_praxis += cycle_output.motor_scores.praxis;
-            sum_nash += cycle_output.motor_scores.nash;
-            sum_chaos += cycle_output.motor_scores.chaos;
-            sum_meristic += cycle_output.motor_scores.meristic;
+            frame_scores.push(cycle_output.motor_scores);
             sum_cp += cycle_output.cp_value;
 
             // Combine DNA
@@ -220,7 +384,7 @@ impl GdoEmulator {
         }
 
         let mut signals = SensorySignals::empty();
-        let mut motor_scores = AggregatedMotorScores::default();
+        let motor_scores = AggregatedMotorScores::from_scores(&frame_scores);
         let mut avg_cp = 0.0;
         
         if count > 0 {
@@ -228,10 +392,6 @@ impl GdoEmulator {
             signals.mean = prev_mean;
             signals.std_dev = if count > 1 { (m2 / (count - 1) as f64).sqrt() } else { 0.0 };
 
-            motor_scores.praxis = sum_praxis / count as f64;
-            motor_scores.nash = sum_nash / count as f64;
-            motor_scores.chaos = sum_chaos / count as f64;
-            motor_scores.meristic = sum_meristic / count as f64;
             avg_cp = sum_cp / count as f64;
         }
 
@@ -254,10 +414,7 @@ impl GdoEmulator {
         let mut prev_mean = 0.0f64;
         let mut count = 0usize;
 
-        let mut sum_praxis = 0.0f64;
-        let mut sum_nash = 0.0f64;
-        let mut sum_chaos = 0.0f64;
-        let mut sum_meristic = 0.0f64;
+        let mut frame_scores = Vec::new();
         let mut sum_cp = 0.0f64;
         let mut dna_hasher = Sha256::new();
 
@@ -271,10 +428,7 @@ impl GdoEmulator {
             prev_mean += delta / (count as f64);
             m2 += delta * (cycle_output.perception.signals.mean - prev_mean);
 
-            sum_praxis += cycle_output.motor_scores.praxis;
-            sum_nash += cycle_output.motor_scores.nash;
-            sum_chaos += cycle_output.motor_scores.chaos;
-            sum_meristic += cycle_output.motor_scores.meristic;
+            frame_scores.push(cycle_output.motor_scores);
             sum_cp += cycle_output.cp_value;
 
             dna_hasher.update(cycle_output.dna_fingerprint);
@@ -294,7 +448,7 @@ impl GdoEmulator {
         }
 
         let mut signals = SensorySignals::empty();
-        let mut motor_scores = AggregatedMotorScores::default();
+        let motor_scores = AggregatedMotorScores::from_scores(&frame_scores);
         let mut avg_cp = 0.0;
         
         if count > 0 {
@@ -302,10 +456,6 @@ impl GdoEmulator {
             signals.mean = prev_mean;
             signals.std_dev = if count > 1 { (m2 / (count - 1) as f64).sqrt() } else { 0.0 };
 
-            motor_scores.praxis = sum_praxis / count as f64;
-            motor_scores.nash = sum_nash / count as f64;
-            motor_scores.chaos = sum_chaos / count as f64;
-            motor_scores.meristic = sum_meristic / count as f64;
             avg_cp = sum_cp / count as f64;
         }
 
@@ -373,6 +523,20 @@ mod tests {
         // Verify motor scores are calculated
         assert!(result.motor_scores.praxis >= 0.0);
         assert!(result.avg_craft_performance >= 0.0);
+
+        // Uniform aggregation, and the streaming path agrees
+        let scores = result.motor_scores;
+        assert_eq!(scores.weights, AggregatedMotorScores::UNIFORM_WEIGHTS);
+        let mean = (scores.praxis + scores.nash + scores.chaos + scores.meristic) / 4.0;
+        assert!((scores.aggregate - mean).abs() < 1e-12);
+
+        let streamed = GdoEmulator::with_frame_size(100)
+            .observe_stream(&data[..])
+            .unwrap()
+            .motor_scores;
+        assert_eq!(streamed.praxis, scores.praxis);
+        assert_eq!(streamed.meristic, scores.meristic);
+        assert_eq!(streamed.aggregate, scores.aggregate);
     }
 
     #[test]
@@ -384,6 +548,97 @@ mod tests {
         assert_ne!(result.reports[0].dna_fingerprint, [0u8; 32]);
     }
 
+    #[test]
+    fn test_assemble_three_fragments() {
+        let mut gdo = GdoEmulator::new();
+        gdo.feed_fragment(&[], TransportCode::BOF).unwrap();
+        gdo.feed_fragment(&[1, 2], TransportCode::BOFR).unwrap();
+        gdo.feed_fragment(&[3, 4], TransportCode::EOFR).unwrap();
+        gdo.feed_fragment(&[5, 6], TransportCode::BOFR).unwrap();
+        gdo.feed_fragment(&[7], TransportCode::EOFR).unwrap();
+        gdo.feed_fragment(&[8, 9], TransportCode::BOFR).unwrap();
+        gdo.feed_fragment(&[], TransportCode::EOFR).unwrap();
+        assert!(gdo.try_assemble().is_none());
+        gdo.feed_fragment(&[], TransportCode::EOF).unwrap();
+
+        let frame = gdo.try_assemble().unwrap();
+        assert_eq!(frame.payload, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert!(frame.is_valid());
+        assert!(!frame.is_fragment);
+        assert_eq!(frame.checksum, GdoEmulator::hash(&frame.payload));
+        assert!(gdo.try_assemble().is_none());
+    }
+
+    #[test]
+    fn test_fragment_stream_missing_eof_rejected() {
+        let mut gdo = GdoEmulator::new();
+        gdo.feed_fragment(&[], TransportCode::BOF).unwrap();
+        gdo.feed_fragment(&[1, 2], TransportCode::BOFR).unwrap();
+        gdo.feed_fragment(&[3], TransportCode::EOFR).unwrap();
+        assert!(gdo.try_assemble().is_none());
+
+        // Next envelope opens before the first was closed
+        assert_eq!(gdo.feed_fragment(&[], TransportCode::BOF), Err(FrameError::MissingEof));
+        assert!(gdo.try_assemble().is_none());
+
+        // Partial envelope was discarded; markers outside one are rejected
+        assert_eq!(gdo.feed_fragment(&[4], TransportCode::EOF), Err(FrameError::MissingBof));
+    }
+
+    #[test]
+    fn test_fragment_unbalanced_rejected() {
+        let mut gdo = GdoEmulator::new();
+        gdo.feed_fragment(&[], TransportCode::BOF).unwrap();
+        gdo.feed_fragment(&[1], TransportCode::BOFR).unwrap();
+        assert_eq!(gdo.feed_fragment(&[], TransportCode::EOF), Err(FrameError::UnbalancedFragment));
+        assert!(gdo.try_assemble().is_none());
+    }
+
+    fn sample_scores() -> Vec<MotorScores> {
+        vec![
+            MotorScores { praxis: 0.9, nash: 0.6, chaos: 0.3, meristic: 0.5 },
+            MotorScores { praxis: 0.7, nash: 0.8, chaos: 0.5, meristic: 0.1 },
+        ]
+    }
+
+    #[test]
+    fn test_uniform_weights_match_unweighted() {
+        let scores = sample_scores();
+        let unweighted = AggregatedMotorScores::from_scores(&scores);
+        let weighted = AggregatedMotorScores::from_scores_weighted(&scores, [2.0; 4]).unwrap();
+        assert!((unweighted.aggregate - weighted.aggregate).abs() < 1e-12);
+        assert_eq!(unweighted.praxis, weighted.praxis);
+        assert_eq!(weighted.weights, AggregatedMotorScores::UNIFORM_WEIGHTS);
+        assert!((unweighted.aggregate - 0.55).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_weight_removes_influence() {
+        let weights = [1.0, 1.0, 0.0, 1.0];
+        let mut scores = sample_scores();
+        let before = AggregatedMotorScores::from_scores_weighted(&scores, weights).unwrap();
+        scores[0].chaos = 0.0;
+        scores[1].chaos = 1.0;
+        let after = AggregatedMotorScores::from_scores_weighted(&scores, weights).unwrap();
+        assert_eq!(before.aggregate, after.aggregate);
+        // Raw per-motor score still exposed
+        assert_ne!(before.chaos, after.chaos);
+    }
+
+    #[test]
+    fn test_invalid_weights_rejected() {
+        let scores = sample_scores();
+        assert_eq!(
+            AggregatedMotorScores::from_scores_weighted(&scores, [1.0, -0.5, 1.0, 1.0]).unwrap_err(),
+            MotorWeightError::InvalidWeight { index: 1, value: -0.5 }
+        );
+        assert_eq!(
+            AggregatedMotorScores::from_scores_weighted(&scores, [0.0; 4]).unwrap_err(),
+            MotorWeightError::ZeroSum
+        );
+        assert!(AggregatedMotorScores::from_scores_weighted(&scores, [f64::NAN, 1.0, 1.0, 1.0]).is_err());
+    }
+
     #[test]
     fn test_determinism() {
         let mut gdo = GdoEmulator::with_frame_size(100);
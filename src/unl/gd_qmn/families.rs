@@ -1,6 +1,11 @@
 //! GD-QMN Code Families (F1-F6)
 //!
 //! Each family groups related cognitive operations.
+//!
+//! # Registry
+//! `REGISTRY_F1`..`REGISTRY_F6` list the valid codes per family and back
+//! `Family::valid_codes`. To add a code: declare the constant in its
+//! family module below AND append it to that family's registry slice.
 
 /// F1: Transduction - Input processing codes
 pub mod f1 {
@@ -61,6 +66,38 @@ pub mod f6 {
     pub const ORIGIN_RECOMBINED: u16 = 0x0022; // State from cognitive recombination
 }
 
+/// Registered F1 codes.
+pub const REGISTRY_F1: &[u16] = &[
+    f1::PERCEIVE_RAW, f1::PERCEIVE_FRAMED,
+    f1::TRANSDUCE_CARRIER, f1::TRANSDUCE_PATTERN, f1::TRANSDUCE_STRUCTURE,
+];
+
+/// Registered F2 codes.
+pub const REGISTRY_F2: &[u16] = &[
+    f2::COMPOSE_SIGNAL, f2::COMPOSE_PATTERN, f2::COMPOSE_ACTION, f2::COMPOSE_DNA,
+];
+
+/// Registered F3 codes.
+pub const REGISTRY_F3: &[u16] = &[
+    f3::MOTOR_PRAXIS, f3::MOTOR_NASH, f3::MOTOR_CHAOS, f3::MOTOR_MERISTIC, f3::MOTOR_VETO,
+];
+
+/// Registered F4 codes.
+pub const REGISTRY_F4: &[u16] = &[
+    f4::EMIT_DNA, f4::EMIT_REPORT, f4::EMIT_SIGNAL,
+];
+
+/// Registered F5 codes.
+pub const REGISTRY_F5: &[u16] = &[
+    f5::SCALE_LINEAR, f5::SCALE_LOG, f5::SCALE_SIGMOID, f5::NORMALIZE,
+];
+
+/// Registered F6 codes.
+pub const REGISTRY_F6: &[u16] = &[
+    f6::NOP, f6::BOF, f6::EOF, f6::BOFR, f6::EOFR, f6::VERSION, f6::CHECKSUM,
+    f6::ORIGIN_EXTERNAL, f6::ORIGIN_INTERNAL, f6::ORIGIN_RECOMBINED,
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,6 +116,16 @@ mod tests {
         assert_eq!(f6::ORIGIN_RECOMBINED, 0x0022);
     }
 
+    #[test]
+    fn test_registries_are_disjoint() {
+        let all = [REGISTRY_F1, REGISTRY_F2, REGISTRY_F3, REGISTRY_F4, REGISTRY_F5, REGISTRY_F6];
+        for (i, a) in all.iter().enumerate() {
+            for b in all.iter().skip(i + 1) {
+                assert!(a.iter().all(|code| !b.contains(code)));
+            }
+        }
+    }
+
     #[test]
     fn test_motor_codes_in_f3() {
         assert!(f3::MOTOR_PRAXIS >= 0x0300);
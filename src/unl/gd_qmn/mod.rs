@@ -40,6 +40,29 @@ impl Profile {
     pub fn bytes(&self) -> usize {
         (self.bits() as usize) / 8
     }
+
+    /// Width of the code field (12 bits in `CompactCode`, 16 otherwise).
+    pub fn code_bits(&self) -> u32 {
+        match self {
+            Self::Compact => 12,
+            Self::Standard | Self::Extended => 16,
+        }
+    }
+
+    /// Whether `code` fits this profile's code field.
+    pub fn fits_code(&self, code: u16) -> bool {
+        u32::from(code) >> self.code_bits() == 0
+    }
+
+    /// Infers the profile from an encoded byte length.
+    pub fn from_bytes_len(len: usize) -> Option<Self> {
+        match len {
+            8 => Some(Self::Compact),
+            16 => Some(Self::Standard),
+            32 => Some(Self::Extended),
+            _ => None,
+        }
+    }
 }
 
 /// Code family (F1-F6).
@@ -59,12 +82,95 @@ impl GdQmn {
         Self { family, code, profile }
     }
 
+    /// Creates a code point, rejecting codes not registered for the family.
+    pub fn new_checked(family: Family, code: u16, profile: Profile) -> Result<Self, GdQmnError> {
+        let q = Self::new(family, code, profile);
+        if q.is_valid() {
+            Ok(q)
+        } else {
+            Err(GdQmnError::InvalidCode { family, code })
+        }
+    }
+
+    /// Checks that the code belongs to its family's registry.
+    pub fn is_valid(&self) -> bool {
+        self.family.valid_codes().contains(&self.code)
+    }
+
     /// Returns full code as u32 (family << 16 | code).
     pub fn full_code(&self) -> u32 {
         ((self.family as u32) << 16) | (self.code as u32)
     }
+
+    /// Encodes to exactly `profile.bytes()` bytes.
+    ///
+    /// Layout (shared with `CompactCode`/`StandardCode`/`ExtendedCode`):
+    /// byte 0 = family, bytes 1..3 = code (little-endian), remaining
+    /// bytes = extension region, zero-filled. The compact profile only
+    /// carries a 12-bit code; wider codes are rejected.
+    pub fn encode(&self) -> Result<Vec<u8>, GdQmnError> {
+        if !self.profile.fits_code(self.code) {
+            return Err(GdQmnError::CodeTooWide { code: self.code, profile: self.profile });
+        }
+        let mut buf = vec![0u8; self.profile.bytes()];
+        buf[0] = self.family as u8;
+        buf[1..3].copy_from_slice(&self.code.to_le_bytes());
+        Ok(buf)
+    }
+
+    /// Decodes a code point, inferring the profile from the byte length.
+    ///
+    /// The extension region is not interpreted (it carries amplitude,
+    /// frequency, etc. in the profile-specific structs).
+    pub fn decode(bytes: &[u8]) -> Result<Self, GdQmnError> {
+        let profile = Profile::from_bytes_len(bytes.len())
+            .ok_or(GdQmnError::InvalidLength(bytes.len()))?;
+        let family = Family::from_u8(bytes[0])
+            .ok_or(GdQmnError::InvalidFamily(bytes[0]))?;
+        let code = u16::from_le_bytes([bytes[1], bytes[2]]);
+        if !profile.fits_code(code) {
+            return Err(GdQmnError::CodeTooWide { code, profile });
+        }
+        Ok(Self { family, code, profile })
+    }
+}
+
+/// GD-QMN encoding errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GdQmnError {
+    /// Byte length does not match any profile (8, 16 or 32)
+    InvalidLength(usize),
+    /// Family byte is not F1-F6
+    InvalidFamily(u8),
+    /// Code is not registered for the family
+    InvalidCode { family: Family, code: u16 },
+    /// Code does not fit the profile's code field (12 bits in Compact)
+    CodeTooWide { code: u16, profile: Profile },
+}
+
+impl std::fmt::Display for GdQmnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GdQmnError::InvalidLength(len) => {
+                write!(f, "Invalid GD-QMN length: {} bytes (expected 8, 16 or 32)", len)
+            }
+            GdQmnError::InvalidFamily(v) => write!(f, "Invalid GD-QMN family: {}", v),
+            GdQmnError::InvalidCode { family, code } => {
+                write!(f, "Code 0x{:04X} is not registered for {:?}", code, family)
+            }
+            GdQmnError::CodeTooWide { code, profile } => write!(
+                f,
+                "Code 0x{:04X} does not fit the {}-bit code field of {:?}",
+                code,
+                profile.code_bits(),
+                profile
+            ),
+        }
+    }
 }
 
+impl std::error::Error for GdQmnError {}
+
 impl Family {
     pub fn from_u8(v: u8) -> Option<Self> {
         match v {
@@ -77,6 +183,20 @@ impl Family {
             _ => None,
         }
     }
+
+    /// Returns the registered codes for this family.
+    ///
+    /// See `families` for how to extend the registry.
+    pub fn valid_codes(&self) -> &'static [u16] {
+        match self {
+            Self::F1Transduction => families::REGISTRY_F1,
+            Self::F2Composition => families::REGISTRY_F2,
+            Self::F3Motors => families::REGISTRY_F3,
+            Self::F4Emission => families::REGISTRY_F4,
+            Self::F5Scale => families::REGISTRY_F5,
+            Self::F6Operational => families::REGISTRY_F6,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +210,60 @@ mod tests {
         assert_eq!(Profile::Extended.bits(), 256);
     }
 
+    #[test]
+    fn test_encode_decode_roundtrip_all_profiles() {
+        for profile in [Profile::Compact, Profile::Standard, Profile::Extended] {
+            let q = GdQmn::new(Family::F3Motors, 0x0301, profile);
+            let bytes = q.encode().unwrap();
+            assert_eq!(bytes.len(), profile.bytes());
+            assert_eq!(GdQmn::decode(&bytes), Ok(q));
+        }
+    }
+
+    #[test]
+    fn test_decode_truncated_is_error() {
+        let bytes = GdQmn::new(Family::F6Operational, 0x0001, Profile::Standard).encode().unwrap();
+        assert_eq!(GdQmn::decode(&bytes[..15]), Err(GdQmnError::InvalidLength(15)));
+        assert_eq!(GdQmn::decode(&[]), Err(GdQmnError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_decode_invalid_family() {
+        let mut bytes = GdQmn::new(Family::F1Transduction, 0x0100, Profile::Compact).encode().unwrap();
+        bytes[0] = 9;
+        assert_eq!(GdQmn::decode(&bytes), Err(GdQmnError::InvalidFamily(9)));
+    }
+
+    #[test]
+    fn test_compact_code_width() {
+        let max = GdQmn::new(Family::F6Operational, 0x0FFF, Profile::Compact);
+        assert_eq!(GdQmn::decode(&max.encode().unwrap()), Ok(max));
+
+        let wide = GdQmn::new(Family::F6Operational, 0x1000, Profile::Compact);
+        let err = GdQmnError::CodeTooWide { code: 0x1000, profile: Profile::Compact };
+        assert_eq!(wide.encode(), Err(err));
+        assert!(GdQmn::new(Family::F6Operational, 0x1000, Profile::Standard).encode().is_ok());
+
+        let mut bytes = max.encode().unwrap();
+        bytes[1..3].copy_from_slice(&0x1000u16.to_le_bytes());
+        assert_eq!(GdQmn::decode(&bytes), Err(err));
+    }
+
+    #[test]
+    fn test_new_checked_valid() {
+        let q = GdQmn::new_checked(Family::F6Operational, families::f6::BOF, Profile::Standard).unwrap();
+        assert!(q.is_valid());
+        assert!(GdQmn::new_checked(Family::F3Motors, families::f3::MOTOR_NASH, Profile::Compact).is_ok());
+    }
+
+    #[test]
+    fn test_new_checked_cross_family_rejected() {
+        // BOF is an F6 code; it is not valid as a motor code
+        let err = GdQmn::new_checked(Family::F3Motors, families::f6::BOF, Profile::Standard);
+        assert_eq!(err, Err(GdQmnError::InvalidCode { family: Family::F3Motors, code: 0x0001 }));
+        assert!(!GdQmn::new(Family::F3Motors, families::f6::BOF, Profile::Standard).is_valid());
+    }
+
     #[test]
     fn test_gd_qmn_full_code() {
         let q = GdQmn::new(Family::F6Operational, 0x0001, Profile::Standard);
@@ -8,5 +8,7 @@ pub mod gd_qmn;
 pub mod emulator;
 
 pub use spec::{UnlSpec, UnlRule, UnlInvariant};
-pub use gd_qmn::{GdQmn, Profile, Family};
-pub use emulator::{GdoEmulator, PerceptualFrame, Observation, GdoResult, AggregatedMotorScores};
+pub use gd_qmn::{GdQmn, Profile, Family, GdQmnError};
+pub use emulator::{GdoEmulator, PerceptualFrame, Observation, GdoResult, AggregatedMotorScores,
+    MotorWeightError,
+};
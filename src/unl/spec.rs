@@ -1,5 +1,6 @@
 //! UNL Specification - Rules and Invariants
 
+use crate::cognitive::{ObservationReport, TransportCode};
 use serde::{Deserialize, Serialize};
 
 /// UNL Specification container.
@@ -19,7 +20,7 @@ pub struct UnlRule {
 }
 
 /// An invariant that must always hold.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UnlInvariant {
     pub id: String,
     pub axiom: String,
@@ -65,9 +66,60 @@ impl UnlSpec {
                     id: "I003".into(),
                     axiom: "motor < VETO_THRESHOLD ⟹ CP = 0".into(),
                 },
+                UnlInvariant {
+                    id: "I004".into(),
+                    axiom: "BOF ∈ report.protocol_markers".into(),
+                },
+                UnlInvariant {
+                    id: "I005".into(),
+                    axiom: "EOF ∈ report.protocol_markers".into(),
+                },
+                UnlInvariant {
+                    id: "I006".into(),
+                    axiom: "∀ motor: signature ≠ 0".into(),
+                },
             ],
         }
     }
+
+    /// Check an `ObservationReport` against the report-level invariants.
+    ///
+    /// Only invariants observable on a report are checked (I004-I006);
+    /// score invariants (I001-I003) need motor values the report only
+    /// carries as hashes and are skipped. A fragment report (BOFR/EOFR
+    /// only) is not a complete transmission and fails I004/I005.
+    ///
+    /// # Errors
+    /// Returns every violated invariant, in specification order.
+    pub fn validate(&self, report: &ObservationReport) -> Result<(), Vec<UnlInvariant>> {
+        let violated: Vec<UnlInvariant> = self
+            .invariants
+            .iter()
+            .filter(|inv| Self::check(&inv.id, report) == Some(false))
+            .cloned()
+            .collect();
+
+        if violated.is_empty() {
+            Ok(())
+        } else {
+            Err(violated)
+        }
+    }
+
+    /// `Some(holds)` for report-level invariants, `None` otherwise.
+    fn check(id: &str, report: &ObservationReport) -> Option<bool> {
+        let sigs = &report.motor_signatures;
+        match id {
+            "I004" => Some(report.protocol_markers.contains(&TransportCode::BOF)),
+            "I005" => Some(report.protocol_markers.contains(&TransportCode::EOF)),
+            "I006" => Some(
+                [sigs.praxis, sigs.nash, sigs.chaos, sigs.meristic]
+                    .iter()
+                    .all(|sig| *sig != [0u8; 32]),
+            ),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -80,4 +132,45 @@ mod tests {
         assert!(!spec.rules.is_empty());
         assert!(!spec.invariants.is_empty());
     }
+
+    fn well_formed_report() -> ObservationReport {
+        ObservationReport {
+            cycle_id: [1; 16],
+            frame_fingerprint: [2; 32],
+            protocol_markers: vec![TransportCode::BOF, TransportCode::EOF],
+            motor_signatures: crate::cognitive::MotorSignatures {
+                praxis: [3; 32],
+                nash: [4; 32],
+                chaos: [5; 32],
+                meristic: [6; 32],
+            },
+            dna_fingerprint: [7; 32],
+        }
+    }
+
+    #[test]
+    fn test_well_formed_report_validates() {
+        let spec = UnlSpec::v1();
+        assert!(spec.validate(&well_formed_report()).is_ok());
+    }
+
+    #[test]
+    fn test_report_missing_eof_violates_i005() {
+        let spec = UnlSpec::v1();
+        let mut report = well_formed_report();
+        report.protocol_markers = vec![TransportCode::BOF];
+        let violated = spec.validate(&report).unwrap_err();
+        assert_eq!(violated.len(), 1);
+        assert_eq!(violated[0].id, "I005");
+    }
+
+    #[test]
+    fn test_report_missing_signatures_violates_i006() {
+        let spec = UnlSpec::v1();
+        let mut report = well_formed_report();
+        report.motor_signatures = crate::cognitive::MotorSignatures::empty();
+        report.protocol_markers.clear();
+        let ids: Vec<String> = spec.validate(&report).unwrap_err().into_iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec!["I004", "I005", "I006"]);
+    }
 }
@@ -46,6 +46,8 @@ use digital_genome_community::{
     CognitiveObservability, HealthIndicators, ProgressTracker,
     // Completeness
     CognitiveCompleteness, AbstractionLevel,
+    // Cognitive cycle
+    CognitiveCycle, MotorContext,
 };
 
 // =============================================================================
@@ -649,3 +651,44 @@ fn test_mature_output_is_stateless() {
     assert_eq!(output1.perception.signals.entropy, output2.perception.signals.entropy);
     assert_eq!(output1.perception.signals.sample_count, output2.perception.signals.sample_count);
 }
+
+// =============================================================================
+// COGNITIVE CYCLE TESTS
+// =============================================================================
+
+/// Tests that CognitiveCycle::run is deterministic and fully reports.
+#[test]
+fn test_cognitive_cycle_run_deterministic_report() {
+    let cycle = CognitiveCycle::new();
+    let ctx = MotorContext {
+        player_count: 2,
+        action_sizes: vec![2, 2],
+        payoffs: vec![vec![3, 0, 5, 1], vec![3, 5, 0, 1]],
+        strategies: vec![vec![0, 100], vec![0, 100]],
+        ..MotorContext::default()
+    };
+
+    let first = cycle.run(&ctx);
+    let second = cycle.run(&ctx);
+
+    assert_eq!(first.report, second.report);
+    assert_eq!(first.report.fingerprint(), second.report.fingerprint());
+    assert_eq!(first.cp_value.to_bits(), second.cp_value.to_bits());
+    assert_eq!(first.dna_fingerprint, second.dna_fingerprint);
+
+    // All five L-004 fields populated
+    let report = &first.report;
+    assert_ne!(report.cycle_id, [0u8; 16]);
+    assert_ne!(report.frame_fingerprint, [0u8; 32]);
+    assert!(!report.protocol_markers.is_empty());
+    let sigs = &report.motor_signatures;
+    for sig in [sigs.praxis, sigs.nash, sigs.chaos, sigs.meristic] {
+        assert_ne!(sig, [0u8; 32]);
+    }
+    assert_ne!(report.dna_fingerprint, [0u8; 32]);
+    assert_eq!(report.dna_fingerprint, first.structured_dna.fingerprint);
+
+    // run() observes only: nothing learned, MCI untouched
+    assert!(first.learning_result.is_none());
+    assert_eq!(cycle.cycle_counter(), 0);
+}
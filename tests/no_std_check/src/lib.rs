@@ -0,0 +1,30 @@
+//! no_std compile check for the Community motors.
+//!
+//! If this crate builds, `NashMotor::evaluate`, the budget checks and
+//! the correlation math compile against `core` + `alloc` only.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use digital_genome_community::budget::{check_numerical_stability, IntegrityCheck};
+use digital_genome_community::motors::{CognitiveMotor, NashInput, NashMotor};
+
+/// Nash score of a 2x2 game, or 0.0 if it fails the numerical check.
+pub fn prisoners_dilemma_score() -> f64 {
+    let input = NashInput {
+        num_players: 2,
+        action_sizes: vec![2, 2],
+        payoffs: vec![vec![3, 0, 5, 1], vec![3, 5, 0, 1]],
+        strategies: vec![vec![0, 1000], vec![0, 1000]],
+        scale: 1000,
+    };
+    let output = NashMotor::new().evaluate(&input);
+
+    match check_numerical_stability(&[output.score]) {
+        IntegrityCheck::WithinBudget => output.score,
+        _ => 0.0,
+    }
+}